@@ -1,2 +1,3 @@
 pub mod memory_db;
+pub mod overlay;
 pub mod rocks_db;
@@ -1,8 +1,30 @@
 use radix_engine::engine::Substate;
 use radix_engine::ledger::{
     bootstrap, OutputValue, QueryableSubstateStore, ReadableSubstateStore, WriteableSubstateStore,
+    OUTPUT_VALUE_FORMAT_VERSION,
 };
 use radix_engine::types::*;
+use serde::{Deserialize, Serialize};
+
+/// Represents an error when importing a store from JSON.
+#[derive(Debug)]
+pub enum ImportJsonError {
+    InvalidJson(serde_json::Error),
+    InvalidHex(hex::FromHexError),
+    InvalidOutputValue(DecodeError),
+    /// A value was written with a newer `OutputValue` format than this build supports.
+    VersionMismatch {
+        found: u16,
+        supported: u16,
+    },
+}
+
+/// The JSON representation of a `SerializedInMemorySubstateStore`, with hex encoded keys and values.
+#[derive(Serialize, Deserialize)]
+struct JsonSnapshot {
+    substates: BTreeMap<String, String>,
+    roots: BTreeSet<String>,
+}
 
 /// A substate store that stores all typed substates in host memory.
 #[derive(Debug, PartialEq, Eq)]
@@ -23,6 +45,60 @@ impl SerializedInMemorySubstateStore {
         let substate_store = Self::new();
         bootstrap(substate_store)
     }
+
+    /// Exports all substates and roots as JSON.
+    pub fn export_json(&self) -> String {
+        let snapshot = JsonSnapshot {
+            substates: self
+                .substates
+                .iter()
+                .map(|(key, value)| (hex::encode(key), hex::encode(value)))
+                .collect(),
+            roots: self.roots.iter().map(hex::encode).collect(),
+        };
+        serde_json::to_string(&snapshot).expect("Failed to serialize store snapshot")
+    }
+
+    /// Creates a store from the JSON produced by `export_json`.
+    pub fn import_json(s: &str) -> Result<Self, ImportJsonError> {
+        let snapshot: JsonSnapshot =
+            serde_json::from_str(s).map_err(ImportJsonError::InvalidJson)?;
+        let substates: HashMap<Vec<u8>, Vec<u8>> = snapshot
+            .substates
+            .into_iter()
+            .map(|(key, value)| Ok((hex::decode(key)?, hex::decode(value)?)))
+            .collect::<Result<_, hex::FromHexError>>()
+            .map_err(ImportJsonError::InvalidHex)?;
+        for value in substates.values() {
+            let found = format_version_of(value).map_err(ImportJsonError::InvalidOutputValue)?;
+            if found > OUTPUT_VALUE_FORMAT_VERSION {
+                return Err(ImportJsonError::VersionMismatch {
+                    found,
+                    supported: OUTPUT_VALUE_FORMAT_VERSION,
+                });
+            }
+        }
+        let roots = snapshot
+            .roots
+            .into_iter()
+            .map(hex::decode)
+            .collect::<Result<_, hex::FromHexError>>()
+            .map_err(ImportJsonError::InvalidHex)?;
+        Ok(Self { substates, roots })
+    }
+}
+
+/// Reads the format version of an encoded `OutputValue`, without decoding its substate.
+fn format_version_of(value: &[u8]) -> Result<u16, DecodeError> {
+    match decode_any(value)? {
+        Value::Struct { fields } => match fields.first() {
+            Some(Value::U16 { value }) => Ok(*value),
+            _ => Err(DecodeError::CustomError(
+                "Missing format version".to_string(),
+            )),
+        },
+        _ => Err(DecodeError::CustomError("Not an output value".to_string())),
+    }
 }
 
 impl Default for SerializedInMemorySubstateStore {
@@ -41,10 +117,35 @@ impl ReadableSubstateStore for SerializedInMemorySubstateStore {
     fn is_root(&self, substate_id: &SubstateId) -> bool {
         self.roots.contains(&scrypto_encode(substate_id))
     }
+
+    fn get_kv_store_entries_in_range(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        start: &[u8],
+        end: &[u8],
+        limit: usize,
+    ) -> Vec<(Vec<u8>, OutputValue)> {
+        let mut entries: Vec<(Vec<u8>, OutputValue)> = self
+            .substates
+            .iter()
+            .filter_map(|(key, value)| match scrypto_decode(key).unwrap() {
+                SubstateId::KeyValueStoreEntry(id, key)
+                    if id == *kv_store_id && start <= key.as_slice() && key.as_slice() <= end =>
+                {
+                    Some((key, scrypto_decode(value).unwrap()))
+                }
+                _ => None,
+            })
+            .collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        entries.truncate(limit);
+        entries
+    }
 }
 
 impl WriteableSubstateStore for SerializedInMemorySubstateStore {
-    fn put_substate(&mut self, substate_id: SubstateId, substate: OutputValue) {
+    fn put_substate(&mut self, substate_id: SubstateId, mut substate: OutputValue) {
+        substate.format_version = OUTPUT_VALUE_FORMAT_VERSION;
         self.substates
             .insert(scrypto_encode(&substate_id), scrypto_encode(&substate));
     }
@@ -73,4 +174,70 @@ impl QueryableSubstateStore for SerializedInMemorySubstateStore {
             })
             .collect()
     }
+
+    fn scan_prefix(&self, prefix: &[u8]) -> Vec<(SubstateId, OutputValue)> {
+        let mut items: Vec<(&Vec<u8>, &Vec<u8>)> = self
+            .substates
+            .iter()
+            .filter(|(key, _)| key.starts_with(prefix))
+            .collect();
+        items.sort_by(|a, b| a.0.cmp(b.0));
+        items
+            .into_iter()
+            .map(|(key, value)| (scrypto_decode(key).unwrap(), scrypto_decode(value).unwrap()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use radix_engine::model::KeyValueStoreEntryWrapper;
+    use scrypto_unit::TestRunner;
+
+    #[test]
+    fn test_json_round_trip() {
+        let mut store = SerializedInMemorySubstateStore::with_bootstrap();
+        let mut test_runner = TestRunner::new(true, &mut store);
+        let (_, _, account) = test_runner.new_account();
+
+        let json = store.export_json();
+        let imported = SerializedInMemorySubstateStore::import_json(&json).unwrap();
+
+        assert_eq!(imported, store);
+        assert!(imported
+            .get_substate(&SubstateId::ComponentInfo(account))
+            .is_some());
+    }
+
+    #[test]
+    fn test_import_future_version() {
+        let substate_id = SubstateId::KeyValueStoreEntry((Hash([0u8; 32]), 0), vec![1]);
+        let value = OutputValue {
+            format_version: OUTPUT_VALUE_FORMAT_VERSION + 1,
+            substate: Substate::KeyValueStoreEntry(KeyValueStoreEntryWrapper(None)),
+            version: 0,
+        };
+        let json = format!(
+            r#"{{"substates":{{"{}":"{}"}},"roots":[]}}"#,
+            hex::encode(scrypto_encode(&substate_id)),
+            hex::encode(scrypto_encode(&value))
+        );
+
+        let result = SerializedInMemorySubstateStore::import_json(&json);
+
+        assert!(matches!(
+            result,
+            Err(ImportJsonError::VersionMismatch { found, supported })
+                if found == OUTPUT_VALUE_FORMAT_VERSION + 1 && supported == OUTPUT_VALUE_FORMAT_VERSION
+        ));
+    }
+
+    #[test]
+    fn test_import_invalid_hex() {
+        let result =
+            SerializedInMemorySubstateStore::import_json(r#"{"substates":{"zz":"00"},"roots":[]}"#);
+
+        assert!(matches!(result, Err(ImportJsonError::InvalidHex(_))));
+    }
 }
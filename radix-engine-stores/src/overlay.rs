@@ -0,0 +1,154 @@
+use radix_engine::engine::Substate;
+use radix_engine::ledger::{
+    OutputValue, QueryableSubstateStore, ReadableSubstateStore, SubstateWrites,
+    WriteableSubstateStore,
+};
+use radix_engine::types::*;
+
+/// The writes buffered by an `OverlaySubstateStore`.
+pub type OverlayChanges = SubstateWrites;
+
+/// A copy-on-write overlay of a substate store.
+///
+/// Reads fall through to the base store unless the substate has been written to the overlay.
+/// Writes are buffered until `commit` applies them to the base store, or are discarded when the
+/// overlay is dropped.
+pub struct OverlaySubstateStore<'s, S: ReadableSubstateStore + WriteableSubstateStore> {
+    base: &'s mut S,
+    writes: SubstateWrites,
+}
+
+impl<'s, S: ReadableSubstateStore + WriteableSubstateStore> OverlaySubstateStore<'s, S> {
+    pub fn new(base: &'s mut S) -> Self {
+        Self {
+            base,
+            writes: SubstateWrites::new(),
+        }
+    }
+
+    /// Applies the buffered writes to the base store.
+    pub fn commit(self) {
+        let base = self.base;
+        for (substate_id, output_value) in self.writes.substates {
+            base.put_substate(substate_id, output_value);
+        }
+        for substate_id in self.writes.root_substates {
+            base.set_root(substate_id);
+        }
+    }
+
+    /// Returns the buffered writes, without applying them.
+    pub fn into_changes(self) -> OverlayChanges {
+        self.writes
+    }
+}
+
+impl<'s, S: ReadableSubstateStore + WriteableSubstateStore> ReadableSubstateStore
+    for OverlaySubstateStore<'s, S>
+{
+    fn get_substate(&self, substate_id: &SubstateId) -> Option<OutputValue> {
+        self.writes.get_substate(&*self.base, substate_id)
+    }
+
+    fn is_root(&self, substate_id: &SubstateId) -> bool {
+        self.writes.is_root(&*self.base, substate_id)
+    }
+
+    fn get_kv_store_entries_in_range(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        start: &[u8],
+        end: &[u8],
+        limit: usize,
+    ) -> Vec<(Vec<u8>, OutputValue)> {
+        self.writes
+            .get_kv_store_entries_in_range(&*self.base, kv_store_id, start, end, limit)
+    }
+}
+
+impl<'s, S: ReadableSubstateStore + WriteableSubstateStore> WriteableSubstateStore
+    for OverlaySubstateStore<'s, S>
+{
+    fn put_substate(&mut self, substate_id: SubstateId, substate: OutputValue) {
+        self.writes.substates.insert(substate_id, substate);
+    }
+
+    fn set_root(&mut self, substate_id: SubstateId) {
+        self.writes.root_substates.insert(substate_id);
+    }
+}
+
+impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateStore>
+    QueryableSubstateStore for OverlaySubstateStore<'s, S>
+{
+    fn get_kv_store_entries(&self, kv_store_id: &KeyValueStoreId) -> HashMap<Vec<u8>, Substate> {
+        self.writes.get_kv_store_entries(&*self.base, kv_store_id)
+    }
+
+    fn scan_prefix(&self, prefix: &[u8]) -> Vec<(SubstateId, OutputValue)> {
+        self.writes.scan_prefix(&*self.base, prefix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory_db::SerializedInMemorySubstateStore;
+    use radix_engine::ledger::OUTPUT_VALUE_FORMAT_VERSION;
+    use radix_engine::model::KeyValueStoreEntryWrapper;
+
+    fn entry(key: u8) -> SubstateId {
+        SubstateId::KeyValueStoreEntry((Hash([0u8; 32]), 0), vec![key])
+    }
+
+    fn value(value: u8) -> OutputValue {
+        OutputValue {
+            format_version: OUTPUT_VALUE_FORMAT_VERSION,
+            substate: Substate::KeyValueStoreEntry(KeyValueStoreEntryWrapper(Some(vec![value]))),
+            version: 0,
+        }
+    }
+
+    #[test]
+    fn test_reads_fall_through_to_base() {
+        let mut base = SerializedInMemorySubstateStore::new();
+        base.put_substate(entry(1), value(1));
+
+        let overlay = OverlaySubstateStore::new(&mut base);
+
+        assert_eq!(overlay.get_substate(&entry(1)), Some(value(1)));
+        assert_eq!(overlay.get_substate(&entry(2)), None);
+    }
+
+    #[test]
+    fn test_writes_are_isolated_until_commit() {
+        let mut base = SerializedInMemorySubstateStore::new();
+        base.put_substate(entry(1), value(1));
+
+        let mut overlay = OverlaySubstateStore::new(&mut base);
+        overlay.put_substate(entry(1), value(2));
+        overlay.put_substate(entry(2), value(2));
+        assert_eq!(overlay.get_substate(&entry(1)), Some(value(2)));
+        let changes = overlay.into_changes();
+
+        assert_eq!(changes.substates.len(), 2);
+        assert_eq!(base.get_substate(&entry(1)), Some(value(1)));
+        assert_eq!(base.get_substate(&entry(2)), None);
+    }
+
+    #[test]
+    fn test_commit_applies_writes() {
+        let mut base = SerializedInMemorySubstateStore::new();
+        base.put_substate(entry(1), value(1));
+
+        let mut overlay = OverlaySubstateStore::new(&mut base);
+        overlay.put_substate(entry(1), value(2));
+        overlay.put_substate(entry(2), value(2));
+        overlay.set_root(entry(2));
+        overlay.commit();
+
+        assert_eq!(base.get_substate(&entry(1)), Some(value(2)));
+        assert_eq!(base.get_substate(&entry(2)), Some(value(2)));
+        assert!(base.is_root(&entry(2)));
+    }
+}
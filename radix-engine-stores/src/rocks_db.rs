@@ -149,6 +149,24 @@ impl QueryableSubstateStore for RadixEngineDB {
         }
         items
     }
+
+    fn scan_prefix(&self, prefix: &[u8]) -> Vec<(SubstateId, OutputValue)> {
+        let iter = self
+            .db
+            .iterator(IteratorMode::From(prefix, Direction::Forward));
+        let mut items = Vec::new();
+        for kv in iter {
+            let (key, value) = kv.unwrap();
+            if !key.starts_with(prefix) {
+                break;
+            }
+            // Root markers share the key space but don't decode as substate ids
+            if let Ok(substate_id) = scrypto_decode::<SubstateId>(&key) {
+                items.push((substate_id, scrypto_decode(&value).unwrap()));
+            }
+        }
+        items
+    }
 }
 
 // Implement this as an enum for now to prevent clashes with Substates
@@ -169,10 +187,36 @@ impl ReadableSubstateStore for RadixEngineDB {
             .unwrap()
             .is_some()
     }
+
+    fn get_kv_store_entries_in_range(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        start: &[u8],
+        end: &[u8],
+        limit: usize,
+    ) -> Vec<(Vec<u8>, OutputValue)> {
+        // Keys are length-prefixed in the encoded substate ids, so they can't be range-scanned
+        let mut entries: Vec<(Vec<u8>, OutputValue)> = self
+            .scan_prefix(&kv_store_entry_prefix(*kv_store_id))
+            .into_iter()
+            .filter_map(|(substate_id, output_value)| match substate_id {
+                SubstateId::KeyValueStoreEntry(_, key)
+                    if start <= key.as_slice() && key.as_slice() <= end =>
+                {
+                    Some((key, output_value))
+                }
+                _ => None,
+            })
+            .collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        entries.truncate(limit);
+        entries
+    }
 }
 
 impl WriteableSubstateStore for RadixEngineDB {
-    fn put_substate(&mut self, substate_id: SubstateId, substate: OutputValue) {
+    fn put_substate(&mut self, substate_id: SubstateId, mut substate: OutputValue) {
+        substate.format_version = OUTPUT_VALUE_FORMAT_VERSION;
         self.write(substate_id, scrypto_encode(&substate));
     }
 
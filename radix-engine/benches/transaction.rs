@@ -64,6 +64,8 @@ fn bench_transaction_validation(c: &mut Criterion) {
             notary_as_signatory: true,
             cost_unit_limit: 1_000_000,
             tip_percentage: 5,
+            cost_unit_price: Decimal::zero(),
+            message: None,
         })
         .manifest(
             ManifestBuilder::new(&NetworkDefinition::simulator())
@@ -85,6 +87,7 @@ fn bench_transaction_validation(c: &mut Criterion) {
         current_epoch: 1,
         max_cost_unit_limit: 10_000_000,
         min_tip_percentage: 0,
+        min_cost_unit_price: Decimal::zero(),
     });
 
     c.bench_function("Transaction validation", |b| {
@@ -14,6 +14,9 @@ pub const DEFAULT_COST_UNIT_PRICE: &'static str = "0.0000001";
 /// The default max call depth.
 pub const DEFAULT_MAX_CALL_DEPTH: usize = 16;
 
+/// The default max size of a single key value store entry value, in encoded bytes.
+pub const DEFAULT_MAX_KV_VALUE_BYTES: usize = 1024 * 1024;
+
 pub const EXTRACT_ABI_CREDIT: u32 = 100_000_000;
 pub const PREVIEW_CREDIT: u32 = 100_000_000;
 pub const GENESIS_CREATION_CREDIT: u32 = 100_000_000;
@@ -67,11 +67,13 @@ impl CallFrame {
     }
 
     pub fn drop_owned_values(&mut self) -> Result<(), RuntimeError> {
-        let values = self
-            .owned_heap_nodes
-            .drain()
-            .map(|(_id, value)| value)
-            .collect();
+        let values: Vec<(RENodeId, HeapRootRENode)> = self.owned_heap_nodes.drain().collect();
+        if let Some((proof_id, bucket_id)) = HeapRENode::find_dangling_proof(&values) {
+            return Err(RuntimeError::DanglingProof {
+                proof_id,
+                bucket_id,
+            });
+        }
         HeapRENode::drop_nodes(values)
             .map_err(|e| RuntimeError::KernelError(KernelError::DropFailure(e)))
     }
@@ -31,6 +31,39 @@ pub enum RuntimeError {
 
     /// An error occurred within application logic, like the RE models.
     ApplicationError(ApplicationError),
+
+    /// A resource's transfer hook refused a vault deposit or withdrawal.
+    TransferHookRejected {
+        resource_address: ResourceAddress,
+        kind: TransferKind,
+    },
+
+    /// A resource's transfer hook moved resources which triggered the same hook again.
+    TransferHookReentered { resource_address: ResourceAddress },
+
+    /// A component deposited a resource it has not been restricted to.
+    ResourceNotAccepted {
+        component_address: ComponentAddress,
+        resource_address: ResourceAddress,
+    },
+
+    /// A proof was left behind together with the bucket it locks, so neither could be dropped.
+    DanglingProof {
+        proof_id: ProofId,
+        bucket_id: BucketId,
+    },
+
+    /// A read-only query attempted to change a substate.
+    WriteInQuery(SubstateId),
+
+    /// A method called with a budget consumed more cost units than allowed; its changes were reverted.
+    SubCallBudgetExceeded { max_cost_units: u32 },
+
+    /// The caller does not satisfy the access rule a method declares with `#[auth]`.
+    AccessDenied { method: String },
+
+    /// A key value store entry value exceeds the configured maximum encoded size.
+    KvValueTooLarge { size: usize, max_size: usize },
 }
 
 #[derive(Debug, Encode, Decode, TypeId)]
@@ -63,6 +96,8 @@ pub enum KernelError {
     RENodeGlobalizeTypeNotAllowed(RENodeId),
     RENodeCreateInvalidPermission,
     RENodeCreateNodeNotFound(RENodeId),
+    RENodeCreateInvalidId(RENodeId),
+    RENodeAlreadyExists(RENodeId),
     RENodeAlreadyTouched,
     RENodeNotInTrack,
 
@@ -140,13 +175,13 @@ pub enum ApplicationError {
 #[derive(Debug, PartialEq, Encode, Decode, TypeId)]
 pub enum DropFailure {
     System,
-    Resource,
-    Component,
-    Bucket,
+    Resource(ResourceAddress),
+    Component(ComponentAddress),
+    Bucket(BucketId),
     Worktop,
-    Vault,
-    Package,
-    KeyValueStore,
+    Vault(VaultId),
+    Package(PackageAddress),
+    KeyValueStore(KeyValueStoreId),
 }
 
 impl fmt::Display for RuntimeError {
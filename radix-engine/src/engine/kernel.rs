@@ -3,7 +3,7 @@ use transaction::model::Instruction;
 use transaction::validation::*;
 
 use crate::engine::*;
-use crate::fee::FeeReserve;
+use crate::fee::{FeeReserve, FeeReserveError};
 use crate::model::*;
 use crate::types::*;
 use crate::wasm::*;
@@ -35,6 +35,8 @@ pub struct Kernel<
     blobs: &'g HashMap<Hash, Vec<u8>>,
     /// The max call depth
     max_depth: usize,
+    /// The max encoded size of a key value store entry value
+    max_kv_value_bytes: usize,
 
     /// State track
     track: &'g mut Track<'s, R>,
@@ -72,6 +74,7 @@ where
         initial_proofs: Vec<NonFungibleAddress>,
         blobs: &'g HashMap<Hash, Vec<u8>>,
         max_depth: usize,
+        max_kv_value_bytes: usize,
         track: &'g mut Track<'s, R>,
         wasm_engine: &'g mut W,
         wasm_instrumenter: &'g mut WasmInstrumenter,
@@ -84,6 +87,7 @@ where
             transaction_hash,
             blobs,
             max_depth,
+            max_kv_value_bytes,
             track,
             wasm_engine,
             wasm_instrumenter,
@@ -143,6 +147,28 @@ where
         Ok(())
     }
 
+    /// Appends the defaults of the trailing inputs omitted by a caller.
+    fn fill_input_defaults(
+        fn_abi: &Fn,
+        input: &mut ScryptoValue,
+        fn_identifier: &FnIdentifier,
+    ) -> Result<(), RuntimeError> {
+        if let Some(args) = fn_abi.fill_input_defaults(&input.dom) {
+            let invalid_input = || {
+                RuntimeError::KernelError(KernelError::InvalidFnInput {
+                    fn_identifier: fn_identifier.clone(),
+                })
+            };
+            let filled = ScryptoValue::from_value(args).map_err(|_| invalid_input())?;
+            // Defaults are plain data and must not smuggle in any nodes
+            if filled.node_ids() != input.node_ids() {
+                return Err(invalid_input());
+            }
+            *input = filled;
+        }
+        Ok(())
+    }
+
     fn process_return_data(validated: &ScryptoValue) -> Result<(), RuntimeError> {
         if !validated.kv_store_ids.is_empty() {
             return Err(RuntimeError::KernelError(
@@ -249,6 +275,122 @@ where
         }
     }
 
+    fn create_node(
+        &mut self,
+        node_id: Option<RENodeId>,
+        re_node: HeapRENode,
+    ) -> Result<RENodeId, RuntimeError> {
+        for m in &mut self.modules {
+            m.pre_sys_call(
+                &mut self.track,
+                &mut self.call_frames,
+                SysCallInput::CreateNode { node: &re_node },
+            )
+            .map_err(RuntimeError::ModuleError)?;
+        }
+
+        // TODO: Authorization
+
+        // Only global RENodes can be created under a predetermined id
+        if let Some(node_id) = node_id {
+            let substate_id = match node_id {
+                RENodeId::ResourceManager(resource_address) => {
+                    SubstateId::ResourceManager(resource_address)
+                }
+                RENodeId::Component(component_address) => {
+                    SubstateId::ComponentInfo(component_address)
+                }
+                RENodeId::Package(package_address) => SubstateId::Package(package_address),
+                _ => {
+                    return Err(RuntimeError::KernelError(
+                        KernelError::RENodeCreateInvalidId(node_id),
+                    ))
+                }
+            };
+            if self.track.is_root(&substate_id) {
+                return Err(RuntimeError::KernelError(KernelError::RENodeAlreadyExists(
+                    node_id,
+                )));
+            }
+        }
+
+        // Take any required child nodes
+        let children = re_node.get_child_nodes()?;
+        let (taken_root_nodes, mut missing) =
+            Self::current_frame_mut(&mut self.call_frames).take_available_values(children, true)?;
+        let first_missing_node = missing.drain().nth(0);
+        if let Some(missing_node) = first_missing_node {
+            return Err(RuntimeError::KernelError(
+                KernelError::RENodeCreateNodeNotFound(missing_node),
+            ));
+        }
+        let mut child_nodes = HashMap::new();
+        for (id, taken_root_node) in taken_root_nodes {
+            child_nodes.extend(taken_root_node.to_nodes(id));
+        }
+
+        // Insert node into heap
+        let node_id = match node_id {
+            Some(node_id) => node_id,
+            None => Self::new_node_id(&mut self.id_allocator, self.transaction_hash, &re_node)
+                .map_err(|e| RuntimeError::KernelError(KernelError::IdAllocationError(e)))?,
+        };
+        let heap_root_node = HeapRootRENode {
+            root: re_node,
+            child_nodes,
+        };
+        Self::current_frame_mut(&mut self.call_frames)
+            .owned_heap_nodes
+            .insert(node_id, heap_root_node);
+
+        // TODO: Clean the following up
+        match node_id {
+            RENodeId::KeyValueStore(..) | RENodeId::ResourceManager(..) => {
+                let frame = self
+                    .call_frames
+                    .last_mut()
+                    .expect("Current call frame does not exist");
+                frame.node_refs.insert(
+                    node_id.clone(),
+                    RENodePointer::Heap {
+                        frame_id: frame.depth,
+                        root: node_id.clone(),
+                        id: None,
+                    },
+                );
+            }
+            RENodeId::Component(component_address) => {
+                let mut visible = HashSet::new();
+                visible.insert(SubstateId::ComponentInfo(component_address));
+
+                let frame = self
+                    .call_frames
+                    .last_mut()
+                    .expect("Current call frame does not exist");
+                frame.node_refs.insert(
+                    node_id.clone(),
+                    RENodePointer::Heap {
+                        frame_id: frame.depth,
+                        root: node_id.clone(),
+                        id: None,
+                    },
+                );
+            }
+            _ => {}
+        }
+
+        for m in &mut self.modules {
+            m.post_sys_call(
+                &mut self.track,
+                &mut self.call_frames,
+                SysCallOutput::CreateNode { node_id: &node_id },
+            )
+            .map_err(RuntimeError::ModuleError)?;
+        }
+
+        Ok(node_id)
+    }
+
     fn run(
         &mut self,
         auth_zone_frame_id: Option<usize>,
@@ -318,14 +460,15 @@ where
                         };
                         let mut runtime: Box<dyn WasmRuntime> =
                             Box::new(RadixEngineWasmRuntime::new(scrypto_actor, self));
-                        instance
-                            .invoke_export(&export_name, &input, &mut runtime)
-                            .map_err(|e| match e {
-                                InvokeError::Error(e) => {
-                                    RuntimeError::KernelError(KernelError::WasmError(e))
-                                }
-                                InvokeError::Downstream(runtime_error) => runtime_error,
-                            })?
+                        let result = instance.invoke_export(&export_name, &input, &mut runtime);
+                        drop(runtime);
+                        self.execution_trace.trace_wasm_memory(&instance);
+                        result.map_err(|e| match e {
+                            InvokeError::Error(e) => {
+                                RuntimeError::KernelError(KernelError::WasmError(e))
+                            }
+                            InvokeError::Downstream(runtime_error) => runtime_error,
+                        })?
                     };
 
                     let package = self
@@ -417,6 +560,15 @@ where
         Ok(())
     }
 
+    fn cost_units_consumed(&self) -> u32 {
+        self.track.fee_reserve.consumed_instant()
+    }
+
+    fn trace_instruction_cost(&mut self, instruction_index: usize, cost_units: u32) {
+        self.execution_trace
+            .trace_instruction_cost(instruction_index, cost_units);
+    }
+
     fn lock_fee(
         &mut self,
         vault_id: VaultId,
@@ -441,7 +593,7 @@ where
     fn invoke_function(
         &mut self,
         fn_identifier: FnIdentifier,
-        input: ScryptoValue,
+        mut input: ScryptoValue,
     ) -> Result<ScryptoValue, RuntimeError> {
         for m in &mut self.modules {
             m.pre_sys_call(
@@ -522,6 +674,7 @@ where
                 let fn_abi = abi.get_fn_abi(ident).ok_or(RuntimeError::KernelError(
                     KernelError::MethodNotFound(fn_identifier.clone()),
                 ))?;
+                Self::fill_input_defaults(fn_abi, &mut input, &fn_identifier)?;
                 if !fn_abi.input.matches(&input.dom) {
                     return Err(RuntimeError::KernelError(KernelError::InvalidFnInput {
                         fn_identifier,
@@ -648,7 +801,7 @@ where
         &mut self,
         receiver: Receiver,
         fn_identifier: FnIdentifier,
-        input: ScryptoValue,
+        mut input: ScryptoValue,
     ) -> Result<ScryptoValue, RuntimeError> {
         for m in &mut self.modules {
             m.pre_sys_call(
@@ -811,6 +964,21 @@ where
                             false,
                         ));
                         next_frame_node_refs.insert(package_node_id, package_node_pointer);
+
+                        if let FnIdentifier::Scrypto {
+                            blueprint_name,
+                            ident,
+                            ..
+                        } = &fn_identifier
+                        {
+                            let package = self.track.read_substate(package_substate_id).package();
+                            if let Some(fn_abi) = package
+                                .blueprint_abi(blueprint_name)
+                                .and_then(|abi| abi.get_fn_abi(ident))
+                            {
+                                Self::fill_input_defaults(fn_abi, &mut input, &fn_identifier)?;
+                            }
+                        }
                     }
                     RENodeId::Bucket(..) => {
                         let resource_address = {
@@ -849,6 +1017,131 @@ where
                             .map_err(RuntimeError::KernelError)?;
                         locked_pointers.push((resource_node_pointer, resource_substate_id, false));
                         next_frame_node_refs.insert(resource_node_id, resource_node_pointer);
+
+                        // Make the resource's transfer hook component visible to the vault
+                        let transfer_hook = {
+                            let node_ref =
+                                resource_node_pointer.to_ref(&self.call_frames, &self.track);
+                            node_ref.resource_manager().transfer_hook().cloned()
+                        };
+                        if let Some(transfer_hook) = &transfer_hook {
+                            let hook_node_id = RENodeId::Component(transfer_hook.component_address);
+                            let hook_node_pointer = RENodePointer::Store(hook_node_id);
+                            let info_substate_id =
+                                SubstateId::ComponentInfo(transfer_hook.component_address);
+                            hook_node_pointer
+                                .acquire_lock(
+                                    info_substate_id.clone(),
+                                    false,
+                                    false,
+                                    &mut self.track,
+                                )
+                                .map_err(RuntimeError::KernelError)?;
+                            locked_pointers.push((hook_node_pointer, info_substate_id, false));
+                            next_frame_node_refs.insert(hook_node_id, hook_node_pointer);
+                        }
+
+                        // The component operating the vault is checked for accepted resources
+                        // on deposit and reported to the transfer hook, if there is one
+                        if let Some(Receiver::Ref(
+                            caller_node_id @ RENodeId::Component(component_address),
+                        )) = &current_frame.actor.receiver
+                        {
+                            if let Some(pointer) = current_frame.node_refs.get(caller_node_id) {
+                                let info_substate_id =
+                                    SubstateId::ComponentInfo(*component_address);
+                                if matches!(
+                                    fn_identifier,
+                                    FnIdentifier::Native(NativeFnIdentifier::Vault(
+                                        VaultFnIdentifier::Put
+                                    ))
+                                ) {
+                                    pointer
+                                        .acquire_lock(
+                                            info_substate_id.clone(),
+                                            false,
+                                            false,
+                                            &mut self.track,
+                                        )
+                                        .map_err(RuntimeError::KernelError)?;
+                                    temporary_locks.push((
+                                        *pointer,
+                                        info_substate_id.clone(),
+                                        false,
+                                    ));
+                                    let accepted = {
+                                        let node_ref =
+                                            pointer.to_ref(&self.call_frames, &self.track);
+                                        node_ref.component_info().accepts(&resource_address)
+                                    };
+                                    if !accepted {
+                                        return Err(RuntimeError::ResourceNotAccepted {
+                                            component_address: *component_address,
+                                            resource_address,
+                                        });
+                                    }
+                                }
+                                if transfer_hook.is_some() {
+                                    pointer
+                                        .acquire_lock(
+                                            info_substate_id.clone(),
+                                            false,
+                                            false,
+                                            &mut self.track,
+                                        )
+                                        .map_err(RuntimeError::KernelError)?;
+                                    locked_pointers.push((*pointer, info_substate_id, false));
+                                    next_frame_node_refs.insert(*caller_node_id, pointer.clone());
+                                }
+                            }
+                        }
+                    }
+                    RENodeId::System
+                        if fn_identifier.eq(&FnIdentifier::Native(NativeFnIdentifier::System(
+                            SystemFnIdentifier::GetComponentMethods,
+                        ))) =>
+                    {
+                        // Lock the packages of referenced components so their ABIs can be read
+                        // TODO: Remove when references cleaned up
+                        for component_address in &input.refed_component_addresses {
+                            let component_node_id = RENodeId::Component(*component_address);
+                            let component_node_pointer = current_frame
+                                .node_refs
+                                .get(&component_node_id)
+                                .cloned()
+                                .ok_or(RuntimeError::KernelError(
+                                    KernelError::InvokeMethodInvalidReferencePass(
+                                        component_node_id,
+                                    ),
+                                ))?;
+                            let info_substate_id = SubstateId::ComponentInfo(*component_address);
+                            component_node_pointer
+                                .acquire_lock(info_substate_id.clone(), false, false, self.track)
+                                .map_err(RuntimeError::KernelError)?;
+                            let package_address = {
+                                let node_ref =
+                                    component_node_pointer.to_ref(&self.call_frames, self.track);
+                                node_ref.component_info().package_address()
+                            };
+                            component_node_pointer.release_lock(
+                                info_substate_id,
+                                false,
+                                self.track,
+                            );
+
+                            let package_substate_id = SubstateId::Package(package_address);
+                            let package_node_id = RENodeId::Package(package_address);
+                            let package_node_pointer = RENodePointer::Store(package_node_id);
+                            package_node_pointer
+                                .acquire_lock(package_substate_id.clone(), false, false, self.track)
+                                .map_err(RuntimeError::KernelError)?;
+                            locked_pointers.push((
+                                package_node_pointer,
+                                package_substate_id,
+                                false,
+                            ));
+                            next_frame_node_refs.insert(package_node_id, package_node_pointer);
+                        }
                     }
                     _ => {}
                 }
@@ -1016,6 +1309,65 @@ where
         Ok(output)
     }
 
+    fn invoke_method_with_budget(
+        &mut self,
+        receiver: Receiver,
+        fn_identifier: FnIdentifier,
+        input: ScryptoValue,
+        max_cost_units: u32,
+    ) -> Result<ScryptoValue, RuntimeError> {
+        // Only calls on global components can be reverted, and nothing owned may be passed along
+        match &receiver {
+            Receiver::Ref(node_id @ RENodeId::Component(..)) => {
+                if !matches!(
+                    Self::current_frame(&self.call_frames)
+                        .node_refs
+                        .get(node_id),
+                    Some(RENodePointer::Store(..))
+                ) {
+                    return Err(RuntimeError::KernelError(
+                        KernelError::InvokeMethodInvalidReceiver(*node_id),
+                    ));
+                }
+            }
+            Receiver::Ref(node_id) | Receiver::Consumed(node_id) => {
+                return Err(RuntimeError::KernelError(
+                    KernelError::InvokeMethodInvalidReceiver(*node_id),
+                ));
+            }
+            Receiver::CurrentAuthZone => {
+                return Err(RuntimeError::KernelError(KernelError::CantMoveAuthZone));
+            }
+        }
+        if !input.bucket_ids.is_empty() {
+            return Err(RuntimeError::KernelError(KernelError::BucketNotAllowed));
+        }
+        if !input.proof_ids.is_empty() {
+            return Err(RuntimeError::KernelError(KernelError::ProofNotAllowed));
+        }
+
+        let call_frames_len = self.call_frames.len();
+        let track_checkpoint = self.track.checkpoint();
+        let resource_changes = self.execution_trace.resource_changes.clone();
+
+        self.track.fee_reserve.push_budget(max_cost_units);
+        let result = self.invoke_method(receiver, fn_identifier, input);
+        self.track.fee_reserve.pop_budget();
+
+        match result {
+            Err(RuntimeError::ModuleError(ModuleError::CostingError(
+                FeeReserveError::BudgetExceeded,
+            ))) => {
+                // Abandon the call frames of the sub-call and revert its state changes
+                self.call_frames.truncate(call_frames_len);
+                self.track.rollback_to(track_checkpoint);
+                self.execution_trace.resource_changes = resource_changes;
+                Err(RuntimeError::SubCallBudgetExceeded { max_cost_units })
+            }
+            result => result,
+        }
+    }
+
     fn borrow_node(&mut self, node_id: &RENodeId) -> Result<RENodeRef<'_, 's, R>, RuntimeError> {
         for m in &mut self.modules {
             m.pre_sys_call(
@@ -1173,89 +1525,15 @@ where
     }
 
     fn node_create(&mut self, re_node: HeapRENode) -> Result<RENodeId, RuntimeError> {
-        for m in &mut self.modules {
-            m.pre_sys_call(
-                &mut self.track,
-                &mut self.call_frames,
-                SysCallInput::CreateNode { node: &re_node },
-            )
-            .map_err(RuntimeError::ModuleError)?;
-        }
-
-        // TODO: Authorization
-
-        // Take any required child nodes
-        let children = re_node.get_child_nodes()?;
-        let (taken_root_nodes, mut missing) =
-            Self::current_frame_mut(&mut self.call_frames).take_available_values(children, true)?;
-        let first_missing_node = missing.drain().nth(0);
-        if let Some(missing_node) = first_missing_node {
-            return Err(RuntimeError::KernelError(
-                KernelError::RENodeCreateNodeNotFound(missing_node),
-            ));
-        }
-        let mut child_nodes = HashMap::new();
-        for (id, taken_root_node) in taken_root_nodes {
-            child_nodes.extend(taken_root_node.to_nodes(id));
-        }
-
-        // Insert node into heap
-        let node_id = Self::new_node_id(&mut self.id_allocator, self.transaction_hash, &re_node)
-            .map_err(|e| RuntimeError::KernelError(KernelError::IdAllocationError(e)))?;
-        let heap_root_node = HeapRootRENode {
-            root: re_node,
-            child_nodes,
-        };
-        Self::current_frame_mut(&mut self.call_frames)
-            .owned_heap_nodes
-            .insert(node_id, heap_root_node);
-
-        // TODO: Clean the following up
-        match node_id {
-            RENodeId::KeyValueStore(..) | RENodeId::ResourceManager(..) => {
-                let frame = self
-                    .call_frames
-                    .last_mut()
-                    .expect("Current call frame does not exist");
-                frame.node_refs.insert(
-                    node_id.clone(),
-                    RENodePointer::Heap {
-                        frame_id: frame.depth,
-                        root: node_id.clone(),
-                        id: None,
-                    },
-                );
-            }
-            RENodeId::Component(component_address) => {
-                let mut visible = HashSet::new();
-                visible.insert(SubstateId::ComponentInfo(component_address));
-
-                let frame = self
-                    .call_frames
-                    .last_mut()
-                    .expect("Current call frame does not exist");
-                frame.node_refs.insert(
-                    node_id.clone(),
-                    RENodePointer::Heap {
-                        frame_id: frame.depth,
-                        root: node_id.clone(),
-                        id: None,
-                    },
-                );
-            }
-            _ => {}
-        }
-
-        for m in &mut self.modules {
-            m.post_sys_call(
-                &mut self.track,
-                &mut self.call_frames,
-                SysCallOutput::CreateNode { node_id: &node_id },
-            )
-            .map_err(RuntimeError::ModuleError)?;
-        }
+        self.create_node(None, re_node)
+    }
 
-        Ok(node_id)
+    fn node_create_with_id(
+        &mut self,
+        node_id: RENodeId,
+        re_node: HeapRENode,
+    ) -> Result<RENodeId, RuntimeError> {
+        self.create_node(Some(node_id), re_node)
     }
 
     fn node_globalize(&mut self, node_id: RENodeId) -> Result<(), RuntimeError> {
@@ -1418,6 +1696,133 @@ where
         Ok(current_value)
     }
 
+    fn kv_store_read_range(
+        &mut self,
+        kv_store_id: KeyValueStoreId,
+        start: Vec<u8>,
+        end: Vec<u8>,
+        limit: u32,
+    ) -> Result<Vec<(Vec<u8>, ScryptoValue)>, RuntimeError> {
+        for m in &mut self.modules {
+            m.pre_sys_call(
+                &mut self.track,
+                &mut self.call_frames,
+                SysCallInput::ReadKeyValueStoreRange {
+                    kv_store_id: &kv_store_id,
+                    limit,
+                },
+            )
+            .map_err(RuntimeError::ModuleError)?;
+        }
+
+        // Authorization, which doesn't depend on the key
+        let substate_id = SubstateId::KeyValueStoreEntry(kv_store_id, start.clone());
+        if !Self::current_frame(&self.call_frames)
+            .actor
+            .is_substate_readable(&substate_id)
+        {
+            return Err(RuntimeError::KernelError(
+                KernelError::SubstateReadNotReadable(
+                    Self::current_frame(&self.call_frames).actor.clone(),
+                    substate_id,
+                ),
+            ));
+        }
+
+        let node_id = RENodeId::KeyValueStore(kv_store_id);
+        let node_pointer = Self::current_frame(&self.call_frames)
+            .node_refs
+            .get(&node_id)
+            .cloned()
+            .ok_or(RuntimeError::KernelError(
+                KernelError::SubstateReadSubstateNotFound(SubstateId::KeyValueStoreSpace(
+                    kv_store_id,
+                )),
+            ))?;
+        let entries = node_pointer
+            .to_ref_mut(&mut self.call_frames, &mut self.track)
+            .kv_store_range(&start, &end, limit as usize);
+
+        for (_, value) in &entries {
+            for component_address in &value.refed_component_addresses {
+                let node_id = RENodeId::Component(*component_address);
+                Self::current_frame_mut(&mut self.call_frames)
+                    .node_refs
+                    .insert(node_id, RENodePointer::Store(node_id));
+            }
+            for child_id in value.node_ids() {
+                let child_pointer = node_pointer.child(child_id);
+                Self::current_frame_mut(&mut self.call_frames)
+                    .node_refs
+                    .insert(child_id, child_pointer);
+            }
+        }
+
+        for m in &mut self.modules {
+            m.post_sys_call(
+                &mut self.track,
+                &mut self.call_frames,
+                SysCallOutput::ReadKeyValueStoreRange { entries: &entries },
+            )
+            .map_err(RuntimeError::ModuleError)?;
+        }
+
+        Ok(entries)
+    }
+
+    fn kv_store_len(&mut self, kv_store_id: KeyValueStoreId) -> Result<u64, RuntimeError> {
+        let substate_id = SubstateId::KeyValueStoreSpace(kv_store_id);
+        for m in &mut self.modules {
+            m.pre_sys_call(
+                &mut self.track,
+                &mut self.call_frames,
+                SysCallInput::ReadSubstate {
+                    substate_id: &substate_id,
+                },
+            )
+            .map_err(RuntimeError::ModuleError)?;
+        }
+
+        // Authorization, which is the same as for any entry
+        let entry_substate_id = SubstateId::KeyValueStoreEntry(kv_store_id, Vec::new());
+        if !Self::current_frame(&self.call_frames)
+            .actor
+            .is_substate_readable(&entry_substate_id)
+        {
+            return Err(RuntimeError::KernelError(
+                KernelError::SubstateReadNotReadable(
+                    Self::current_frame(&self.call_frames).actor.clone(),
+                    substate_id,
+                ),
+            ));
+        }
+
+        let node_id = RENodeId::KeyValueStore(kv_store_id);
+        let node_pointer = Self::current_frame(&self.call_frames)
+            .node_refs
+            .get(&node_id)
+            .cloned()
+            .ok_or(RuntimeError::KernelError(
+                KernelError::SubstateReadSubstateNotFound(substate_id),
+            ))?;
+        let len = node_pointer
+            .to_ref_mut(&mut self.call_frames, &mut self.track)
+            .kv_store_len();
+
+        for m in &mut self.modules {
+            m.post_sys_call(
+                &mut self.track,
+                &mut self.call_frames,
+                SysCallOutput::ReadSubstate {
+                    value: &ScryptoValue::from_typed(&len),
+                },
+            )
+            .map_err(RuntimeError::ModuleError)?;
+        }
+
+        Ok(len)
+    }
+
     fn substate_take(&mut self, substate_id: SubstateId) -> Result<ScryptoValue, RuntimeError> {
         for m in &mut self.modules {
             m.pre_sys_call(
@@ -1498,6 +1903,15 @@ where
             ));
         }
 
+        if let SubstateId::KeyValueStoreEntry(..) = substate_id {
+            if value.raw.len() > self.max_kv_value_bytes {
+                return Err(RuntimeError::KvValueTooLarge {
+                    size: value.raw.len(),
+                    max_size: self.max_kv_value_bytes,
+                });
+            }
+        }
+
         // TODO: Do this in a better way once references cleaned up
         for component_address in &value.refed_component_addresses {
             if !self
@@ -1652,6 +2066,22 @@ where
         Ok(())
     }
 
+    fn emit_resource_event(
+        &mut self,
+        kind: ResourceEventKind,
+        resource_address: ResourceAddress,
+        amount: Decimal,
+    ) -> Result<(), RuntimeError> {
+        let actor = self.caller().cloned();
+        self.track.add_resource_event(ResourceEvent {
+            kind,
+            resource_address,
+            amount,
+            actor,
+        });
+        Ok(())
+    }
+
     fn check_access_rule(
         &mut self,
         access_rule: scrypto::resource::AccessRule,
@@ -1711,4 +2141,30 @@ where
             .expect(&format!("CallFrame #{} not found", frame_id))
             .auth_zone
     }
+
+    fn caller(&self) -> Option<&REActor> {
+        self.call_frames
+            .iter()
+            .rev()
+            .nth(1)
+            .map(|frame| &frame.actor)
+    }
+
+    fn is_on_call_stack(&self, actor: &REActor) -> bool {
+        self.call_frames.iter().any(|frame| frame.actor.eq(actor))
+    }
+
+    fn vault_transfer_hook(&self, vault_id: VaultId) -> Option<TransferHook> {
+        let current_frame = Self::current_frame(&self.call_frames);
+        let vault_pointer = current_frame.node_refs.get(&RENodeId::Vault(vault_id))?;
+        let resource_address = vault_pointer
+            .to_ref(&self.call_frames, &self.track)
+            .vault()
+            .resource_address();
+        let resource_pointer = current_frame
+            .node_refs
+            .get(&RENodeId::ResourceManager(resource_address))?;
+        let node_ref = resource_pointer.to_ref(&self.call_frames, &self.track);
+        node_ref.resource_manager().transfer_hook().cloned()
+    }
 }
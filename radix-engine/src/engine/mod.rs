@@ -7,6 +7,7 @@ mod native_interpreter;
 mod node;
 mod node_properties;
 mod node_ref;
+mod resource_event;
 mod system_api;
 mod track;
 mod track_support;
@@ -21,6 +22,7 @@ pub use native_interpreter::NativeInterpreter;
 pub use node::*;
 pub use node_properties::*;
 pub use node_ref::*;
+pub use resource_event::*;
 pub use system_api::SystemApi;
 pub use track::*;
 pub use track_support::*;
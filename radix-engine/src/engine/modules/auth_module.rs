@@ -73,7 +73,9 @@ impl AuthModule {
             }
             (
                 Receiver::Ref(RENodeId::System),
-                FnIdentifier::Native(NativeFnIdentifier::System(SystemFnIdentifier::SetEpoch)),
+                FnIdentifier::Native(NativeFnIdentifier::System(
+                    SystemFnIdentifier::SetEpoch | SystemFnIdentifier::SetTime,
+                )),
             ) => {
                 vec![MethodAuthorization::Protected(HardAuthRule::ProofRule(
                     HardProofRule::Require(HardResourceOrNonFungible::Resource(SYSTEM_TOKEN)),
@@ -110,12 +112,35 @@ impl AuthModule {
                     }));
                 }
 
-                {
+                let (declared_auth, method_auths) = {
                     let value_ref = node_pointer.to_ref(call_frames, track);
                     let component = value_ref.component_info();
                     let component_state = value_ref.component_state();
-                    component.method_authorization(component_state, &abi.structure, ident)
+                    let declared_auth = fn_abi.auth.as_ref().map(|encoded| {
+                        // An undecodable rule can never be satisfied
+                        let access_rule: AccessRule =
+                            scrypto_decode(encoded).unwrap_or(AccessRule::DenyAll);
+                        component.declared_method_authorization(
+                            component_state,
+                            &abi.structure,
+                            &access_rule,
+                        )
+                    });
+                    (
+                        declared_auth,
+                        component.method_authorization(component_state, &abi.structure, ident),
+                    )
+                };
+
+                if let Some(declared_auth) = declared_auth {
+                    Self::auth(function, vec![declared_auth], call_frames).map_err(|_| {
+                        RuntimeError::AccessDenied {
+                            method: ident.clone(),
+                        }
+                    })?;
                 }
+
+                method_auths
             }
             (
                 Receiver::Ref(RENodeId::Vault(..)),
@@ -367,6 +367,9 @@ impl<R: FeeReserve> Module<R> for CostingModule {
                     )
                     .map_err(ModuleError::CostingError)?;
             }
+            SysCallInput::ReadKeyValueStoreRange { .. } => {
+                // Charged per entry read, once the entries are known
+            }
             SysCallInput::ReadTransactionHash => {
                 track
                     .fee_reserve
@@ -439,10 +442,28 @@ impl<R: FeeReserve> Module<R> for CostingModule {
 
     fn post_sys_call(
         &mut self,
-        _track: &mut Track<R>,
+        track: &mut Track<R>,
         _heap: &mut Vec<CallFrame>,
-        _output: SysCallOutput,
+        output: SysCallOutput,
     ) -> Result<(), ModuleError> {
+        // Every entry of a range read costs as much as reading it by key
+        if let SysCallOutput::ReadKeyValueStoreRange { entries } = output {
+            for (_, value) in entries {
+                track
+                    .fee_reserve
+                    .consume(
+                        track
+                            .fee_table
+                            .system_api_cost(SystemApiCostingEntry::ReadSubstate {
+                                size: value.raw.len() as u32,
+                            }),
+                        "read_substate",
+                        false,
+                    )
+                    .map_err(ModuleError::CostingError)?;
+            }
+        }
+
         Ok(())
     }
 
@@ -468,9 +489,10 @@ impl<R: FeeReserve> Module<R> for CostingModule {
         _heap: &mut Vec<CallFrame>,
         units: u32,
     ) -> Result<(), ModuleError> {
+        let cost_units = units.saturating_mul(track.fee_table.wasm_execution_per_unit());
         track
             .fee_reserve
-            .consume(units, "run_wasm", false)
+            .consume(cost_units, "run_wasm", false)
             .map_err(ModuleError::CostingError)
     }
 
@@ -2,6 +2,7 @@ use crate::engine::*;
 use crate::fee::FeeReserve;
 use crate::model::*;
 use crate::types::*;
+use crate::wasm::WasmInstance;
 
 #[derive(Debug, Clone, PartialEq, TypeId, Encode, Decode)]
 pub struct ResourceChange {
@@ -11,23 +12,78 @@ pub struct ResourceChange {
     pub amount: Decimal,
 }
 
+/// A proof present in an auth zone at the time of a method call.
+#[derive(Debug, Clone, PartialEq, Eq, TypeId, Encode, Decode)]
+pub struct ProofSnapshot {
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+}
+
+/// The proofs visible to authorization when a method was invoked.
+#[derive(Debug, Clone, PartialEq, Eq, TypeId, Encode, Decode)]
+pub struct AuthZoneTraceEntry {
+    pub fn_identifier: FnIdentifier,
+    pub node_id: RENodeId,
+    pub proofs: Vec<ProofSnapshot>,
+}
+
+impl AuthZoneTraceEntry {
+    pub fn contains_proof_of(&self, resource_address: &ResourceAddress) -> bool {
+        self.proofs
+            .iter()
+            .any(|proof| proof.resource_address.eq(resource_address))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ExecutionTraceReceipt {
     pub resource_changes: Vec<ResourceChange>,
+    pub peak_wasm_memory_bytes: Option<usize>,
+    pub auth_zone_trace: Option<Vec<AuthZoneTraceEntry>>,
+    pub instruction_cost_units: Vec<(usize, u32)>,
 }
 
 #[derive(Debug)]
 pub struct ExecutionTrace {
     pub resource_changes: HashMap<ComponentAddress, HashMap<VaultId, (ResourceAddress, Decimal)>>,
+    /// The largest linear memory of any WASM invocation, if memory profiling is enabled.
+    pub peak_wasm_memory_bytes: Option<usize>,
+    /// The auth zone state at each method invocation, if auth zone tracing is enabled.
+    pub auth_zone_trace: Option<Vec<AuthZoneTraceEntry>>,
+    /// The cost units consumed by each executed manifest instruction, by instruction index.
+    pub instruction_cost_units: Vec<(usize, u32)>,
 }
 
 impl ExecutionTrace {
     pub fn new() -> ExecutionTrace {
         Self {
             resource_changes: HashMap::new(),
+            peak_wasm_memory_bytes: None,
+            auth_zone_trace: None,
+            instruction_cost_units: Vec::new(),
         }
     }
 
+    pub fn enable_auth_zone_tracing(&mut self) {
+        self.auth_zone_trace.get_or_insert(Vec::new());
+    }
+
+    pub fn enable_wasm_memory_profiling(&mut self) {
+        self.peak_wasm_memory_bytes.get_or_insert(0);
+    }
+
+    /// Records the memory usage of a WASM instance, if memory profiling is enabled.
+    pub fn trace_wasm_memory<I: WasmInstance>(&mut self, instance: &I) {
+        if let Some(peak) = &mut self.peak_wasm_memory_bytes {
+            *peak = (*peak).max(instance.memory_size());
+        }
+    }
+
+    pub fn trace_instruction_cost(&mut self, instruction_index: usize, cost_units: u32) {
+        self.instruction_cost_units
+            .push((instruction_index, cost_units));
+    }
+
     pub fn trace_invoke_method<'s, R: FeeReserve>(
         &mut self,
         call_frames: &Vec<CallFrame>,
@@ -39,6 +95,25 @@ impl ExecutionTrace {
         input: &ScryptoValue,
         next_owned_values: &HashMap<RENodeId, HeapRootRENode>,
     ) -> Result<(), RuntimeError> {
+        if let Some(auth_zone_trace) = &mut self.auth_zone_trace {
+            // Same auth zones as the ones used by `AuthModule`
+            let proofs = call_frames
+                .iter()
+                .rev()
+                .take(2)
+                .flat_map(|frame| frame.auth_zone.proofs.iter())
+                .map(|proof| ProofSnapshot {
+                    resource_address: proof.resource_address(),
+                    amount: proof.total_amount(),
+                })
+                .collect();
+            auth_zone_trace.push(AuthZoneTraceEntry {
+                fn_identifier: fn_identifier.clone(),
+                node_id: *node_id,
+                proofs,
+            });
+        }
+
         if let RENodeId::Vault(vault_id) = node_id {
             /* TODO: Warning: depends on call frame's actor being the vault's parent component!
             This isn't always the case! For example, when vault is instantiated in a blueprint
@@ -174,6 +249,11 @@ impl ExecutionTrace {
             })
             .filter(|el| !el.amount.is_zero())
             .collect();
-        ExecutionTraceReceipt { resource_changes }
+        ExecutionTraceReceipt {
+            resource_changes,
+            peak_wasm_memory_bytes: self.peak_wasm_memory_bytes,
+            auth_zone_trace: self.auth_zone_trace,
+            instruction_cost_units: self.instruction_cost_units,
+        }
     }
 }
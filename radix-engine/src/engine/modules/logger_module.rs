@@ -95,6 +95,14 @@ impl<R: FeeReserve> Module<R> for LoggerModule {
             SysCallInput::TakeSubstate { substate_id } => {
                 log!(self, "Taking substate: substate_id = {:?}", substate_id);
             }
+            SysCallInput::ReadKeyValueStoreRange { kv_store_id, limit } => {
+                log!(
+                    self,
+                    "Reading key value store range: kv_store_id = {:?}, limit = {}",
+                    kv_store_id,
+                    limit
+                );
+            }
             SysCallInput::ReadTransactionHash => {
                 log!(self, "Reading transaction hash");
             }
@@ -139,6 +147,7 @@ impl<R: FeeReserve> Module<R> for LoggerModule {
             SysCallOutput::ReadSubstate { .. } => {}
             SysCallOutput::WriteSubstate { .. } => {}
             SysCallOutput::TakeSubstate { .. } => {}
+            SysCallOutput::ReadKeyValueStoreRange { .. } => {}
             SysCallOutput::ReadTransactionHash { .. } => {}
             SysCallOutput::ReadBlob { .. } => {}
             SysCallOutput::GenerateUuid { .. } => {}
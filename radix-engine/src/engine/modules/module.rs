@@ -41,6 +41,10 @@ pub enum SysCallInput<'a> {
     TakeSubstate {
         substate_id: &'a SubstateId,
     },
+    ReadKeyValueStoreRange {
+        kv_store_id: &'a KeyValueStoreId,
+        limit: u32,
+    },
     ReadTransactionHash,
     ReadBlob {
         blob_hash: &'a Hash,
@@ -57,22 +61,49 @@ pub enum SysCallInput<'a> {
 }
 
 pub enum SysCallOutput<'a> {
-    InvokeFunction { output: &'a ScryptoValue },
-    InvokeMethod { output: &'a ScryptoValue },
-    BorrowNode { node_pointer: &'a RENodePointer },
-    DropNode { node: &'a HeapRootRENode },
-    CreateNode { node_id: &'a RENodeId },
+    InvokeFunction {
+        output: &'a ScryptoValue,
+    },
+    InvokeMethod {
+        output: &'a ScryptoValue,
+    },
+    BorrowNode {
+        node_pointer: &'a RENodePointer,
+    },
+    DropNode {
+        node: &'a HeapRootRENode,
+    },
+    CreateNode {
+        node_id: &'a RENodeId,
+    },
     GlobalizeNode,
-    BorrowSubstateMut { substate_ref: &'a NativeSubstateRef },
+    BorrowSubstateMut {
+        substate_ref: &'a NativeSubstateRef,
+    },
     ReturnSubstateMut,
-    ReadSubstate { value: &'a ScryptoValue },
+    ReadSubstate {
+        value: &'a ScryptoValue,
+    },
     WriteSubstate,
-    TakeSubstate { value: &'a ScryptoValue },
-    ReadTransactionHash { hash: &'a Hash },
-    ReadBlob { blob: &'a [u8] },
-    GenerateUuid { uuid: u128 },
+    TakeSubstate {
+        value: &'a ScryptoValue,
+    },
+    ReadKeyValueStoreRange {
+        entries: &'a [(Vec<u8>, ScryptoValue)],
+    },
+    ReadTransactionHash {
+        hash: &'a Hash,
+    },
+    ReadBlob {
+        blob: &'a [u8],
+    },
+    GenerateUuid {
+        uuid: u128,
+    },
     EmitLog,
-    CheckAccessRule { result: bool },
+    CheckAccessRule {
+        result: bool,
+    },
 }
 
 pub trait Module<R: FeeReserve> {
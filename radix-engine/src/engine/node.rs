@@ -14,6 +14,7 @@ pub enum Substate {
     Vault(Vault),
     NonFungible(NonFungibleWrapper),
     KeyValueStoreEntry(KeyValueStoreEntryWrapper),
+    KeyValueStoreSpace(KeyValueStoreSpace),
 }
 
 impl Substate {
@@ -112,6 +113,14 @@ impl Substate {
             panic!("Not a KVEntry");
         }
     }
+
+    pub fn kv_store_space(&self) -> &KeyValueStoreSpace {
+        if let Substate::KeyValueStoreSpace(kv_store_space) = self {
+            kv_store_space
+        } else {
+            panic!("Not a KVSpace");
+        }
+    }
 }
 
 impl Into<Substate> for System {
@@ -162,6 +171,12 @@ impl Into<Substate> for KeyValueStoreEntryWrapper {
     }
 }
 
+impl Into<Substate> for KeyValueStoreSpace {
+    fn into(self) -> Substate {
+        Substate::KeyValueStoreSpace(self)
+    }
+}
+
 impl Into<ComponentInfo> for Substate {
     fn into(self) -> ComponentInfo {
         if let Substate::ComponentInfo(component) = self {
@@ -419,14 +434,14 @@ impl HeapRENode {
         }
     }
 
-    pub fn try_drop(self) -> Result<(), DropFailure> {
+    pub fn try_drop(self, node_id: RENodeId) -> Result<(), DropFailure> {
         match self {
-            HeapRENode::Package(..) => Err(DropFailure::Package),
-            HeapRENode::Vault(..) => Err(DropFailure::Vault),
-            HeapRENode::KeyValueStore(..) => Err(DropFailure::KeyValueStore),
-            HeapRENode::Component(..) => Err(DropFailure::Component),
-            HeapRENode::Bucket(..) => Err(DropFailure::Bucket),
-            HeapRENode::Resource(..) => Err(DropFailure::Resource),
+            HeapRENode::Package(..) => Err(DropFailure::Package(node_id.into())),
+            HeapRENode::Vault(..) => Err(DropFailure::Vault(node_id.into())),
+            HeapRENode::KeyValueStore(..) => Err(DropFailure::KeyValueStore(node_id.into())),
+            HeapRENode::Component(..) => Err(DropFailure::Component(node_id.into())),
+            HeapRENode::Bucket(..) => Err(DropFailure::Bucket(node_id.into())),
+            HeapRENode::Resource(..) => Err(DropFailure::Resource(node_id.into())),
             HeapRENode::System(..) => Err(DropFailure::System),
             HeapRENode::Proof(proof) => {
                 proof.drop();
@@ -436,13 +451,41 @@ impl HeapRENode {
         }
     }
 
-    pub fn drop_nodes(nodes: Vec<HeapRootRENode>) -> Result<(), DropFailure> {
+    /// Finds a proof which still locks a bucket from the given nodes, returning the ids of
+    /// both. Such a bucket can't be dropped for as long as the proof exists.
+    pub fn find_dangling_proof(
+        nodes: &[(RENodeId, HeapRootRENode)],
+    ) -> Option<(ProofId, BucketId)> {
+        let bucket_ids: Vec<BucketId> = nodes
+            .iter()
+            .filter_map(|(node_id, _)| match node_id {
+                RENodeId::Bucket(bucket_id) => Some(*bucket_id),
+                _ => None,
+            })
+            .collect();
+
+        nodes
+            .iter()
+            .filter_map(|(node_id, node)| match (node_id, node.root()) {
+                (RENodeId::Proof(proof_id), HeapRENode::Proof(proof)) => bucket_ids
+                    .iter()
+                    .filter(|bucket_id| {
+                        proof.is_backed_by(&ResourceContainerId::Bucket(**bucket_id))
+                    })
+                    .min()
+                    .map(|bucket_id| (*proof_id, *bucket_id)),
+                _ => None,
+            })
+            .min()
+    }
+
+    pub fn drop_nodes(nodes: Vec<(RENodeId, HeapRootRENode)>) -> Result<(), DropFailure> {
         let mut worktops = Vec::new();
-        for node in nodes {
+        for (node_id, node) in nodes {
             if let HeapRENode::Worktop(worktop) = node.root {
                 worktops.push(worktop);
             } else {
-                node.try_drop()?;
+                node.try_drop(node_id)?;
             }
         }
         for worktop in worktops {
@@ -504,8 +547,8 @@ impl HeapRootRENode {
         nodes
     }
 
-    pub fn try_drop(self) -> Result<(), DropFailure> {
-        self.root.try_drop()
+    pub fn try_drop(self, node_id: RENodeId) -> Result<(), DropFailure> {
+        self.root.try_drop(node_id)
     }
 }
 
@@ -515,6 +515,63 @@ impl<'f, 's, R: FeeReserve> RENodeRefMut<'f, 's, R> {
             .expect("Failed to convert non-fungible value to Scrypto value")
     }
 
+    pub fn kv_store_range(
+        &mut self,
+        start: &[u8],
+        end: &[u8],
+        limit: usize,
+    ) -> Vec<(Vec<u8>, ScryptoValue)> {
+        match self {
+            RENodeRefMut::Stack(re_value, id) => {
+                let store = re_value.get_node_mut(id.as_ref()).kv_store_mut();
+                let mut entries: Vec<(Vec<u8>, ScryptoValue)> = store
+                    .store
+                    .iter()
+                    .filter(|(key, _)| start <= key.as_slice() && key.as_slice() <= end)
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect();
+                entries.sort_by(|a, b| a.0.cmp(&b.0));
+                entries.truncate(limit);
+                entries
+            }
+            RENodeRefMut::Track(track, node_id) => {
+                let kv_store_id = match node_id {
+                    RENodeId::KeyValueStore(kv_store_id) => *kv_store_id,
+                    _ => panic!("Unexpeceted"),
+                };
+                track
+                    .read_key_value_range(&kv_store_id, start, end, limit)
+                    .into_iter()
+                    .filter_map(|(key, substate)| {
+                        let wrapper: KeyValueStoreEntryWrapper = substate.into();
+                        wrapper.0.map(|v| {
+                            let value = ScryptoValue::from_slice(&v)
+                                .expect("Failed to decode the value in KeyValueStoreEntryWrapper");
+                            (key, value)
+                        })
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    pub fn kv_store_len(&mut self) -> u64 {
+        match self {
+            RENodeRefMut::Stack(re_value, id) => re_value
+                .get_node_mut(id.as_ref())
+                .kv_store_mut()
+                .store
+                .len() as u64,
+            RENodeRefMut::Track(track, node_id) => {
+                let kv_store_id = match node_id {
+                    RENodeId::KeyValueStore(kv_store_id) => *kv_store_id,
+                    _ => panic!("Unexpeceted"),
+                };
+                track.key_value_store_len(&kv_store_id)
+            }
+        }
+    }
+
     pub fn non_fungible_get(&mut self, id: &NonFungibleId) -> ScryptoValue {
         let wrapper = match self {
             RENodeRefMut::Stack(value, re_id) => {
@@ -0,0 +1,18 @@
+use crate::engine::REActor;
+use crate::types::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TypeId, Encode, Decode)]
+pub enum ResourceEventKind {
+    Minted,
+    Burned,
+}
+
+/// A change to the supply of a resource, emitted by the engine on every mint and burn.
+#[derive(Debug, Clone, PartialEq, Eq, TypeId, Encode, Decode)]
+pub struct ResourceEvent {
+    pub kind: ResourceEventKind,
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+    /// The actor which minted or burned, e.g. the calling blueprint.
+    pub actor: Option<REActor>,
+}
@@ -15,8 +15,24 @@ where
     // TODO: possible to consider AuthZone as a RENode?
     fn auth_zone(&mut self, frame_id: usize) -> &mut AuthZone;
 
+    /// Returns the actor of the frame which invoked the current one
+    fn caller(&self) -> Option<&REActor>;
+
+    /// Returns whether any frame on the call stack runs the given actor
+    fn is_on_call_stack(&self, actor: &REActor) -> bool;
+
+    /// Returns the transfer hook of the resource held by a vault visible to the current frame,
+    /// read from the resource manager locked for the invocation rather than borrowed
+    fn vault_transfer_hook(&self, vault_id: VaultId) -> Option<TransferHook>;
+
     fn consume_cost_units(&mut self, units: u32) -> Result<(), RuntimeError>;
 
+    /// Returns the number of cost units consumed by the transaction execution so far
+    fn cost_units_consumed(&self) -> u32;
+
+    /// Records the cost units consumed by a manifest instruction
+    fn trace_instruction_cost(&mut self, instruction_index: usize, cost_units: u32);
+
     fn lock_fee(
         &mut self,
         vault_id: VaultId,
@@ -37,6 +53,18 @@ where
         input: ScryptoValue,
     ) -> Result<ScryptoValue, RuntimeError>;
 
+    /// Invokes a method which may consume at most `max_cost_units`.
+    ///
+    /// If the budget runs out, the state changes made by the call are reverted and
+    /// `RuntimeError::SubCallBudgetExceeded` is returned, leaving the caller able to continue.
+    fn invoke_method_with_budget(
+        &mut self,
+        receiver: Receiver,
+        function: FnIdentifier,
+        input: ScryptoValue,
+        max_cost_units: u32,
+    ) -> Result<ScryptoValue, RuntimeError>;
+
     // TODO: Convert to substate_borrow
     fn borrow_node(&mut self, node_id: &RENodeId) -> Result<RENodeRef<'_, 's, R>, RuntimeError>;
 
@@ -46,6 +74,13 @@ where
     /// Creates a new RENode and places it in the Heap
     fn node_create(&mut self, re_node: HeapRENode) -> Result<RENodeId, RuntimeError>;
 
+    /// Creates a new global RENode under a predetermined id and places it in the Heap
+    fn node_create_with_id(
+        &mut self,
+        node_id: RENodeId,
+        re_node: HeapRENode,
+    ) -> Result<RENodeId, RuntimeError>;
+
     /// Moves an RENode from Heap to Store
     fn node_globalize(&mut self, node_id: RENodeId) -> Result<(), RuntimeError>;
 
@@ -67,6 +102,19 @@ where
     ) -> Result<(), RuntimeError>;
     fn substate_take(&mut self, substate_id: SubstateId) -> Result<ScryptoValue, RuntimeError>;
 
+    /// Reads the first `limit` key value store entries whose encoded keys lie within
+    /// `start..=end`, ordered by encoded key.
+    fn kv_store_read_range(
+        &mut self,
+        kv_store_id: KeyValueStoreId,
+        start: Vec<u8>,
+        end: Vec<u8>,
+        limit: u32,
+    ) -> Result<Vec<(Vec<u8>, ScryptoValue)>, RuntimeError>;
+
+    /// Returns the number of entries in a key value store.
+    fn kv_store_len(&mut self, kv_store_id: KeyValueStoreId) -> Result<u64, RuntimeError>;
+
     fn transaction_hash(&mut self) -> Result<Hash, RuntimeError>;
 
     fn read_blob(&mut self, blob_hash: &Hash) -> Result<&[u8], RuntimeError>;
@@ -75,6 +123,14 @@ where
 
     fn emit_log(&mut self, level: Level, message: String) -> Result<(), RuntimeError>;
 
+    /// Records a mint or burn on behalf of the calling actor.
+    fn emit_resource_event(
+        &mut self,
+        kind: ResourceEventKind,
+        resource_address: ResourceAddress,
+        amount: Decimal,
+    ) -> Result<(), RuntimeError>;
+
     fn check_access_rule(
         &mut self,
         access_rule: AccessRule,
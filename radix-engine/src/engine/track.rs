@@ -11,6 +11,7 @@ use crate::fee::FeeTable;
 use crate::ledger::*;
 use crate::model::Bucket;
 use crate::model::KeyValueStoreEntryWrapper;
+use crate::model::KeyValueStoreSpace;
 use crate::model::NonFungibleWrapper;
 use crate::model::ResourceContainer;
 use crate::transaction::CommitResult;
@@ -20,7 +21,7 @@ use crate::transaction::TransactionOutcome;
 use crate::transaction::TransactionResult;
 use crate::types::*;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum BorrowedSubstate {
     Loaded(Substate, u32),
     LoadedMut(Substate),
@@ -40,6 +41,7 @@ impl BorrowedSubstate {
 /// Transaction-wide states and side effects
 pub struct Track<'s, R: FeeReserve> {
     application_logs: Vec<(Level, String)>,
+    resource_events: Vec<ResourceEvent>,
     new_substates: Vec<SubstateId>,
     state_track: AppStateTrack<'s>,
     borrowed_substates: HashMap<SubstateId, BorrowedSubstate>,
@@ -47,6 +49,15 @@ pub struct Track<'s, R: FeeReserve> {
     pub fee_table: FeeTable,
 }
 
+/// A snapshot of the track, see `Track::checkpoint`.
+pub struct TrackCheckpoint {
+    application_logs_len: usize,
+    resource_events_len: usize,
+    new_substates_len: usize,
+    state_track: AppStateTrackCheckpoint,
+    borrowed_substates: HashMap<SubstateId, BorrowedSubstate>,
+}
+
 #[derive(Debug)]
 pub enum TrackError {
     Reentrancy,
@@ -76,6 +87,7 @@ impl<'s, R: FeeReserve> Track<'s, R> {
 
         Self {
             application_logs: Vec::new(),
+            resource_events: Vec::new(),
             new_substates: Vec::new(),
             state_track,
             borrowed_substates: HashMap::new(),
@@ -89,6 +101,11 @@ impl<'s, R: FeeReserve> Track<'s, R> {
         self.application_logs.push((level, message));
     }
 
+    /// Adds a resource supply change event.
+    pub fn add_resource_event(&mut self, event: ResourceEvent) {
+        self.resource_events.push(event);
+    }
+
     /// Creates a row with the given key/value
     pub fn create_uuid_substate<V: Into<Substate>>(
         &mut self,
@@ -104,6 +121,29 @@ impl<'s, R: FeeReserve> Track<'s, R> {
         }
     }
 
+    /// Captures the application state, logs and locks, so that they can be restored with `rollback_to`.
+    ///
+    /// Fee payments and substates written through to the base track are not covered.
+    pub fn checkpoint(&self) -> TrackCheckpoint {
+        TrackCheckpoint {
+            application_logs_len: self.application_logs.len(),
+            resource_events_len: self.resource_events.len(),
+            new_substates_len: self.new_substates.len(),
+            state_track: self.state_track.checkpoint(),
+            borrowed_substates: self.borrowed_substates.clone(),
+        }
+    }
+
+    pub fn rollback_to(&mut self, checkpoint: TrackCheckpoint) {
+        self.application_logs
+            .truncate(checkpoint.application_logs_len);
+        self.resource_events
+            .truncate(checkpoint.resource_events_len);
+        self.new_substates.truncate(checkpoint.new_substates_len);
+        self.state_track.rollback_to(checkpoint.state_track);
+        self.borrowed_substates = checkpoint.borrowed_substates;
+    }
+
     // TODO: Clean this up
     pub fn is_root(&mut self, substate_id: &SubstateId) -> bool {
         self.state_track.is_root(substate_id)
@@ -217,6 +257,17 @@ impl<'s, R: FeeReserve> Track<'s, R> {
         }
     }
 
+    /// Returns the ids of the substates which have been created or changed so far.
+    pub fn changed_substate_ids(&self) -> Vec<SubstateId> {
+        let mut changed = self.state_track.changed_substate_ids();
+        for substate_id in &self.new_substates {
+            if !changed.contains(substate_id) {
+                changed.push(substate_id.clone());
+            }
+        }
+        changed
+    }
+
     pub fn read_substate(&self, substate_id: SubstateId) -> &Substate {
         match self
             .borrowed_substates
@@ -285,6 +336,26 @@ impl<'s, R: FeeReserve> Track<'s, R> {
         }
     }
 
+    /// Returns the first `limit` key value store entries whose keys lie within `start..=end`,
+    /// ordered by key.
+    pub fn read_key_value_range(
+        &mut self,
+        kv_store_id: &KeyValueStoreId,
+        start: &[u8],
+        end: &[u8],
+        limit: usize,
+    ) -> Vec<(Vec<u8>, Substate)> {
+        self.state_track
+            .get_kv_store_entries_in_range(kv_store_id, start, end, limit)
+    }
+
+    /// Returns the number of entries in a key value store
+    pub fn key_value_store_len(&mut self, kv_store_id: &KeyValueStoreId) -> u64 {
+        self.state_track
+            .get_substate(&SubstateId::KeyValueStoreSpace(*kv_store_id))
+            .map_or(0, |substate| substate.kv_store_space().len)
+    }
+
     /// Sets a key value
     pub fn set_key_value<V: Into<Substate>>(
         &mut self,
@@ -292,18 +363,36 @@ impl<'s, R: FeeReserve> Track<'s, R> {
         key: Vec<u8>,
         value: V,
     ) {
+        let value = value.into();
+
         // TODO: consider using a single address as function input
         let substate_id = match parent_substate_id {
             SubstateId::NonFungibleSpace(resource_address) => {
                 SubstateId::NonFungible(resource_address, NonFungibleId(key.clone()))
             }
             SubstateId::KeyValueStoreSpace(kv_store_id) => {
-                SubstateId::KeyValueStoreEntry(kv_store_id, key.clone())
+                let substate_id = SubstateId::KeyValueStoreEntry(kv_store_id, key.clone());
+                let existed = matches!(
+                    self.state_track.get_substate(&substate_id),
+                    Some(Substate::KeyValueStoreEntry(KeyValueStoreEntryWrapper(
+                        Some(_)
+                    )))
+                );
+                let exists = value.kv_entry().0.is_some();
+                if existed != exists {
+                    let len = self.key_value_store_len(&kv_store_id);
+                    let len = if exists { len + 1 } else { len - 1 };
+                    self.state_track.put_substate(
+                        SubstateId::KeyValueStoreSpace(kv_store_id),
+                        KeyValueStoreSpace { len }.into(),
+                    );
+                }
+                substate_id
             }
             _ => panic!("Unsupported key value"),
         };
 
-        self.state_track.put_substate(substate_id, value.into());
+        self.state_track.put_substate(substate_id, value);
     }
 
     pub fn apply_pre_execution_costs<T: ExecutableTransaction>(
@@ -370,6 +459,7 @@ impl<'s, R: FeeReserve> Track<'s, R> {
             self.state_track.rollback();
             self.borrowed_substates.clear();
             self.new_substates.clear();
+            self.resource_events.clear();
         }
 
         // Close fee reserve
@@ -460,6 +550,7 @@ impl<'s, R: FeeReserve> Track<'s, R> {
                     new_resource_addresses,
                 },
                 resource_changes,
+                resource_events: self.resource_events,
             })
         };
 
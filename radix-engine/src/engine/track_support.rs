@@ -70,6 +70,7 @@ impl<'s> BaseStateTrack<'s> {
                         };
 
                         let output_value = OutputValue {
+                            format_version: OUTPUT_VALUE_FORMAT_VERSION,
                             substate: scrypto_decode(&substate)
                                 .expect("Failed to decode NonFungibleWrapper substate"),
                             version: next_version,
@@ -91,6 +92,7 @@ impl<'s> BaseStateTrack<'s> {
                         };
 
                         let output_value = OutputValue {
+                            format_version: OUTPUT_VALUE_FORMAT_VERSION,
                             substate: scrypto_decode(&substate)
                                 .expect("Failed to decode KeyValueStoreEntryWrapper substate"),
                             version: next_version,
@@ -140,6 +142,7 @@ impl<'s> BaseStateTrack<'s> {
                             substate.clone()
                         };
                         let output_value = OutputValue {
+                            format_version: OUTPUT_VALUE_FORMAT_VERSION,
                             substate: scrypto_decode(&transformed_substate)
                                 .expect(&format!("Failed to decode substate {:?}", substate_id)),
                             version: next_version,
@@ -162,6 +165,12 @@ pub enum StateTrackError {
     RENodeAlreadyTouched,
 }
 
+/// A snapshot of the application state changes, see `AppStateTrack::checkpoint`.
+pub struct AppStateTrackCheckpoint {
+    substates: IndexMap<SubstateId, Option<Vec<u8>>>,
+    new_root_substates: IndexSet<SubstateId>,
+}
+
 /// Keeps track of state changes that may be rolled back according to transaction status
 pub struct AppStateTrack<'s> {
     /// The parent state track
@@ -196,6 +205,20 @@ impl<'s> AppStateTrack<'s> {
         self.base_state_track.substate_store.is_root(substate_id)
     }
 
+    /// Captures the state changes so far, so that later changes can be reverted with `rollback_to`.
+    pub fn checkpoint(&self) -> AppStateTrackCheckpoint {
+        AppStateTrackCheckpoint {
+            substates: self.substates.clone(),
+            new_root_substates: self.new_root_substates.clone(),
+        }
+    }
+
+    /// Reverts the state changes made since the checkpoint; the base track isn't affected.
+    pub fn rollback_to(&mut self, checkpoint: AppStateTrackCheckpoint) {
+        self.substates = checkpoint.substates;
+        self.new_root_substates = checkpoint.new_root_substates;
+    }
+
     pub fn set_substate_root(&mut self, substate_id: SubstateId) {
         self.new_root_substates.insert(substate_id);
     }
@@ -224,6 +247,54 @@ impl<'s> AppStateTrack<'s> {
             })
     }
 
+    /// Returns copies of the first `limit` key value store entries whose keys lie within
+    /// `start..=end`, ordered by key, including the entries changed during the transaction.
+    pub fn get_kv_store_entries_in_range(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        start: &[u8],
+        end: &[u8],
+        limit: usize,
+    ) -> Vec<(Vec<u8>, Substate)> {
+        let mut changes: Vec<(Vec<u8>, Option<Vec<u8>>)> = Vec::new();
+        for substates in [&self.base_state_track.substates, &self.substates] {
+            for (substate_id, substate) in substates {
+                if let SubstateId::KeyValueStoreEntry(id, key) = substate_id {
+                    if id == kv_store_id && start <= key.as_slice() && key.as_slice() <= end {
+                        changes.push((key.clone(), substate.clone()));
+                    }
+                }
+            }
+        }
+
+        // Every changed key may take the place of one of the stored entries
+        let mut entries: BTreeMap<Vec<u8>, Option<Vec<u8>>> = self
+            .base_state_track
+            .substate_store
+            .get_kv_store_entries_in_range(
+                kv_store_id,
+                start,
+                end,
+                limit.saturating_add(changes.len()),
+            )
+            .into_iter()
+            .map(|(key, output_value)| (key, Some(scrypto_encode(&output_value.substate))))
+            .collect();
+        entries.extend(changes);
+
+        entries
+            .into_iter()
+            .filter_map(|(key, substate)| {
+                substate.map(|x| {
+                    let substate = scrypto_decode(&x)
+                        .expect("Failed to decode KeyValueStoreEntryWrapper substate");
+                    (key, substate)
+                })
+            })
+            .take(limit)
+            .collect()
+    }
+
     /// Returns a copy of the substate associated with the given address from the base track
     pub fn get_substate_from_base(
         &mut self,
@@ -265,6 +336,25 @@ impl<'s> AppStateTrack<'s> {
             .insert(substate_id, Some(scrypto_encode(&substate)));
     }
 
+    /// Returns the ids of the substates which have been created or changed since being loaded.
+    pub fn changed_substate_ids(&self) -> Vec<SubstateId> {
+        let mut changed: Vec<SubstateId> = self.new_root_substates.iter().cloned().collect();
+        for (substate_id, substate) in &self.substates {
+            let original = match self.base_state_track.substates.get(substate_id) {
+                Some(original) => original.clone(),
+                None => self
+                    .base_state_track
+                    .substate_store
+                    .get_substate(substate_id)
+                    .map(|s| scrypto_encode(&s.substate)),
+            };
+            if substate != &original && !changed.contains(substate_id) {
+                changed.push(substate_id.clone());
+            }
+        }
+        changed
+    }
+
     /// Commit all state changes into base state track
     pub fn commit(&mut self) {
         self.base_state_track
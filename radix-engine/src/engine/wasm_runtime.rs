@@ -74,6 +74,28 @@ where
             .invoke_method(receiver, fn_identifier, call_data)
     }
 
+    fn handle_invoke_method_with_budget(
+        &mut self,
+        receiver: Receiver,
+        fn_identifier: FnIdentifier,
+        input: Vec<u8>,
+        max_cost_units: u32,
+    ) -> Result<ScryptoValue, RuntimeError> {
+        let call_data = ScryptoValue::from_slice(&input)
+            .map_err(|e| RuntimeError::KernelError(KernelError::DecodeError(e)))?;
+        let output: Result<Vec<u8>, SubCallBudgetExceeded> = match self
+            .system_api
+            .invoke_method_with_budget(receiver, fn_identifier, call_data, max_cost_units)
+        {
+            Ok(output) => Ok(output.raw),
+            Err(RuntimeError::SubCallBudgetExceeded { max_cost_units }) => {
+                Err(SubCallBudgetExceeded { max_cost_units })
+            }
+            Err(e) => return Err(e),
+        };
+        Ok(ScryptoValue::from_typed(&output))
+    }
+
     fn handle_node_create(
         &mut self,
         scrypto_node: ScryptoRENode,
@@ -166,6 +188,29 @@ where
         Ok(ScryptoValue::unit())
     }
 
+    fn handle_iterate_kv_store(
+        &mut self,
+        kv_store_id: KeyValueStoreId,
+        start: Vec<u8>,
+        end: Vec<u8>,
+        limit: u32,
+    ) -> Result<ScryptoValue, RuntimeError> {
+        let entries: Vec<(Vec<u8>, Vec<u8>)> = self
+            .system_api
+            .kv_store_read_range(kv_store_id, start, end, limit)?
+            .into_iter()
+            .map(|(key, value)| (key, value.raw))
+            .collect();
+        Ok(ScryptoValue::from_typed(&entries))
+    }
+
+    fn handle_get_kv_store_length(
+        &mut self,
+        kv_store_id: KeyValueStoreId,
+    ) -> Result<u64, RuntimeError> {
+        self.system_api.kv_store_len(kv_store_id)
+    }
+
     fn handle_get_actor(&mut self) -> Result<ScryptoActor, RuntimeError> {
         return Ok(self.actor.clone());
     }
@@ -208,6 +253,17 @@ where
             RadixEngineInput::InvokeMethod(receiver, fn_identifier, input_bytes) => {
                 self.handle_invoke_method(receiver, fn_identifier, input_bytes)
             }
+            RadixEngineInput::InvokeMethodWithBudget(
+                receiver,
+                fn_identifier,
+                input_bytes,
+                max_cost_units,
+            ) => self.handle_invoke_method_with_budget(
+                receiver,
+                fn_identifier,
+                input_bytes,
+                max_cost_units,
+            ),
             RadixEngineInput::RENodeGlobalize(node_id) => self.handle_node_globalize(node_id),
             RadixEngineInput::RENodeCreate(node) => self.handle_node_create(node),
             RadixEngineInput::SubstateRead(substate_id) => self.handle_substate_read(substate_id),
@@ -222,6 +278,12 @@ where
             RadixEngineInput::CheckAccessRule(rule, proof_ids) => {
                 self.handle_check_access_rule(rule, proof_ids).map(encode)
             }
+            RadixEngineInput::IterateKeyValueStore(kv_store_id, start, end, limit) => {
+                self.handle_iterate_kv_store(kv_store_id, start, end, limit)
+            }
+            RadixEngineInput::GetKeyValueStoreLength(kv_store_id) => {
+                self.handle_get_kv_store_length(kv_store_id).map(encode)
+            }
         }
         .map_err(InvokeError::downstream)
     }
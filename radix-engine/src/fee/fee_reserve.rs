@@ -10,6 +10,17 @@ pub enum FeeReserveError {
     Overflow,
     LimitExceeded,
     SystemLoanNotCleared,
+    InvalidCheckpoint,
+    BudgetExceeded,
+    InvalidRefund,
+}
+
+/// A snapshot of the cost units consumed at some point, which can be rolled back to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeeReserveCheckpoint {
+    consumed_instant: u32,
+    consumed_deferred: u32,
+    cost_breakdown: HashMap<String, u32>,
 }
 
 pub trait FeeReserve {
@@ -27,6 +38,13 @@ pub trait FeeReserve {
         contingent: bool,
     ) -> Result<ResourceContainer, FeeReserveError>;
 
+    /// Returns cost units consumed for the given reason to the balance.
+    ///
+    /// At most what has been consumed can be refunded, so the balance never exceeds what was
+    /// loaned and repaid. Unused payments are returned to their vaults when the transaction
+    /// is committed.
+    fn refund<T: ToString>(&mut self, n: u32, reason: T) -> Result<(), FeeReserveError>;
+
     fn finalize(self) -> FeeSummary;
 
     fn limit(&self) -> u32;
@@ -38,8 +56,26 @@ pub trait FeeReserve {
     fn balance(&self) -> u32;
 
     fn owed(&self) -> u32;
+
+    /// Captures the consumed cost units, so that they can be restored with `rollback`.
+    fn checkpoint(&self) -> FeeReserveCheckpoint;
+
+    /// Restores the consumed cost units to the given checkpoint, refunding anything consumed since.
+    ///
+    /// Payments made after the checkpoint are kept.
+    fn rollback(&mut self, checkpoint: FeeReserveCheckpoint) -> Result<(), FeeReserveError>;
+
+    /// Limits further consumption to the given number of cost units, until `pop_budget` is called.
+    ///
+    /// Budgets nest; an inner budget never extends beyond the enclosing one.
+    fn push_budget(&mut self, max_cost_units: u32);
+
+    fn pop_budget(&mut self);
 }
 
+/// A callback which receives the total consumed cost units.
+pub type SoftLimitCallback = Box<dyn FnMut(u32)>;
+
 pub struct SystemLoanFeeReserve {
     /// The price of cost unit
     cost_unit_price: Decimal,
@@ -61,6 +97,10 @@ pub struct SystemLoanFeeReserve {
     check_point: u32,
     /// Cost breakdown
     cost_breakdown: HashMap<String, u32>,
+    /// The total consumed cost units at which each active budget runs out
+    budgets: Vec<u32>,
+    /// The threshold which hasn't been crossed yet, and the callback to notify when it is
+    soft_limit: Option<(u32, SoftLimitCallback)>,
 }
 
 impl SystemLoanFeeReserve {
@@ -81,9 +121,19 @@ impl SystemLoanFeeReserve {
             limit: cost_unit_limit,
             check_point: system_loan,
             cost_breakdown: HashMap::new(),
+            budgets: Vec::new(),
+            soft_limit: None,
         }
     }
 
+    /// Registers a callback to be invoked, with the total consumed cost units, the first time
+    /// the consumption reaches `threshold`.
+    ///
+    /// Unlike the cost unit limit, the soft limit never fails consumption.
+    pub fn set_soft_limit(&mut self, threshold: u32, callback: SoftLimitCallback) {
+        self.soft_limit = Some((threshold, callback));
+    }
+
     /// Adds free credit.
     ///
     /// Note that overflow is not checked.
@@ -113,7 +163,13 @@ impl FeeReserve for SystemLoanFeeReserve {
                 .checked_add(n)
                 .ok_or(FeeReserveError::Overflow)?;
         }
-        if self.consumed_instant + self.consumed_deferred > self.limit {
+        let consumed = self.consumed_instant + self.consumed_deferred;
+        if matches!(&self.soft_limit, Some((threshold, _)) if consumed >= *threshold) {
+            if let Some((_, mut callback)) = self.soft_limit.take() {
+                callback(consumed);
+            }
+        }
+        if consumed > self.limit {
             return Err(FeeReserveError::LimitExceeded);
         }
 
@@ -138,6 +194,13 @@ impl FeeReserve for SystemLoanFeeReserve {
         if self.consumed_instant >= self.check_point && self.owed > 0 {
             return Err(FeeReserveError::SystemLoanNotCleared);
         }
+
+        // check budget
+        if let Some(budget) = self.budgets.last() {
+            if self.consumed_instant + self.consumed_deferred > *budget {
+                return Err(FeeReserveError::BudgetExceeded);
+            }
+        }
         Ok(())
     }
 
@@ -182,6 +245,21 @@ impl FeeReserve for SystemLoanFeeReserve {
         Ok(fee)
     }
 
+    fn refund<T: ToString>(&mut self, n: u32, reason: T) -> Result<(), FeeReserveError> {
+        let consumed = self
+            .cost_breakdown
+            .get_mut(&reason.to_string())
+            .filter(|consumed| **consumed >= n && self.consumed_instant >= n)
+            .ok_or(FeeReserveError::InvalidRefund)?;
+        *consumed -= n;
+        self.consumed_instant -= n;
+        self.balance = self
+            .balance
+            .checked_add(n)
+            .ok_or(FeeReserveError::Overflow)?;
+        Ok(())
+    }
+
     fn finalize(mut self) -> FeeSummary {
         if self.owed > 0 && self.balance != 0 {
             let n = u32::min(self.owed, self.balance);
@@ -222,6 +300,52 @@ impl FeeReserve for SystemLoanFeeReserve {
     fn owed(&self) -> u32 {
         self.owed
     }
+
+    fn checkpoint(&self) -> FeeReserveCheckpoint {
+        FeeReserveCheckpoint {
+            consumed_instant: self.consumed_instant,
+            consumed_deferred: self.consumed_deferred,
+            cost_breakdown: self.cost_breakdown.clone(),
+        }
+    }
+
+    fn rollback(&mut self, checkpoint: FeeReserveCheckpoint) -> Result<(), FeeReserveError> {
+        let instant = self
+            .consumed_instant
+            .checked_sub(checkpoint.consumed_instant)
+            .ok_or(FeeReserveError::InvalidCheckpoint)?;
+        let deferred = self
+            .consumed_deferred
+            .checked_sub(checkpoint.consumed_deferred)
+            .ok_or(FeeReserveError::InvalidCheckpoint)?;
+
+        // refund what's been consumed since the checkpoint; deferred cost units which a later
+        // fee lock has already repaid are credited back to the balance instead
+        let unpaid = min(deferred, self.owed);
+        self.balance = self
+            .balance
+            .checked_add(instant)
+            .and_then(|balance| balance.checked_add(deferred - unpaid))
+            .ok_or(FeeReserveError::Overflow)?;
+        self.owed -= unpaid;
+        self.consumed_instant = checkpoint.consumed_instant;
+        self.consumed_deferred = checkpoint.consumed_deferred;
+        self.cost_breakdown = checkpoint.cost_breakdown;
+        Ok(())
+    }
+
+    fn push_budget(&mut self, max_cost_units: u32) {
+        let mut budget =
+            (self.consumed_instant + self.consumed_deferred).saturating_add(max_cost_units);
+        if let Some(outer) = self.budgets.last() {
+            budget = min(budget, *outer);
+        }
+        self.budgets.push(budget);
+    }
+
+    fn pop_budget(&mut self) {
+        self.budgets.pop();
+    }
 }
 
 impl Default for SystemLoanFeeReserve {
@@ -240,6 +364,8 @@ impl Default for SystemLoanFeeReserve {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use sbor::rust::cell::RefCell;
+    use sbor::rust::rc::Rc;
     use scrypto::{constants::RADIX_TOKEN, crypto::Hash};
 
     const TEST_VAULT_ID: VaultId = (Hash([0u8; 32]), 1);
@@ -299,4 +425,108 @@ mod tests {
             fee_reserve.finalize().payments
         )
     }
+
+    #[test]
+    fn test_refund() {
+        let mut fee_reserve = SystemLoanFeeReserve::new(200, 0, 1.into(), 0);
+        fee_reserve.repay(TEST_VAULT_ID, xrd(100), false).unwrap();
+        fee_reserve.consume(30, "test", false).unwrap();
+        fee_reserve.consume(50, "estimate", false).unwrap();
+
+        fee_reserve.refund(40, "estimate").unwrap();
+        assert_eq!(60, fee_reserve.balance());
+        assert_eq!(40, fee_reserve.consumed_instant());
+        assert_eq!(
+            Err(FeeReserveError::InvalidRefund),
+            fee_reserve.refund(11, "estimate")
+        );
+        assert_eq!(
+            Err(FeeReserveError::InvalidRefund),
+            fee_reserve.refund(1, "unknown")
+        );
+        assert_eq!(
+            HashMap::from([("test".to_string(), 30), ("estimate".to_string(), 10)]),
+            fee_reserve.finalize().cost_breakdown
+        );
+    }
+
+    #[test]
+    fn test_soft_limit() {
+        let crossings = Rc::new(RefCell::new(Vec::new()));
+        let crossings_by_callback = crossings.clone();
+        let mut fee_reserve = SystemLoanFeeReserve::new(100, 0, 1.into(), 0);
+        fee_reserve.repay(TEST_VAULT_ID, xrd(100), false).unwrap();
+        fee_reserve.set_soft_limit(
+            80,
+            Box::new(move |consumed| crossings_by_callback.borrow_mut().push(consumed)),
+        );
+
+        for _ in 0..5 {
+            fee_reserve.consume(20, "test", false).unwrap();
+        }
+
+        assert_eq!(vec![80], *crossings.borrow());
+        assert_eq!(
+            Err(FeeReserveError::LimitExceeded),
+            fee_reserve.consume(1, "test", false)
+        );
+        assert_eq!(vec![80], *crossings.borrow());
+    }
+
+    #[test]
+    fn test_checkpoint_and_rollback() {
+        let mut fee_reserve = SystemLoanFeeReserve::new(100, 0, 1.into(), 50);
+        fee_reserve.consume(10, "test", false).unwrap();
+        let checkpoint = fee_reserve.checkpoint();
+        fee_reserve.consume(20, "test", false).unwrap();
+        fee_reserve.consume(5, "speculative", false).unwrap();
+        assert_eq!(35, fee_reserve.consumed_instant());
+
+        fee_reserve.rollback(checkpoint).unwrap();
+        assert_eq!(10, fee_reserve.consumed_instant());
+        assert_eq!(40, fee_reserve.balance());
+        assert_eq!(
+            HashMap::from([("test".to_string(), 10)]),
+            fee_reserve.finalize().cost_breakdown
+        );
+
+        // deferred cost units which have been repaid since the checkpoint
+        let mut fee_reserve = SystemLoanFeeReserve::new(100, 0, 1.into(), 50);
+        let checkpoint = fee_reserve.checkpoint();
+        fee_reserve.consume(10, "deferred", true).unwrap();
+        fee_reserve.repay(TEST_VAULT_ID, xrd(100), false).unwrap();
+        assert_eq!(0, fee_reserve.owed());
+        assert_eq!(90, fee_reserve.balance());
+
+        fee_reserve.rollback(checkpoint).unwrap();
+        assert_eq!(0, fee_reserve.consumed_deferred());
+        assert_eq!(0, fee_reserve.owed());
+        assert_eq!(100, fee_reserve.balance());
+    }
+
+    #[test]
+    fn test_budget() {
+        let mut fee_reserve = SystemLoanFeeReserve::new(100, 0, 1.into(), 50);
+        fee_reserve.consume(10, "test", false).unwrap();
+        fee_reserve.push_budget(5);
+        fee_reserve.consume(5, "test", false).unwrap();
+        assert_eq!(
+            Err(FeeReserveError::BudgetExceeded),
+            fee_reserve.consume(1, "test", false)
+        );
+        fee_reserve.pop_budget();
+        fee_reserve.consume(1, "test", false).unwrap();
+        assert_eq!(17, fee_reserve.consumed_instant());
+    }
+
+    #[test]
+    fn test_rollback_to_later_checkpoint_fails() {
+        let mut fee_reserve = SystemLoanFeeReserve::new(100, 0, 1.into(), 50);
+        let mut other = SystemLoanFeeReserve::new(100, 0, 1.into(), 50);
+        other.consume(10, "test", false).unwrap();
+        assert_eq!(
+            Err(FeeReserveError::InvalidCheckpoint),
+            fee_reserve.rollback(other.checkpoint())
+        );
+    }
 }
@@ -58,6 +58,45 @@ pub enum SystemApiCostingEntry<'a> {
     CheckAccessRule { size: u32 },
 }
 
+/// A listing of every rate in a fee table, in cost units.
+///
+/// Rates which scale with some size are listed as a fixed part plus a rate per unit of size.
+#[derive(Debug, Clone, PartialEq, Eq, TypeId, Encode, Decode)]
+pub struct FeeTableSnapshot {
+    /// Charges applied to every transaction before execution.
+    pub transaction: BTreeMap<String, u32>,
+    /// Charges for running WASM code, on top of the instrumented instruction costs.
+    pub wasm: BTreeMap<String, u32>,
+    /// Charges for running each native function or method.
+    pub native: BTreeMap<NativeFnIdentifier, u32>,
+    /// Additional charges per byte of input, for the native functions which have them.
+    pub native_per_input_byte: BTreeMap<NativeFnIdentifier, u32>,
+    /// Charges for creating, reading and writing RENodes and substates.
+    pub storage: BTreeMap<String, u32>,
+    /// Charges for the remaining system calls.
+    pub system_api: BTreeMap<String, u32>,
+}
+
+const INVOKE_COST_PER_BYTE: u32 = 5;
+const INVOKE_COST_PER_VALUE: u32 = 10;
+
+/// A configurable rate of a fee table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FeeEntry {
+    TxBaseFee,
+    TxManifestDecodingPerByte,
+    TxManifestVerificationPerByte,
+    TxSignatureVerificationPerSig,
+    TxBlobPricePerByte,
+    /// The price of each cost unit charged by instrumented WASM code.
+    WasmExecutionPerUnit,
+    WasmInstantiationPerByte,
+    FixedLow,
+    FixedMedium,
+    FixedHigh,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FeeTable {
     tx_base_fee: u32,
     tx_manifest_decoding_per_byte: u32,
@@ -67,6 +106,7 @@ pub struct FeeTable {
     fixed_low: u32,
     fixed_medium: u32,
     fixed_high: u32,
+    wasm_execution_per_unit: u32,
     wasm_instantiation_per_byte: u32,
 }
 
@@ -78,6 +118,7 @@ impl FeeTable {
             tx_manifest_verification_per_byte: 1,
             tx_signature_verification_per_sig: 3750,
             tx_blob_price_per_byte: 1,
+            wasm_execution_per_unit: 1,
             wasm_instantiation_per_byte: 0, // TODO: Re-enable WASM instantiation cost if it's unavoidable
             fixed_low: 100,
             fixed_medium: 500,
@@ -85,6 +126,31 @@ impl FeeTable {
         }
     }
 
+    /// Creates the standard fee table, with the given rates overridden.
+    pub fn custom(table: HashMap<FeeEntry, u32>) -> Self {
+        let mut fee_table = Self::new();
+        for (entry, rate) in table {
+            let field = match entry {
+                FeeEntry::TxBaseFee => &mut fee_table.tx_base_fee,
+                FeeEntry::TxManifestDecodingPerByte => &mut fee_table.tx_manifest_decoding_per_byte,
+                FeeEntry::TxManifestVerificationPerByte => {
+                    &mut fee_table.tx_manifest_verification_per_byte
+                }
+                FeeEntry::TxSignatureVerificationPerSig => {
+                    &mut fee_table.tx_signature_verification_per_sig
+                }
+                FeeEntry::TxBlobPricePerByte => &mut fee_table.tx_blob_price_per_byte,
+                FeeEntry::WasmExecutionPerUnit => &mut fee_table.wasm_execution_per_unit,
+                FeeEntry::WasmInstantiationPerByte => &mut fee_table.wasm_instantiation_per_byte,
+                FeeEntry::FixedLow => &mut fee_table.fixed_low,
+                FeeEntry::FixedMedium => &mut fee_table.fixed_medium,
+                FeeEntry::FixedHigh => &mut fee_table.fixed_high,
+            };
+            *field = rate;
+        }
+        fee_table
+    }
+
     pub fn tx_base_fee(&self) -> u32 {
         self.tx_base_fee
     }
@@ -105,6 +171,10 @@ impl FeeTable {
         self.tx_blob_price_per_byte
     }
 
+    pub fn wasm_execution_per_unit(&self) -> u32 {
+        self.wasm_execution_per_unit
+    }
+
     pub fn wasm_instantiation_per_byte(&self) -> u32 {
         self.wasm_instantiation_per_byte
     }
@@ -140,6 +210,15 @@ impl FeeTable {
                         SystemFnIdentifier::GetCurrentEpoch => self.fixed_low,
                         SystemFnIdentifier::GetTransactionHash => self.fixed_low,
                         SystemFnIdentifier::SetEpoch => self.fixed_low,
+                        SystemFnIdentifier::GetCurrentTime => self.fixed_low,
+                        SystemFnIdentifier::SetTime => self.fixed_low,
+                        SystemFnIdentifier::VerifyEcdsaSecp256k1Signature => {
+                            self.tx_signature_verification_per_sig
+                        }
+                        SystemFnIdentifier::VerifyEddsaEd25519Signature => {
+                            self.tx_signature_verification_per_sig
+                        }
+                        SystemFnIdentifier::GetComponentMethods => self.fixed_medium,
                     },
                     NativeFnIdentifier::Bucket(bucket_ident) => match bucket_ident {
                         BucketFnIdentifier::Take => self.fixed_medium,
@@ -173,6 +252,8 @@ impl FeeTable {
                             ResourceManagerFnIdentifier::UpdateNonFungibleData => self.fixed_medium,
                             ResourceManagerFnIdentifier::NonFungibleExists => self.fixed_low,
                             ResourceManagerFnIdentifier::GetNonFungible => self.fixed_medium,
+                            ResourceManagerFnIdentifier::SetTransferHook => self.fixed_medium,
+                            ResourceManagerFnIdentifier::CreateWithAddressSeed => self.fixed_high,
                         }
                     }
                     NativeFnIdentifier::Worktop(worktop_ident) => match worktop_ident {
@@ -187,6 +268,7 @@ impl FeeTable {
                     },
                     NativeFnIdentifier::Component(component_ident) => match component_ident {
                         ComponentFnIdentifier::AddAccessCheck => self.fixed_medium,
+                        ComponentFnIdentifier::RestrictResources => self.fixed_medium,
                     },
                     NativeFnIdentifier::Vault(vault_ident) => {
                         match vault_ident {
@@ -217,10 +299,14 @@ impl FeeTable {
     pub fn system_api_cost(&self, entry: SystemApiCostingEntry) -> u32 {
         match entry {
             SystemApiCostingEntry::InvokeFunction { input, .. } => {
-                self.fixed_low + (5 * input.raw.len() + 10 * input.value_count()) as u32
+                self.fixed_low
+                    + INVOKE_COST_PER_BYTE * input.raw.len() as u32
+                    + INVOKE_COST_PER_VALUE * input.value_count() as u32
             }
             SystemApiCostingEntry::InvokeMethod { input, .. } => {
-                self.fixed_low + (5 * input.raw.len() + 10 * input.value_count()) as u32
+                self.fixed_low
+                    + INVOKE_COST_PER_BYTE * input.raw.len() as u32
+                    + INVOKE_COST_PER_VALUE * input.value_count() as u32
             }
 
             SystemApiCostingEntry::CreateNode { .. } => self.fixed_medium,
@@ -254,4 +340,349 @@ impl FeeTable {
             SystemApiCostingEntry::CheckAccessRule { .. } => self.fixed_medium,
         }
     }
+
+    /// Lists every rate of this fee table.
+    pub fn export(&self) -> FeeTableSnapshot {
+        let mut transaction = BTreeMap::new();
+        transaction.insert("base_fee".to_owned(), self.tx_base_fee);
+        transaction.insert(
+            "manifest_decoding_per_byte".to_owned(),
+            self.tx_manifest_decoding_per_byte,
+        );
+        transaction.insert(
+            "manifest_verification_per_byte".to_owned(),
+            self.tx_manifest_verification_per_byte,
+        );
+        transaction.insert(
+            "signature_verification_per_sig".to_owned(),
+            self.tx_signature_verification_per_sig,
+        );
+        transaction.insert(
+            "blob_price_per_byte".to_owned(),
+            self.tx_blob_price_per_byte,
+        );
+
+        let mut wasm = BTreeMap::new();
+        wasm.insert(
+            "execution_per_unit".to_owned(),
+            self.wasm_execution_per_unit,
+        );
+        wasm.insert(
+            "instantiation_per_byte".to_owned(),
+            self.wasm_instantiation_per_byte,
+        );
+        let scrypto_fn = FnIdentifier::Scrypto {
+            package_address: SYS_FAUCET_PACKAGE,
+            blueprint_name: String::new(),
+            ident: String::new(),
+        };
+        let unit = ScryptoValue::unit();
+        wasm.insert(
+            "run_function".to_owned(),
+            self.run_method_cost(None, &scrypto_fn, &unit),
+        );
+        wasm.insert(
+            "run_method".to_owned(),
+            self.run_method_cost(
+                Some(&Receiver::Consumed(RENodeId::System)),
+                &scrypto_fn,
+                &unit,
+            ),
+        );
+
+        // Natives are probed with two inputs one byte apart, to split out any size-based part
+        let shorter = ScryptoValue::from_typed(&Vec::<u8>::new());
+        let longer = ScryptoValue::from_typed(&vec![0u8]);
+        let mut native = BTreeMap::new();
+        let mut native_per_input_byte = BTreeMap::new();
+        for native_fn in all_native_fn_identifiers() {
+            let fn_identifier = FnIdentifier::Native(native_fn);
+            let shorter_cost = self.run_method_cost(None, &fn_identifier, &shorter);
+            let per_byte = self.run_method_cost(None, &fn_identifier, &longer) - shorter_cost;
+            native.insert(
+                native_fn,
+                shorter_cost - per_byte * shorter.raw.len() as u32,
+            );
+            if per_byte > 0 {
+                native_per_input_byte.insert(native_fn, per_byte);
+            }
+        }
+
+        // Sized rates are probed at sizes 0 and 1, so they always match what's charged
+        let sized = |map: &mut BTreeMap<String, u32>,
+                     name: &str,
+                     entry: fn(u32) -> SystemApiCostingEntry<'static>| {
+            let fixed = self.system_api_cost(entry(0));
+            map.insert(name.to_owned(), fixed);
+            map.insert(
+                format!("{}_per_byte", name),
+                self.system_api_cost(entry(1)) - fixed,
+            );
+        };
+
+        let mut storage = BTreeMap::new();
+        sized(&mut storage, "create_node", |size| {
+            SystemApiCostingEntry::CreateNode { size }
+        });
+        sized(&mut storage, "drop_node", |size| {
+            SystemApiCostingEntry::DropNode { size }
+        });
+        sized(&mut storage, "globalize_node", |size| {
+            SystemApiCostingEntry::GlobalizeNode { size }
+        });
+        sized(&mut storage, "borrow_node", |size| {
+            SystemApiCostingEntry::BorrowNode {
+                loaded: false,
+                size,
+            }
+        });
+        sized(&mut storage, "borrow_loaded_node", |size| {
+            SystemApiCostingEntry::BorrowNode { loaded: true, size }
+        });
+        sized(&mut storage, "borrow_substate", |size| {
+            SystemApiCostingEntry::BorrowSubstate {
+                loaded: false,
+                size,
+            }
+        });
+        sized(&mut storage, "borrow_loaded_substate", |size| {
+            SystemApiCostingEntry::BorrowSubstate { loaded: true, size }
+        });
+        sized(&mut storage, "return_substate", |size| {
+            SystemApiCostingEntry::ReturnSubstate { size }
+        });
+        sized(&mut storage, "take_substate", |size| {
+            SystemApiCostingEntry::TakeSubstate { size }
+        });
+        sized(&mut storage, "read_substate", |size| {
+            SystemApiCostingEntry::ReadSubstate { size }
+        });
+        sized(&mut storage, "write_substate", |size| {
+            SystemApiCostingEntry::WriteSubstate { size }
+        });
+
+        let mut system_api = BTreeMap::new();
+        system_api.insert("invoke".to_owned(), self.fixed_low);
+        system_api.insert("invoke_per_byte".to_owned(), INVOKE_COST_PER_BYTE);
+        system_api.insert("invoke_per_value".to_owned(), INVOKE_COST_PER_VALUE);
+        system_api.insert(
+            "read_epoch".to_owned(),
+            self.system_api_cost(SystemApiCostingEntry::ReadEpoch),
+        );
+        system_api.insert(
+            "read_transaction_hash".to_owned(),
+            self.system_api_cost(SystemApiCostingEntry::ReadTransactionHash),
+        );
+        system_api.insert(
+            "generate_uuid".to_owned(),
+            self.system_api_cost(SystemApiCostingEntry::GenerateUuid),
+        );
+        sized(&mut system_api, "read_blob", |size| {
+            SystemApiCostingEntry::ReadBlob { size }
+        });
+        sized(&mut system_api, "emit_log", |size| {
+            SystemApiCostingEntry::EmitLog { size }
+        });
+        sized(&mut system_api, "check_access_rule", |size| {
+            SystemApiCostingEntry::CheckAccessRule { size }
+        });
+
+        FeeTableSnapshot {
+            transaction,
+            wasm,
+            native,
+            native_per_input_byte,
+            storage,
+            system_api,
+        }
+    }
+}
+
+fn all_native_fn_identifiers() -> Vec<NativeFnIdentifier> {
+    let mut natives = Vec::new();
+    natives.extend(
+        [
+            ComponentFnIdentifier::AddAccessCheck,
+            ComponentFnIdentifier::RestrictResources,
+        ]
+        .map(NativeFnIdentifier::Component),
+    );
+    natives.extend(
+        [
+            SystemFnIdentifier::GetCurrentEpoch,
+            SystemFnIdentifier::GetTransactionHash,
+            SystemFnIdentifier::SetEpoch,
+            SystemFnIdentifier::GetCurrentTime,
+            SystemFnIdentifier::SetTime,
+            SystemFnIdentifier::VerifyEcdsaSecp256k1Signature,
+            SystemFnIdentifier::VerifyEddsaEd25519Signature,
+            SystemFnIdentifier::GetComponentMethods,
+        ]
+        .map(NativeFnIdentifier::System),
+    );
+    natives.extend(
+        [
+            AuthZoneFnIdentifier::Pop,
+            AuthZoneFnIdentifier::Push,
+            AuthZoneFnIdentifier::CreateProof,
+            AuthZoneFnIdentifier::CreateProofByAmount,
+            AuthZoneFnIdentifier::CreateProofByIds,
+            AuthZoneFnIdentifier::Clear,
+        ]
+        .map(NativeFnIdentifier::AuthZone),
+    );
+    natives.extend(
+        [
+            ResourceManagerFnIdentifier::Create,
+            ResourceManagerFnIdentifier::UpdateAuth,
+            ResourceManagerFnIdentifier::LockAuth,
+            ResourceManagerFnIdentifier::Mint,
+            ResourceManagerFnIdentifier::UpdateNonFungibleData,
+            ResourceManagerFnIdentifier::GetNonFungible,
+            ResourceManagerFnIdentifier::GetMetadata,
+            ResourceManagerFnIdentifier::GetResourceType,
+            ResourceManagerFnIdentifier::GetTotalSupply,
+            ResourceManagerFnIdentifier::UpdateMetadata,
+            ResourceManagerFnIdentifier::NonFungibleExists,
+            ResourceManagerFnIdentifier::CreateBucket,
+            ResourceManagerFnIdentifier::CreateVault,
+            ResourceManagerFnIdentifier::SetTransferHook,
+            ResourceManagerFnIdentifier::CreateWithAddressSeed,
+        ]
+        .map(NativeFnIdentifier::ResourceManager),
+    );
+    natives.extend(
+        [
+            BucketFnIdentifier::Burn,
+            BucketFnIdentifier::Take,
+            BucketFnIdentifier::TakeNonFungibles,
+            BucketFnIdentifier::Put,
+            BucketFnIdentifier::GetNonFungibleIds,
+            BucketFnIdentifier::GetAmount,
+            BucketFnIdentifier::GetResourceAddress,
+            BucketFnIdentifier::CreateProof,
+        ]
+        .map(NativeFnIdentifier::Bucket),
+    );
+    natives.extend(
+        [
+            VaultFnIdentifier::Take,
+            VaultFnIdentifier::LockFee,
+            VaultFnIdentifier::LockContingentFee,
+            VaultFnIdentifier::Put,
+            VaultFnIdentifier::TakeNonFungibles,
+            VaultFnIdentifier::GetAmount,
+            VaultFnIdentifier::GetResourceAddress,
+            VaultFnIdentifier::GetNonFungibleIds,
+            VaultFnIdentifier::CreateProof,
+            VaultFnIdentifier::CreateProofByAmount,
+            VaultFnIdentifier::CreateProofByIds,
+        ]
+        .map(NativeFnIdentifier::Vault),
+    );
+    natives.extend(
+        [
+            ProofFnIdentifier::Clone,
+            ProofFnIdentifier::GetAmount,
+            ProofFnIdentifier::GetNonFungibleIds,
+            ProofFnIdentifier::GetResourceAddress,
+            ProofFnIdentifier::Drop,
+        ]
+        .map(NativeFnIdentifier::Proof),
+    );
+    natives.extend(
+        [
+            WorktopFnIdentifier::TakeAll,
+            WorktopFnIdentifier::TakeAmount,
+            WorktopFnIdentifier::TakeNonFungibles,
+            WorktopFnIdentifier::Put,
+            WorktopFnIdentifier::AssertContains,
+            WorktopFnIdentifier::AssertContainsAmount,
+            WorktopFnIdentifier::AssertContainsNonFungibles,
+            WorktopFnIdentifier::Drain,
+        ]
+        .map(NativeFnIdentifier::Worktop),
+    );
+    natives.push(NativeFnIdentifier::Package(PackageFnIdentifier::Publish));
+    natives.push(NativeFnIdentifier::TransactionProcessor(
+        TransactionProcessorFnIdentifier::Run,
+    ));
+    natives
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sbor::describe::{Fields, Type};
+    use sbor::Describe;
+
+    #[test]
+    fn test_export_standard_fee_table() {
+        let snapshot = FeeTable::new().export();
+
+        assert_eq!(snapshot.transaction["base_fee"], 10_000);
+        assert_eq!(snapshot.transaction["signature_verification_per_sig"], 3750);
+        assert_eq!(snapshot.wasm["instantiation_per_byte"], 0);
+        assert_eq!(snapshot.wasm["execution_per_unit"], 1);
+        assert_eq!(snapshot.wasm["run_method"], 1000);
+        assert_eq!(
+            snapshot.native[&NativeFnIdentifier::Vault(VaultFnIdentifier::Put)],
+            500
+        );
+        assert_eq!(
+            snapshot.native[&NativeFnIdentifier::Package(PackageFnIdentifier::Publish)],
+            100
+        );
+        assert_eq!(
+            snapshot.native_per_input_byte
+                [&NativeFnIdentifier::Package(PackageFnIdentifier::Publish)],
+            2
+        );
+        assert_eq!(snapshot.storage["globalize_node"], 1000);
+        assert_eq!(snapshot.storage["globalize_node_per_byte"], 200);
+        assert_eq!(snapshot.system_api["emit_log_per_byte"], 10);
+        assert_eq!(snapshot.system_api["invoke_per_value"], 10);
+    }
+
+    #[test]
+    fn test_all_native_fn_identifiers_are_listed() {
+        // Every `Group(Variant)` described by `NativeFnIdentifier`, as formatted by `Debug`
+        let mut described = Vec::new();
+        if let Type::Enum { variants, .. } = NativeFnIdentifier::describe() {
+            for group in variants {
+                if let Fields::Unnamed { unnamed } = group.fields {
+                    if let Some(Type::Enum { variants, .. }) = unnamed.first() {
+                        for variant in variants {
+                            described.push(format!("{}({})", group.name, variant.name));
+                        }
+                    }
+                }
+            }
+        }
+        described.sort();
+
+        let mut listed: Vec<String> = all_native_fn_identifiers()
+            .iter()
+            .map(|native_fn| format!("{:?}", native_fn))
+            .collect();
+        listed.sort();
+
+        assert!(!described.is_empty());
+        assert_eq!(listed, described);
+    }
+
+    #[test]
+    fn test_custom_fee_table() {
+        let mut table = HashMap::new();
+        table.insert(FeeEntry::TxBaseFee, 20_000);
+        table.insert(FeeEntry::WasmExecutionPerUnit, 2);
+        let fee_table = FeeTable::custom(table);
+
+        assert_eq!(fee_table.tx_base_fee(), 20_000);
+        assert_eq!(fee_table.wasm_execution_per_unit(), 2);
+        assert_eq!(
+            fee_table.tx_signature_verification_per_sig(),
+            FeeTable::new().tx_signature_verification_per_sig()
+        );
+    }
 }
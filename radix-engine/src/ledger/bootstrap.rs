@@ -131,7 +131,7 @@ pub fn execute_genesis<'s, R: FeeReserve>(mut track: Track<'s, R>) -> TrackRecei
         true,
     );
 
-    track.create_uuid_substate(SubstateId::System, System { epoch: 0 }, true);
+    track.create_uuid_substate(SubstateId::System, System { epoch: 0, time: 0 }, true);
 
     track.finalize(Ok(Vec::new()), vec![initial_xrd])
 }
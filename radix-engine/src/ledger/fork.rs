@@ -0,0 +1,171 @@
+use crate::engine::Substate;
+use crate::ledger::*;
+use crate::types::*;
+
+/// Substates written on top of a base store, which take precedence over the base store's
+/// on reads.
+///
+/// Shared by the stores which buffer writes in front of another store.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubstateWrites {
+    pub substates: HashMap<SubstateId, OutputValue>,
+    pub root_substates: HashSet<SubstateId>,
+}
+
+impl SubstateWrites {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get_substate<S: ReadableSubstateStore>(
+        &self,
+        base: &S,
+        substate_id: &SubstateId,
+    ) -> Option<OutputValue> {
+        self.substates
+            .get(substate_id)
+            .cloned()
+            .or_else(|| base.get_substate(substate_id))
+    }
+
+    pub fn is_root<S: ReadableSubstateStore>(&self, base: &S, substate_id: &SubstateId) -> bool {
+        self.root_substates.contains(substate_id) || base.is_root(substate_id)
+    }
+
+    pub fn get_kv_store_entries_in_range<S: ReadableSubstateStore>(
+        &self,
+        base: &S,
+        kv_store_id: &KeyValueStoreId,
+        start: &[u8],
+        end: &[u8],
+        limit: usize,
+    ) -> Vec<(Vec<u8>, OutputValue)> {
+        let overrides: Vec<(Vec<u8>, OutputValue)> = self
+            .substates
+            .iter()
+            .filter_map(|(substate_id, output_value)| match substate_id {
+                SubstateId::KeyValueStoreEntry(id, key)
+                    if id == kv_store_id && start <= key.as_slice() && key.as_slice() <= end =>
+                {
+                    Some((key.clone(), output_value.clone()))
+                }
+                _ => None,
+            })
+            .collect();
+        // Every overridden key may take the place of one of the base entries
+        let mut entries: BTreeMap<Vec<u8>, OutputValue> = base
+            .get_kv_store_entries_in_range(
+                kv_store_id,
+                start,
+                end,
+                limit.saturating_add(overrides.len()),
+            )
+            .into_iter()
+            .collect();
+        entries.extend(overrides);
+        entries.into_iter().take(limit).collect()
+    }
+
+    pub fn get_kv_store_entries<S: QueryableSubstateStore>(
+        &self,
+        base: &S,
+        kv_store_id: &KeyValueStoreId,
+    ) -> HashMap<Vec<u8>, Substate> {
+        let mut entries = base.get_kv_store_entries(kv_store_id);
+        for (substate_id, output_value) in &self.substates {
+            if let SubstateId::KeyValueStoreEntry(id, key) = substate_id {
+                if id == kv_store_id {
+                    entries.insert(key.clone(), output_value.substate.clone());
+                }
+            }
+        }
+        entries
+    }
+
+    pub fn scan_prefix<S: QueryableSubstateStore>(
+        &self,
+        base: &S,
+        prefix: &[u8],
+    ) -> Vec<(SubstateId, OutputValue)> {
+        let mut items: BTreeMap<Vec<u8>, (SubstateId, OutputValue)> = base
+            .scan_prefix(prefix)
+            .into_iter()
+            .map(|(substate_id, output_value)| {
+                (scrypto_encode(&substate_id), (substate_id, output_value))
+            })
+            .collect();
+        for (substate_id, output_value) in &self.substates {
+            let key = scrypto_encode(substate_id);
+            if key.starts_with(prefix) {
+                items.insert(key, (substate_id.clone(), output_value.clone()));
+            }
+        }
+        items.into_values().collect()
+    }
+}
+
+/// A copy-on-write fork of a substate store.
+///
+/// Reads fall through to the base store unless the substate has been written to the fork;
+/// writes are kept in the fork, so the base store is never modified.
+pub struct ForkedSubstateStore<'b, S: ReadableSubstateStore> {
+    base: &'b S,
+    writes: SubstateWrites,
+}
+
+impl<'b, S: ReadableSubstateStore> ForkedSubstateStore<'b, S> {
+    pub fn new(base: &'b S) -> Self {
+        Self {
+            base,
+            writes: SubstateWrites::new(),
+        }
+    }
+
+    /// Returns the substates written to the fork.
+    pub fn substates(&self) -> &HashMap<SubstateId, OutputValue> {
+        &self.writes.substates
+    }
+}
+
+impl<'b, S: ReadableSubstateStore> ReadableSubstateStore for ForkedSubstateStore<'b, S> {
+    fn get_substate(&self, substate_id: &SubstateId) -> Option<OutputValue> {
+        self.writes.get_substate(self.base, substate_id)
+    }
+
+    fn is_root(&self, substate_id: &SubstateId) -> bool {
+        self.writes.is_root(self.base, substate_id)
+    }
+
+    fn get_kv_store_entries_in_range(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        start: &[u8],
+        end: &[u8],
+        limit: usize,
+    ) -> Vec<(Vec<u8>, OutputValue)> {
+        self.writes
+            .get_kv_store_entries_in_range(self.base, kv_store_id, start, end, limit)
+    }
+}
+
+impl<'b, S: ReadableSubstateStore> WriteableSubstateStore for ForkedSubstateStore<'b, S> {
+    fn put_substate(&mut self, substate_id: SubstateId, substate: OutputValue) {
+        self.writes.substates.insert(substate_id, substate);
+    }
+
+    fn set_root(&mut self, substate_id: SubstateId) {
+        self.writes.root_substates.insert(substate_id);
+    }
+}
+
+impl<'b, S: ReadableSubstateStore + QueryableSubstateStore> QueryableSubstateStore
+    for ForkedSubstateStore<'b, S>
+{
+    fn get_kv_store_entries(&self, kv_store_id: &KeyValueStoreId) -> HashMap<Vec<u8>, Substate> {
+        self.writes.get_kv_store_entries(self.base, kv_store_id)
+    }
+
+    fn scan_prefix(&self, prefix: &[u8]) -> Vec<(SubstateId, OutputValue)> {
+        self.writes.scan_prefix(self.base, prefix)
+    }
+}
@@ -38,10 +38,35 @@ impl ReadableSubstateStore for TypedInMemorySubstateStore {
     fn is_root(&self, substate_id: &SubstateId) -> bool {
         self.root_substates.contains(substate_id)
     }
+
+    fn get_kv_store_entries_in_range(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        start: &[u8],
+        end: &[u8],
+        limit: usize,
+    ) -> Vec<(Vec<u8>, OutputValue)> {
+        let mut entries: Vec<(Vec<u8>, OutputValue)> = self
+            .substates
+            .iter()
+            .filter_map(|(substate_id, output_value)| match substate_id {
+                SubstateId::KeyValueStoreEntry(id, key)
+                    if id == kv_store_id && start <= key.as_slice() && key.as_slice() <= end =>
+                {
+                    Some((key.clone(), output_value.clone()))
+                }
+                _ => None,
+            })
+            .collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        entries.truncate(limit);
+        entries
+    }
 }
 
 impl WriteableSubstateStore for TypedInMemorySubstateStore {
-    fn put_substate(&mut self, substate_id: SubstateId, substate: OutputValue) {
+    fn put_substate(&mut self, substate_id: SubstateId, mut substate: OutputValue) {
+        substate.format_version = OUTPUT_VALUE_FORMAT_VERSION;
         self.substates.insert(substate_id, substate);
     }
 
@@ -70,4 +95,24 @@ impl QueryableSubstateStore for TypedInMemorySubstateStore {
             })
             .collect()
     }
+
+    fn scan_prefix(&self, prefix: &[u8]) -> Vec<(SubstateId, OutputValue)> {
+        let mut items: Vec<(Vec<u8>, SubstateId, OutputValue)> = self
+            .substates
+            .iter()
+            .filter_map(|(substate_id, output_value)| {
+                let key = scrypto_encode(substate_id);
+                if key.starts_with(prefix) {
+                    Some((key, substate_id.clone(), output_value.clone()))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        items.sort_by(|a, b| a.0.cmp(&b.0));
+        items
+            .into_iter()
+            .map(|(_, substate_id, output_value)| (substate_id, output_value))
+            .collect()
+    }
 }
@@ -1,7 +1,11 @@
 mod bootstrap;
+mod fork;
 mod memory;
+mod tracking;
 mod traits;
 
 pub use bootstrap::{bootstrap, execute_genesis};
+pub use fork::{ForkedSubstateStore, SubstateWrites};
 pub use memory::TypedInMemorySubstateStore;
+pub use tracking::TrackingSubstateStore;
 pub use traits::*;
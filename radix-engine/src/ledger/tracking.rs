@@ -0,0 +1,79 @@
+use crate::engine::Substate;
+use crate::ledger::*;
+use crate::types::*;
+
+/// A substate store wrapper which records every substate written to the inner store, in order.
+pub struct TrackingSubstateStore<S: ReadableSubstateStore + WriteableSubstateStore> {
+    inner: S,
+    changes: Vec<(SubstateId, OutputValue)>,
+}
+
+impl<S: ReadableSubstateStore + WriteableSubstateStore> TrackingSubstateStore<S> {
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            changes: Vec::new(),
+        }
+    }
+
+    /// Returns the substates written since the last call, in write order.
+    pub fn drain_changes(&mut self) -> Vec<(SubstateId, OutputValue)> {
+        sbor::rust::mem::take(&mut self.changes)
+    }
+
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: ReadableSubstateStore + WriteableSubstateStore> ReadableSubstateStore
+    for TrackingSubstateStore<S>
+{
+    fn get_substate(&self, substate_id: &SubstateId) -> Option<OutputValue> {
+        self.inner.get_substate(substate_id)
+    }
+
+    fn is_root(&self, substate_id: &SubstateId) -> bool {
+        self.inner.is_root(substate_id)
+    }
+
+    fn get_kv_store_entries_in_range(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        start: &[u8],
+        end: &[u8],
+        limit: usize,
+    ) -> Vec<(Vec<u8>, OutputValue)> {
+        self.inner
+            .get_kv_store_entries_in_range(kv_store_id, start, end, limit)
+    }
+}
+
+impl<S: ReadableSubstateStore + WriteableSubstateStore> WriteableSubstateStore
+    for TrackingSubstateStore<S>
+{
+    fn put_substate(&mut self, substate_id: SubstateId, substate: OutputValue) {
+        self.changes.push((substate_id.clone(), substate.clone()));
+        self.inner.put_substate(substate_id, substate);
+    }
+
+    fn set_root(&mut self, substate_id: SubstateId) {
+        self.inner.set_root(substate_id);
+    }
+}
+
+impl<S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateStore>
+    QueryableSubstateStore for TrackingSubstateStore<S>
+{
+    fn get_kv_store_entries(&self, kv_store_id: &KeyValueStoreId) -> HashMap<Vec<u8>, Substate> {
+        self.inner.get_kv_store_entries(kv_store_id)
+    }
+
+    fn scan_prefix(&self, prefix: &[u8]) -> Vec<(SubstateId, OutputValue)> {
+        self.inner.scan_prefix(prefix)
+    }
+}
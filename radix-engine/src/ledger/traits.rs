@@ -1,8 +1,223 @@
 use crate::engine::Substate;
+use crate::model::KeyValueStoreEntryWrapper;
 use crate::types::*;
 
 pub trait QueryableSubstateStore {
     fn get_kv_store_entries(&self, kv_store_id: &KeyValueStoreId) -> HashMap<Vec<u8>, Substate>;
+
+    /// Returns all substates whose encoded substate id starts with the given prefix,
+    /// ordered by encoded substate id.
+    fn scan_prefix(&self, prefix: &[u8]) -> Vec<(SubstateId, OutputValue)>;
+
+    /// Returns all substates matching the given prefix, ordered by encoded substate id.
+    fn list_substates(&self, prefix: SubstatePrefix) -> Vec<(SubstateId, Substate)> {
+        self.scan_prefix(&prefix.to_bytes())
+            .into_iter()
+            .map(|(substate_id, output)| (substate_id, output.substate))
+            .collect()
+    }
+
+    /// Returns the resources created by blueprints of the given package.
+    fn resources_created_by(&self, package_address: PackageAddress) -> Vec<ResourceAddress> {
+        self.scan_prefix(&resource_manager_prefix())
+            .into_iter()
+            .filter_map(
+                |(substate_id, output)| match (substate_id, output.substate) {
+                    (
+                        SubstateId::ResourceManager(resource_address),
+                        Substate::Resource(resource),
+                    ) if resource.creator() == Some(package_address) => Some(resource_address),
+                    _ => None,
+                },
+            )
+            .collect()
+    }
+
+    /// Returns the components directly referenced by the state of the given component, or the
+    /// error if its state can't be decoded.
+    fn component_dependencies(
+        &self,
+        component_address: ComponentAddress,
+    ) -> Result<Vec<ComponentAddress>, DecodeError>
+    where
+        Self: ReadableSubstateStore,
+    {
+        let substate_id = SubstateId::ComponentState(component_address);
+        let mut dependencies: Vec<ComponentAddress> = match self.get_substate(&substate_id) {
+            Some(OutputValue {
+                substate: Substate::ComponentState(component_state),
+                ..
+            }) => ScryptoValue::from_slice(component_state.state())?
+                .refed_component_addresses
+                .into_iter()
+                .filter(|address| *address != component_address)
+                .collect(),
+            _ => Vec::new(),
+        };
+        dependencies.sort();
+        Ok(dependencies)
+    }
+
+    /// Returns all components reachable from the given component through component references,
+    /// excluding the component itself. Reference cycles are followed only once.
+    fn transitive_component_dependencies(
+        &self,
+        component_address: ComponentAddress,
+    ) -> Result<Vec<ComponentAddress>, DecodeError>
+    where
+        Self: ReadableSubstateStore,
+    {
+        let mut visited = BTreeSet::new();
+        visited.insert(component_address);
+        let mut queue = vec![component_address];
+        while let Some(next) = queue.pop() {
+            for dependency in self.component_dependencies(next)? {
+                if visited.insert(dependency) {
+                    queue.push(dependency);
+                }
+            }
+        }
+        visited.remove(&component_address);
+        Ok(visited.into_iter().collect())
+    }
+
+    /// Returns the total encoded size of the substates owned by the given component, including
+    /// its vaults and key value stores, recursively.
+    fn component_storage_size(
+        &self,
+        component_address: ComponentAddress,
+    ) -> Result<usize, DecodeError>
+    where
+        Self: ReadableSubstateStore,
+    {
+        let mut size = 0;
+        let mut vault_ids = Vec::new();
+        let mut kv_store_ids = Vec::new();
+
+        for substate_id in [
+            SubstateId::ComponentInfo(component_address),
+            SubstateId::ComponentState(component_address),
+        ] {
+            if let Some(output) = self.get_substate(&substate_id) {
+                size += sbor::encoded_len(&output.substate);
+                if let Substate::ComponentState(component_state) = &output.substate {
+                    collect_owned_nodes(
+                        component_state.state(),
+                        &mut vault_ids,
+                        &mut kv_store_ids,
+                    )?;
+                }
+            }
+        }
+
+        while let Some(kv_store_id) = kv_store_ids.pop() {
+            if let Some(output) = self.get_substate(&SubstateId::KeyValueStoreSpace(kv_store_id)) {
+                size += sbor::encoded_len(&output.substate);
+            }
+            for (key, substate) in self.get_kv_store_entries(&kv_store_id) {
+                size += key.len() + sbor::encoded_len(&substate);
+                if let Substate::KeyValueStoreEntry(KeyValueStoreEntryWrapper(Some(value))) =
+                    &substate
+                {
+                    collect_owned_nodes(value, &mut vault_ids, &mut kv_store_ids)?;
+                }
+            }
+        }
+
+        for vault_id in vault_ids {
+            if let Some(output) = self.get_substate(&SubstateId::Vault(vault_id)) {
+                size += sbor::encoded_len(&output.substate);
+            }
+        }
+
+        Ok(size)
+    }
+
+    /// Returns the rent owed for storing the substates of the given component, see
+    /// `component_storage_size`, over a number of epochs.
+    fn storage_rent(
+        &self,
+        component_address: ComponentAddress,
+        rate_per_byte_per_epoch: Decimal,
+        epochs: u64,
+    ) -> Result<Decimal, DecodeError>
+    where
+        Self: ReadableSubstateStore,
+    {
+        let size = self.component_storage_size(component_address)? as u64;
+        Ok(rate_per_byte_per_epoch * size * epochs)
+    }
+}
+
+/// Collects the vaults and key value stores referenced by a stored value.
+fn collect_owned_nodes(
+    value: &[u8],
+    vault_ids: &mut Vec<VaultId>,
+    kv_store_ids: &mut Vec<KeyValueStoreId>,
+) -> Result<(), DecodeError> {
+    let value = ScryptoValue::from_slice(value)?;
+    vault_ids.extend(value.vault_ids);
+    kv_store_ids.extend(value.kv_store_ids);
+    Ok(())
+}
+
+/// A set of substates sharing the variant, and possibly the address, of their substate id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubstatePrefix {
+    ResourceManagers,
+    NonFungibles(ResourceAddress),
+    KeyValueStoreEntries(KeyValueStoreId),
+    Vaults,
+}
+
+impl SubstatePrefix {
+    /// Returns the encoded substate id prefix.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            SubstatePrefix::ResourceManagers => resource_manager_prefix(),
+            SubstatePrefix::NonFungibles(resource_address) => {
+                non_fungible_prefix(*resource_address)
+            }
+            SubstatePrefix::KeyValueStoreEntries(kv_store_id) => {
+                kv_store_entry_prefix(*kv_store_id)
+            }
+            SubstatePrefix::Vaults => vault_prefix(),
+        }
+    }
+}
+
+/// Returns the encoded substate id prefix shared by all resource managers.
+pub fn resource_manager_prefix() -> Vec<u8> {
+    let address_len = scrypto_encode(&RADIX_TOKEN).len();
+    let mut prefix = scrypto_encode(&SubstateId::ResourceManager(RADIX_TOKEN));
+    prefix.truncate(prefix.len() - address_len);
+    prefix
+}
+
+/// Returns the encoded substate id prefix shared by all non-fungibles of a resource.
+pub fn non_fungible_prefix(resource_address: ResourceAddress) -> Vec<u8> {
+    let id = NonFungibleId::from_bytes(Vec::new());
+    let id_len = scrypto_encode(&id).len();
+    let mut prefix = scrypto_encode(&SubstateId::NonFungible(resource_address, id));
+    prefix.truncate(prefix.len() - id_len);
+    prefix
+}
+
+/// Returns the encoded substate id prefix shared by all entries of a key value store.
+pub fn kv_store_entry_prefix(kv_store_id: KeyValueStoreId) -> Vec<u8> {
+    let key_len = scrypto_encode(&Vec::<u8>::new()).len();
+    let mut prefix = scrypto_encode(&SubstateId::KeyValueStoreEntry(kv_store_id, Vec::new()));
+    prefix.truncate(prefix.len() - key_len);
+    prefix
+}
+
+/// Returns the encoded substate id prefix shared by all vaults.
+pub fn vault_prefix() -> Vec<u8> {
+    let vault_id: VaultId = (Hash([0u8; 32]), 0);
+    let vault_id_len = scrypto_encode(&vault_id).len();
+    let mut prefix = scrypto_encode(&SubstateId::Vault(vault_id));
+    prefix.truncate(prefix.len() - vault_id_len);
+    prefix
 }
 
 #[derive(Debug, Clone, Hash, TypeId, Encode, Decode, PartialEq, Eq)]
@@ -12,8 +227,14 @@ pub struct OutputId {
     pub version: u32,
 }
 
+/// The encoding version of `OutputValue`, to be bumped whenever `Substate` changes incompatibly.
+pub const OUTPUT_VALUE_FORMAT_VERSION: u16 = 1;
+
 #[derive(Debug, Clone, Encode, Decode, TypeId, PartialEq, Eq)]
 pub struct OutputValue {
+    /// The `OUTPUT_VALUE_FORMAT_VERSION` the value was written with. Being the first field, it
+    /// can be read without decoding the substate.
+    pub format_version: u16,
     pub substate: Substate,
     pub version: u32,
 }
@@ -21,6 +242,16 @@ pub struct OutputValue {
 pub trait ReadableSubstateStore {
     fn get_substate(&self, substate_id: &SubstateId) -> Option<OutputValue>;
     fn is_root(&self, substate_id: &SubstateId) -> bool;
+
+    /// Returns the first `limit` entries of a key value store whose encoded keys lie within
+    /// `start..=end`, ordered by encoded key.
+    fn get_kv_store_entries_in_range(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        start: &[u8],
+        end: &[u8],
+        limit: usize,
+    ) -> Vec<(Vec<u8>, OutputValue)>;
 }
 
 pub trait WriteableSubstateStore {
@@ -1,4 +1,4 @@
-use crate::engine::{HeapRENode, SystemApi};
+use crate::engine::{HeapRENode, ResourceEventKind, SystemApi};
 use crate::fee::FeeReserve;
 use crate::model::{
     InvokeError, Proof, ProofError, ResourceContainer, ResourceContainerError, ResourceContainerId,
@@ -294,6 +294,13 @@ impl Bucket {
                 system_api
                     .substate_return_mut(value)
                     .map_err(InvokeError::Downstream)?;
+                system_api
+                    .emit_resource_event(
+                        ResourceEventKind::Burned,
+                        resource_address,
+                        bucket.total_amount(),
+                    )
+                    .map_err(InvokeError::Downstream)?;
 
                 Ok(ScryptoValue::from_typed(&()))
             }
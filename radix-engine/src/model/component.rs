@@ -35,6 +35,7 @@ pub struct ComponentInfo {
     package_address: PackageAddress,
     blueprint_name: String,
     access_rules: Vec<AccessRules>,
+    accepted_resources: Option<BTreeSet<ResourceAddress>>,
 }
 
 impl ComponentInfo {
@@ -47,6 +48,7 @@ impl ComponentInfo {
             package_address,
             blueprint_name,
             access_rules,
+            accepted_resources: None,
         }
     }
 
@@ -69,6 +71,18 @@ impl ComponentInfo {
         authorizations
     }
 
+    /// Resolves an access rule declared on a method against the component state.
+    pub fn declared_method_authorization(
+        &self,
+        component_state: &ComponentState,
+        schema: &Type,
+        access_rule: &AccessRule,
+    ) -> MethodAuthorization {
+        let data = ScryptoValue::from_slice(&component_state.state)
+            .expect("Failed to decode component state");
+        convert(schema, &data, access_rule)
+    }
+
     pub fn info(&self) -> (PackageAddress, String) {
         (self.package_address.clone(), self.blueprint_name.clone())
     }
@@ -85,6 +99,14 @@ impl ComponentInfo {
         &self.blueprint_name
     }
 
+    /// Returns whether this component may deposit the given resource into its vaults.
+    pub fn accepts(&self, resource_address: &ResourceAddress) -> bool {
+        match &self.accepted_resources {
+            Some(accepted) => accepted.contains(resource_address),
+            None => true,
+        }
+    }
+
     pub fn main<'s, Y, W, I, R>(
         component_address: ComponentAddress,
         component_fn: ComponentFnIdentifier,
@@ -147,6 +169,29 @@ impl ComponentInfo {
 
                 Ok(ScryptoValue::from_typed(&()))
             }
+            ComponentFnIdentifier::RestrictResources => {
+                let input: ComponentRestrictResourcesInput = scrypto_decode(&args.raw)
+                    .map_err(|e| InvokeError::Error(ComponentError::InvalidRequestData(e)))?;
+
+                let mut ref_mut = system_api
+                    .substate_borrow_mut(&substate_id)
+                    .map_err(InvokeError::Downstream)?;
+                let component_info = ref_mut.component_info();
+                // A restriction can only ever be narrowed down
+                let accepted = match component_info.accepted_resources.take() {
+                    Some(accepted) => accepted
+                        .intersection(&input.resource_addresses)
+                        .cloned()
+                        .collect(),
+                    None => input.resource_addresses,
+                };
+                component_info.accepted_resources = Some(accepted);
+                system_api
+                    .substate_return_mut(ref_mut)
+                    .map_err(InvokeError::Downstream)?;
+
+                Ok(ScryptoValue::from_typed(&()))
+            }
         }?;
 
         Ok(rtn)
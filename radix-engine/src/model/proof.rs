@@ -292,8 +292,12 @@ impl Proof {
         }
     }
 
+    /// Drops this proof, releasing its locks on the supporting containers in container id order.
     pub fn drop(self) {
-        for (_, (container, locked_amount_or_ids)) in self.evidence {
+        let mut evidence: Vec<_> = self.evidence.into_iter().collect();
+        evidence.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        for (_, (container, locked_amount_or_ids)) in evidence {
             container.borrow_mut().unlock(locked_amount_or_ids);
         }
     }
@@ -324,6 +328,11 @@ impl Proof {
         self.restricted
     }
 
+    /// Whether this proof holds a lock on the given container.
+    pub fn is_backed_by(&self, container_id: &ResourceContainerId) -> bool {
+        self.evidence.contains_key(container_id)
+    }
+
     pub fn main<'s, Y, W, I, R>(
         proof_id: ProofId,
         proof_fn: ProofFnIdentifier,
@@ -407,3 +416,41 @@ impl Proof {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Vault;
+    use scrypto::{constants::RADIX_TOKEN, crypto::Hash};
+
+    const TEST_VAULT_ID: VaultId = (Hash([0u8; 32]), 1);
+
+    #[test]
+    fn test_dropping_proofs_releases_vault_locks() {
+        let mut vault = Vault::new(ResourceContainer::new_fungible(RADIX_TOKEN, 18, 100.into()));
+        let container_id = ResourceContainerId::Vault(TEST_VAULT_ID);
+        let proofs: Vec<Proof> = [10, 30, 30]
+            .into_iter()
+            .map(|amount| {
+                vault
+                    .create_proof_by_amount(amount.into(), container_id.clone())
+                    .unwrap()
+            })
+            .collect();
+        assert_eq!(
+            Decimal::from(30),
+            vault.borrow_container().max_locked_amount()
+        );
+
+        for proof in proofs {
+            proof.drop();
+        }
+
+        assert!(!vault.is_locked());
+        assert_eq!(
+            Decimal::zero(),
+            vault.borrow_container().max_locked_amount()
+        );
+        assert_eq!(Decimal::from(100), vault.borrow_container().liquid_amount());
+    }
+}
@@ -48,7 +48,7 @@ pub enum LockedAmountOrIds {
     Ids(BTreeSet<NonFungibleId>),
 }
 
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, PartialOrd, Ord)]
 pub enum ResourceContainerId {
     Bucket(BucketId),
     Vault(VaultId),
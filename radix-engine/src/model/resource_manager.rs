@@ -1,4 +1,4 @@
-use crate::engine::{HeapRENode, SystemApi};
+use crate::engine::{HeapRENode, REActor, ResourceEventKind, SystemApi};
 use crate::fee::FeeReserve;
 use crate::model::resource_manager::ResourceMethodRule::{Protected, Public};
 use crate::model::ResourceManagerError::InvalidMethod;
@@ -27,7 +27,10 @@ pub enum ResourceManagerError {
     InvalidMintPermission,
     ResourceTypeDoesNotMatch,
     MaxMintAmountExceeded,
+    SupplyCapExceeded,
+    AddressSeedRequiresBlueprint,
     InvalidNonFungibleData,
+    NonFungibleDataSchemaMismatch,
     NonFungibleAlreadyExists(NonFungibleAddress),
     NonFungibleNotFound(NonFungibleAddress),
     InvalidRequestData(DecodeError),
@@ -108,6 +111,11 @@ pub struct ResourceManager {
     bucket_method_table: HashMap<BucketFnIdentifier, ResourceMethodRule>,
     authorization: HashMap<ResourceMethodAuthKey, MethodAccessRule>,
     total_supply: Decimal,
+    supply_cap: Option<Decimal>,
+    non_fungible_data_schema: Option<NonFungibleDataSchema>,
+    transfer_hook: Option<TransferHook>,
+    /// The package of the blueprint that created this resource, if any
+    creator: Option<PackageAddress>,
 }
 
 impl ResourceManager {
@@ -145,6 +153,10 @@ impl ResourceManager {
         method_table.insert(ResourceManagerFnIdentifier::GetResourceType, Public);
         method_table.insert(ResourceManagerFnIdentifier::GetTotalSupply, Public);
         method_table.insert(ResourceManagerFnIdentifier::CreateVault, Public);
+        method_table.insert(
+            ResourceManagerFnIdentifier::SetTransferHook,
+            Protected(UpdateTransferHook),
+        );
 
         // Non Fungible methods
         method_table.insert(
@@ -162,6 +174,7 @@ impl ResourceManager {
             (Deposit, (AllowAll, LOCKED)),
             (UpdateMetadata, (DenyAll, LOCKED)),
             (UpdateNonFungibleData, (DenyAll, LOCKED)),
+            (UpdateTransferHook, (DenyAll, LOCKED)),
         ] {
             let entry = auth.remove(&auth_entry_key).unwrap_or(default);
             authorization.insert(auth_entry_key, MethodAccessRule::new(entry));
@@ -175,6 +188,10 @@ impl ResourceManager {
             bucket_method_table,
             authorization,
             total_supply: 0.into(),
+            supply_cap: None,
+            non_fungible_data_schema: None,
+            transfer_hook: None,
+            creator: None,
         };
 
         Ok(resource_manager)
@@ -254,6 +271,22 @@ impl ResourceManager {
         self.total_supply
     }
 
+    pub fn supply_cap(&self) -> Option<Decimal> {
+        self.supply_cap
+    }
+
+    pub fn non_fungible_data_schema(&self) -> Option<&NonFungibleDataSchema> {
+        self.non_fungible_data_schema.as_ref()
+    }
+
+    pub fn transfer_hook(&self) -> Option<&TransferHook> {
+        self.transfer_hook.as_ref()
+    }
+
+    pub fn creator(&self) -> Option<PackageAddress> {
+        self.creator
+    }
+
     pub fn mint<'s, Y, W, I, R>(
         &mut self,
         mint_params: MintParams,
@@ -289,6 +322,7 @@ impl ResourceManager {
                     ResourceManagerError::MaxMintAmountExceeded,
                 ));
             }
+            self.check_supply_cap(amount)?;
 
             self.total_supply += amount;
 
@@ -326,6 +360,10 @@ impl ResourceManager {
         // check amount
         let amount: Decimal = entries.len().into();
         self.check_amount(amount)?;
+        self.check_supply_cap(amount)?;
+        for (_, data) in &entries {
+            self.check_non_fungible_data(Some(&data.0), &data.1)?;
+        }
 
         self.total_supply += amount;
 
@@ -387,6 +425,188 @@ impl ResourceManager {
         }
     }
 
+    fn check_non_fungible_data(
+        &self,
+        immutable_data: Option<&[u8]>,
+        mutable_data: &[u8],
+    ) -> Result<(), InvokeError<ResourceManagerError>> {
+        if let Some(schema) = &self.non_fungible_data_schema {
+            let matches = |schema: &Type, data: &[u8]| match decode_any(data) {
+                Ok(value) => schema.matches(&value),
+                Err(_) => false,
+            };
+            let immutable_matches = match immutable_data {
+                Some(data) => matches(&schema.immutable_data, data),
+                None => true,
+            };
+            if !immutable_matches || !matches(&schema.mutable_data, mutable_data) {
+                return Err(InvokeError::Error(
+                    ResourceManagerError::NonFungibleDataSchemaMismatch,
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn check_supply_cap(&self, amount: Decimal) -> Result<(), InvokeError<ResourceManagerError>> {
+        match self.supply_cap {
+            Some(cap) if self.total_supply + amount > cap => {
+                Err(InvokeError::Error(ResourceManagerError::SupplyCapExceeded))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn create<'s, Y, W, I, R>(
+        input: ResourceManagerCreateInput,
+        resource_address: Option<ResourceAddress>,
+        system_api: &mut Y,
+    ) -> Result<ScryptoValue, InvokeError<ResourceManagerError>>
+    where
+        Y: SystemApi<'s, W, I, R>,
+        W: WasmEngine<I>,
+        I: WasmInstance,
+        R: FeeReserve,
+    {
+        let mut resource_manager =
+            ResourceManager::new(input.resource_type, input.metadata, input.access_rules)?;
+        if let Some(supply_cap) = input.supply_cap {
+            resource_manager.check_amount(supply_cap)?;
+            resource_manager.supply_cap = Some(supply_cap);
+        }
+        if let Some(schema) = input.non_fungible_data_schema {
+            if !matches!(input.resource_type, ResourceType::NonFungible) {
+                return Err(InvokeError::Error(
+                    ResourceManagerError::ResourceTypeDoesNotMatch,
+                ));
+            }
+            resource_manager.non_fungible_data_schema = Some(schema);
+        }
+        resource_manager.creator = Self::creator_package(system_api);
+
+        let resource_node_id = if matches!(input.resource_type, ResourceType::NonFungible) {
+            let mut non_fungibles: HashMap<NonFungibleId, NonFungible> = HashMap::new();
+            if let Some(mint_params) = &input.mint_params {
+                if let MintParams::NonFungible { entries } = mint_params {
+                    for (non_fungible_id, data) in entries {
+                        resource_manager.check_non_fungible_data(Some(&data.0), &data.1)?;
+                        let non_fungible = NonFungible::new(data.0.clone(), data.1.clone());
+                        non_fungibles.insert(non_fungible_id.clone(), non_fungible);
+                    }
+                    resource_manager.check_supply_cap(entries.len().into())?;
+                    resource_manager.total_supply = entries.len().into();
+                } else {
+                    return Err(InvokeError::Error(
+                        ResourceManagerError::ResourceTypeDoesNotMatch,
+                    ));
+                }
+            }
+            Self::create_node(
+                HeapRENode::Resource(resource_manager, Some(non_fungibles)),
+                resource_address,
+                system_api,
+            )?
+        } else {
+            if let Some(mint_params) = &input.mint_params {
+                if let MintParams::Fungible { amount } = mint_params {
+                    resource_manager.check_amount(*amount)?;
+                    // TODO: refactor this into mint function
+                    if *amount > dec!("1000000000000000000") {
+                        return Err(InvokeError::Error(
+                            ResourceManagerError::MaxMintAmountExceeded,
+                        ));
+                    }
+                    resource_manager.check_supply_cap(*amount)?;
+                    resource_manager.total_supply = amount.clone();
+                } else {
+                    return Err(InvokeError::Error(
+                        ResourceManagerError::ResourceTypeDoesNotMatch,
+                    ));
+                }
+            }
+            Self::create_node(
+                HeapRENode::Resource(resource_manager, None),
+                resource_address,
+                system_api,
+            )?
+        };
+        let resource_address = resource_node_id.clone().into();
+
+        let bucket_id = if let Some(mint_params) = input.mint_params {
+            let container = match mint_params {
+                MintParams::NonFungible { entries } => {
+                    let ids = entries.into_keys().collect();
+                    ResourceContainer::new_non_fungible(resource_address, ids)
+                }
+                MintParams::Fungible { amount } => ResourceContainer::new_fungible(
+                    resource_address,
+                    input.resource_type.divisibility(),
+                    amount,
+                ),
+            };
+            system_api
+                .emit_resource_event(
+                    ResourceEventKind::Minted,
+                    resource_address,
+                    container.total_amount(),
+                )
+                .map_err(InvokeError::Downstream)?;
+            let bucket_id = system_api
+                .node_create(HeapRENode::Bucket(Bucket::new(container)))
+                .map_err(InvokeError::Downstream)?
+                .into();
+            Some(scrypto::resource::Bucket(bucket_id))
+        } else {
+            None
+        };
+
+        system_api
+            .node_globalize(resource_node_id)
+            .map_err(InvokeError::Downstream)?;
+
+        Ok(ScryptoValue::from_typed(&(resource_address, bucket_id)))
+    }
+
+    /// Returns the package of the blueprint calling into the resource manager, if any.
+    fn creator_package<'s, Y, W, I, R>(system_api: &Y) -> Option<PackageAddress>
+    where
+        Y: SystemApi<'s, W, I, R>,
+        W: WasmEngine<I>,
+        I: WasmInstance,
+        R: FeeReserve,
+    {
+        match system_api.caller() {
+            Some(REActor {
+                fn_identifier:
+                    FnIdentifier::Scrypto {
+                        package_address, ..
+                    },
+                ..
+            }) => Some(*package_address),
+            _ => None,
+        }
+    }
+
+    fn create_node<'s, Y, W, I, R>(
+        node: HeapRENode,
+        resource_address: Option<ResourceAddress>,
+        system_api: &mut Y,
+    ) -> Result<RENodeId, InvokeError<ResourceManagerError>>
+    where
+        Y: SystemApi<'s, W, I, R>,
+        W: WasmEngine<I>,
+        I: WasmInstance,
+        R: FeeReserve,
+    {
+        match resource_address {
+            Some(resource_address) => {
+                system_api.node_create_with_id(RENodeId::ResourceManager(resource_address), node)
+            }
+            None => system_api.node_create(node),
+        }
+        .map_err(InvokeError::Downstream)
+    }
+
     pub fn static_main<'s, Y, W, I, R>(
         resource_manager_fn: ResourceManagerFnIdentifier,
         args: ScryptoValue,
@@ -402,77 +622,19 @@ impl ResourceManager {
             ResourceManagerFnIdentifier::Create => {
                 let input: ResourceManagerCreateInput = scrypto_decode(&args.raw)
                     .map_err(|e| InvokeError::Error(ResourceManagerError::InvalidRequestData(e)))?;
+                Self::create(input, None, system_api)
+            }
+            ResourceManagerFnIdentifier::CreateWithAddressSeed => {
+                let input: ResourceManagerCreateWithAddressSeedInput = scrypto_decode(&args.raw)
+                    .map_err(|e| InvokeError::Error(ResourceManagerError::InvalidRequestData(e)))?;
 
-                let mut resource_manager =
-                    ResourceManager::new(input.resource_type, input.metadata, input.access_rules)?;
-
-                let resource_node_id = if matches!(input.resource_type, ResourceType::NonFungible) {
-                    let mut non_fungibles: HashMap<NonFungibleId, NonFungible> = HashMap::new();
-                    if let Some(mint_params) = &input.mint_params {
-                        if let MintParams::NonFungible { entries } = mint_params {
-                            for (non_fungible_id, data) in entries {
-                                let non_fungible = NonFungible::new(data.0.clone(), data.1.clone());
-                                non_fungibles.insert(non_fungible_id.clone(), non_fungible);
-                            }
-                            resource_manager.total_supply = entries.len().into();
-                        } else {
-                            return Err(InvokeError::Error(
-                                ResourceManagerError::ResourceTypeDoesNotMatch,
-                            ));
-                        }
-                    }
-                    system_api
-                        .node_create(HeapRENode::Resource(resource_manager, Some(non_fungibles)))
-                        .map_err(InvokeError::Downstream)?
-                } else {
-                    if let Some(mint_params) = &input.mint_params {
-                        if let MintParams::Fungible { amount } = mint_params {
-                            resource_manager.check_amount(*amount)?;
-                            // TODO: refactor this into mint function
-                            if *amount > dec!("1000000000000000000") {
-                                return Err(InvokeError::Error(
-                                    ResourceManagerError::MaxMintAmountExceeded,
-                                ));
-                            }
-                            resource_manager.total_supply = amount.clone();
-                        } else {
-                            return Err(InvokeError::Error(
-                                ResourceManagerError::ResourceTypeDoesNotMatch,
-                            ));
-                        }
-                    }
-                    system_api
-                        .node_create(HeapRENode::Resource(resource_manager, None))
-                        .map_err(InvokeError::Downstream)?
-                };
-                let resource_address = resource_node_id.clone().into();
-
-                let bucket_id = if let Some(mint_params) = input.mint_params {
-                    let container = match mint_params {
-                        MintParams::NonFungible { entries } => {
-                            let ids = entries.into_keys().collect();
-                            ResourceContainer::new_non_fungible(resource_address, ids)
-                        }
-                        MintParams::Fungible { amount } => ResourceContainer::new_fungible(
-                            resource_address,
-                            input.resource_type.divisibility(),
-                            amount,
-                        ),
-                    };
-                    let bucket_id = system_api
-                        .node_create(HeapRENode::Bucket(Bucket::new(container)))
-                        .map_err(InvokeError::Downstream)?
-                        .into();
-                    Some(scrypto::resource::Bucket(bucket_id))
-                } else {
-                    None
-                };
-
-                system_api
-                    .node_globalize(resource_node_id)
-                    .map_err(InvokeError::Downstream)?;
-
-                Ok(ScryptoValue::from_typed(&(resource_address, bucket_id)))
+                // The address is tied to the package of the creating blueprint
+                let creator = Self::creator_package(system_api).ok_or(InvokeError::Error(
+                    ResourceManagerError::AddressSeedRequiresBlueprint,
+                ))?;
+                let resource_address =
+                    ResourceAddress::from_address_seed(creator, input.address_seed);
+                Self::create(input.create, Some(resource_address), system_api)
             }
             _ => Err(InvokeError::Error(InvalidMethod)),
         }
@@ -556,6 +718,13 @@ impl ResourceManager {
                     .map_err(|e| InvokeError::Error(ResourceManagerError::InvalidRequestData(e)))?;
                 let container =
                     resource_manager.mint(input.mint_params, resource_address, system_api)?;
+                system_api
+                    .emit_resource_event(
+                        ResourceEventKind::Minted,
+                        resource_address,
+                        container.total_amount(),
+                    )
+                    .map_err(InvokeError::Downstream)?;
                 let bucket_id = system_api
                     .node_create(HeapRENode::Bucket(Bucket::new(container)))
                     .map_err(InvokeError::Downstream)?
@@ -585,9 +754,16 @@ impl ResourceManager {
                 resource_manager.update_metadata(input.metadata)?;
                 Ok(ScryptoValue::from_typed(&()))
             }
+            ResourceManagerFnIdentifier::SetTransferHook => {
+                let input: ResourceManagerSetTransferHookInput = scrypto_decode(&args.raw)
+                    .map_err(|e| InvokeError::Error(ResourceManagerError::InvalidRequestData(e)))?;
+                resource_manager.transfer_hook = input.transfer_hook;
+                Ok(ScryptoValue::from_typed(&()))
+            }
             ResourceManagerFnIdentifier::UpdateNonFungibleData => {
                 let input: ResourceManagerUpdateNonFungibleDataInput = scrypto_decode(&args.raw)
                     .map_err(|e| InvokeError::Error(ResourceManagerError::InvalidRequestData(e)))?;
+                resource_manager.check_non_fungible_data(None, &input.data)?;
 
                 // Read current value
                 let value = system_api
@@ -1,3 +1,5 @@
+use transaction::validation::{verify_ecdsa_secp256k1, verify_eddsa_ed25519};
+
 use crate::engine::SystemApi;
 use crate::fee::FeeReserve;
 use crate::model::InvokeError;
@@ -7,11 +9,18 @@ use crate::wasm::*;
 #[derive(Debug, TypeId, Encode, Decode)]
 pub enum SystemError {
     InvalidRequestData(DecodeError),
+    InvalidComponentInfo(DecodeError),
+    BlueprintNotFound {
+        package_address: PackageAddress,
+        blueprint_name: String,
+    },
 }
 
 #[derive(Debug, Clone, TypeId, Encode, Decode, PartialEq, Eq)]
 pub struct System {
     pub epoch: u64,
+    /// The ledger time, in seconds since the Unix epoch
+    pub time: u64,
 }
 
 impl System {
@@ -47,6 +56,26 @@ impl System {
                     .map_err(InvokeError::Downstream)?;
                 Ok(ScryptoValue::from_typed(&()))
             }
+            SystemFnIdentifier::GetCurrentTime => {
+                let _: SystemGetCurrentTimeInput = scrypto_decode(&args.raw)
+                    .map_err(|e| InvokeError::Error(SystemError::InvalidRequestData(e)))?;
+                let node_ref = system_api
+                    .borrow_node(&RENodeId::System)
+                    .map_err(InvokeError::Downstream)?;
+                Ok(ScryptoValue::from_typed(&node_ref.system().time))
+            }
+            SystemFnIdentifier::SetTime => {
+                let SystemSetTimeInput { time } = scrypto_decode(&args.raw)
+                    .map_err(|e| InvokeError::Error(SystemError::InvalidRequestData(e)))?;
+                let mut system_node_ref = system_api
+                    .substate_borrow_mut(&SubstateId::System)
+                    .map_err(InvokeError::Downstream)?;
+                system_node_ref.system().time = time;
+                system_api
+                    .substate_return_mut(system_node_ref)
+                    .map_err(InvokeError::Downstream)?;
+                Ok(ScryptoValue::from_typed(&()))
+            }
             SystemFnIdentifier::GetTransactionHash => {
                 let _: SystemGetTransactionHashInput = scrypto_decode(&args.raw)
                     .map_err(|e| InvokeError::Error(SystemError::InvalidRequestData(e)))?;
@@ -56,6 +85,55 @@ impl System {
                         .map_err(InvokeError::Downstream)?,
                 ))
             }
+            SystemFnIdentifier::VerifyEcdsaSecp256k1Signature => {
+                let SystemVerifyEcdsaSecp256k1SignatureInput {
+                    public_key,
+                    message_hash,
+                    signature,
+                } = scrypto_decode(&args.raw)
+                    .map_err(|e| InvokeError::Error(SystemError::InvalidRequestData(e)))?;
+                Ok(ScryptoValue::from_typed(&verify_ecdsa_secp256k1(
+                    &message_hash.0,
+                    &public_key,
+                    &signature,
+                )))
+            }
+            SystemFnIdentifier::VerifyEddsaEd25519Signature => {
+                let SystemVerifyEddsaEd25519SignatureInput {
+                    public_key,
+                    message_hash,
+                    signature,
+                } = scrypto_decode(&args.raw)
+                    .map_err(|e| InvokeError::Error(SystemError::InvalidRequestData(e)))?;
+                Ok(ScryptoValue::from_typed(&verify_eddsa_ed25519(
+                    &message_hash.0,
+                    &public_key,
+                    &signature,
+                )))
+            }
+            SystemFnIdentifier::GetComponentMethods => {
+                let SystemGetComponentMethodsInput { component_address } =
+                    scrypto_decode(&args.raw)
+                        .map_err(|e| InvokeError::Error(SystemError::InvalidRequestData(e)))?;
+                let component_info = system_api
+                    .substate_read(SubstateId::ComponentInfo(component_address))
+                    .map_err(InvokeError::Downstream)?;
+                let (package_address, blueprint_name): (PackageAddress, String) =
+                    scrypto_decode(&component_info.raw)
+                        .map_err(|e| InvokeError::Error(SystemError::InvalidComponentInfo(e)))?;
+                let node_ref = system_api
+                    .borrow_node(&RENodeId::Package(package_address))
+                    .map_err(InvokeError::Downstream)?;
+                let methods = node_ref
+                    .package()
+                    .blueprint_abi(&blueprint_name)
+                    .ok_or(InvokeError::Error(SystemError::BlueprintNotFound {
+                        package_address,
+                        blueprint_name: blueprint_name.clone(),
+                    }))?
+                    .method_signatures();
+                Ok(ScryptoValue::from_typed(&methods))
+            }
         }
     }
 }
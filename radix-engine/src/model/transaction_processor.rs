@@ -211,7 +211,8 @@ impl TransactionProcessor {
                     .node_create(HeapRENode::Worktop(Worktop::new()))
                     .map_err(InvokeError::Downstream)?;
 
-                for inst in &input.instructions.clone() {
+                for (index, inst) in input.instructions.clone().iter().enumerate() {
+                    let consumed_before = system_api.cost_units_consumed();
                     let result = match inst {
                         Instruction::TakeFromWorktop { resource_address } => id_allocator
                             .new_bucket_id()
@@ -732,8 +733,12 @@ impl TransactionProcessor {
                                 }),
                             )
                             .map_err(InvokeError::Downstream),
-                    }?;
-                    outputs.push(result);
+                    };
+                    let consumed = system_api
+                        .cost_units_consumed()
+                        .saturating_sub(consumed_before);
+                    system_api.trace_instruction_cost(index, consumed);
+                    outputs.push(result?);
                 }
 
                 Ok(ScryptoValue::from_typed(
@@ -1,4 +1,4 @@
-use crate::engine::{HeapRENode, SystemApi};
+use crate::engine::{HeapRENode, REActor, RuntimeError, SystemApi};
 use crate::fee::{FeeReserve, FeeReserveError};
 use crate::model::{
     Bucket, InvokeError, Proof, ProofError, ResourceContainer, ResourceContainerError,
@@ -18,6 +18,9 @@ pub enum VaultError {
     LockFeeNotRadixToken,
     LockFeeInsufficientBalance,
     LockFeeRepayFailure(FeeReserveError),
+    InvalidTransferHookOutput(DecodeError),
+    InvalidTransferHookAdjustment(Decimal),
+    InvalidComponentInfo(DecodeError),
 }
 
 /// A persistent resource container.
@@ -152,6 +155,110 @@ impl Vault {
         self.container.borrow_mut()
     }
 
+    /// Runs a transfer past the transfer hook of the vault's resource, if there is one.
+    ///
+    /// Returns the amount to move instead of the requested one, if the hook adjusted it.
+    fn run_transfer_hook<'s, Y, W, I, R>(
+        vault_id: VaultId,
+        kind: TransferKind,
+        amount: Decimal,
+        system_api: &mut Y,
+    ) -> Result<Option<Decimal>, InvokeError<VaultError>>
+    where
+        Y: SystemApi<'s, W, I, R>,
+        W: WasmEngine<I>,
+        I: WasmInstance,
+        R: FeeReserve,
+    {
+        // Transfers of resources without a hook don't pay for any lookups
+        let transfer_hook = match system_api.vault_transfer_hook(vault_id) {
+            Some(transfer_hook) => transfer_hook,
+            None => return Ok(None),
+        };
+        let (resource_address, resource_type) = {
+            let node_ref = system_api
+                .borrow_node(&RENodeId::Vault(vault_id))
+                .map_err(InvokeError::Downstream)?;
+            let vault = node_ref.vault();
+            (vault.resource_address(), vault.resource_type())
+        };
+
+        // The component operating the vault is the one which invoked this method
+        let component_address = match system_api.caller() {
+            Some(REActor {
+                receiver: Some(Receiver::Ref(RENodeId::Component(component_address))),
+                ..
+            }) => Some(*component_address),
+            _ => None,
+        };
+
+        let component_info = system_api
+            .substate_read(SubstateId::ComponentInfo(transfer_hook.component_address))
+            .map_err(InvokeError::Downstream)?;
+        let (package_address, blueprint_name): (PackageAddress, String) =
+            scrypto_decode(&component_info.raw)
+                .map_err(|e| InvokeError::Error(VaultError::InvalidComponentInfo(e)))?;
+        let receiver = Receiver::Ref(RENodeId::Component(transfer_hook.component_address));
+        let fn_identifier = FnIdentifier::Scrypto {
+            package_address,
+            blueprint_name,
+            ident: transfer_hook.method_name,
+        };
+
+        // A hook which moves resources guarded by itself would otherwise recurse until the
+        // call depth limit is hit
+        let hook_actor = REActor {
+            fn_identifier: fn_identifier.clone(),
+            receiver: Some(receiver.clone()),
+        };
+        if system_api.is_on_call_stack(&hook_actor) {
+            return Err(InvokeError::Downstream(
+                RuntimeError::TransferHookReentered { resource_address },
+            ));
+        }
+
+        let input = TransferHookInput {
+            kind,
+            resource_address,
+            amount,
+            component_address,
+        };
+        // Call depth and cost unit limits apply to the hook like to any other invocation
+        let output = system_api
+            .invoke_method(
+                receiver,
+                fn_identifier,
+                ScryptoValue::from_slice(&args!(input))
+                    .expect("Failed to encode transfer hook input"),
+            )
+            .map_err(InvokeError::Downstream)?;
+
+        let outcome: TransferHookOutcome = scrypto_decode(&output.raw)
+            .map_err(|e| InvokeError::Error(VaultError::InvalidTransferHookOutput(e)))?;
+        match outcome {
+            TransferHookOutcome::Accept => Ok(None),
+            TransferHookOutcome::Reject => Err(InvokeError::Downstream(
+                RuntimeError::TransferHookRejected {
+                    resource_address,
+                    kind,
+                },
+            )),
+            TransferHookOutcome::Adjust(adjusted) => {
+                // The vault can't dispose of a held back deposit, so only withdrawals adjust
+                if kind == TransferKind::Deposit
+                    || matches!(resource_type, ResourceType::NonFungible)
+                    || adjusted.is_negative()
+                    || adjusted > amount
+                {
+                    return Err(InvokeError::Error(
+                        VaultError::InvalidTransferHookAdjustment(adjusted),
+                    ));
+                }
+                Ok(Some(adjusted))
+            }
+        }
+    }
+
     pub fn main<'s, Y, W, I, R>(
         vault_id: VaultId,
         vault_fn: VaultFnIdentifier,
@@ -164,6 +271,49 @@ impl Vault {
         I: WasmInstance,
         R: FeeReserve,
     {
+        // Transfers go past the transfer hook before the vault is borrowed
+        let mut deposit = None;
+        let mut withdrawal = None;
+        match vault_fn {
+            VaultFnIdentifier::Put => {
+                let input: VaultPutInput = scrypto_decode(&args.raw)
+                    .map_err(|e| InvokeError::Error(VaultError::InvalidRequestData(e)))?;
+                let bucket: Bucket = system_api
+                    .node_drop(&RENodeId::Bucket(input.bucket.0))
+                    .map_err(InvokeError::Downstream)?
+                    .into();
+                Self::run_transfer_hook(
+                    vault_id,
+                    TransferKind::Deposit,
+                    bucket.total_amount(),
+                    system_api,
+                )?;
+                deposit = Some(bucket);
+            }
+            VaultFnIdentifier::Take => {
+                let input: VaultTakeInput = scrypto_decode(&args.raw)
+                    .map_err(|e| InvokeError::Error(VaultError::InvalidRequestData(e)))?;
+                let adjusted = Self::run_transfer_hook(
+                    vault_id,
+                    TransferKind::Withdraw,
+                    input.amount,
+                    system_api,
+                )?;
+                withdrawal = Some(adjusted.unwrap_or(input.amount));
+            }
+            VaultFnIdentifier::TakeNonFungibles => {
+                let input: VaultTakeNonFungiblesInput = scrypto_decode(&args.raw)
+                    .map_err(|e| InvokeError::Error(VaultError::InvalidRequestData(e)))?;
+                Self::run_transfer_hook(
+                    vault_id,
+                    TransferKind::Withdraw,
+                    input.non_fungible_ids.len().into(),
+                    system_api,
+                )?;
+            }
+            _ => {}
+        }
+
         let substate_id = SubstateId::Vault(vault_id.clone());
         let mut ref_mut = system_api
             .substate_borrow_mut(&substate_id)
@@ -172,21 +322,15 @@ impl Vault {
 
         let rtn = match vault_fn {
             VaultFnIdentifier::Put => {
-                let input: VaultPutInput = scrypto_decode(&args.raw)
-                    .map_err(|e| InvokeError::Error(VaultError::InvalidRequestData(e)))?;
-                let bucket = system_api
-                    .node_drop(&RENodeId::Bucket(input.bucket.0))
-                    .map_err(InvokeError::Downstream)?
-                    .into();
+                let bucket = deposit.expect("Deposit not prepared for a vault put");
                 vault
                     .put(bucket)
                     .map_err(|e| InvokeError::Error(VaultError::ResourceContainerError(e)))?;
                 Ok(ScryptoValue::from_typed(&()))
             }
             VaultFnIdentifier::Take => {
-                let input: VaultTakeInput = scrypto_decode(&args.raw)
-                    .map_err(|e| InvokeError::Error(VaultError::InvalidRequestData(e)))?;
-                let container = vault.take(input.amount)?;
+                let amount = withdrawal.expect("Withdrawal not prepared for a vault take");
+                let container = vault.take(amount)?;
                 let bucket_id = system_api
                     .node_create(HeapRENode::Bucket(Bucket::new(container)))
                     .map_err(InvokeError::Downstream)?
@@ -10,3 +10,10 @@ pub struct NonFungibleWrapper(pub Option<NonFungible>);
 /// when persisting into the substate store.
 #[derive(Debug, Clone, TypeId, Encode, Decode, PartialEq, Eq)]
 pub struct KeyValueStoreEntryWrapper(pub Option<Vec<u8>>);
+
+/// The substate of a key value store as a whole, which keeps count of its entries so that the
+/// length can be read without loading them.
+#[derive(Debug, Clone, TypeId, Encode, Decode, PartialEq, Eq)]
+pub struct KeyValueStoreSpace {
+    pub len: u64,
+}
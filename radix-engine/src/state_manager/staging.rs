@@ -143,6 +143,50 @@ impl<'t, 's, S: ReadableSubstateStore> StagedSubstateStore<'t, 's, S> {
         self.get_substate_recurse(substate_id, node.parent_id)
     }
 
+    fn get_kv_store_entries_in_range_recurse(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        start: &[u8],
+        end: &[u8],
+        limit: usize,
+        id: u64,
+    ) -> BTreeMap<Vec<u8>, OutputValue> {
+        if id == 0 {
+            return self
+                .stores
+                .root
+                .get_kv_store_entries_in_range(kv_store_id, start, end, limit)
+                .into_iter()
+                .collect();
+        }
+
+        let node = self.stores.nodes.get(&id).unwrap();
+        let outputs: Vec<(Vec<u8>, OutputValue)> = node
+            .outputs
+            .iter()
+            .filter_map(|(substate_id, output)| match substate_id {
+                SubstateId::KeyValueStoreEntry(entry_kv_store_id, key)
+                    if entry_kv_store_id == kv_store_id
+                        && start <= key.as_slice()
+                        && key.as_slice() <= end =>
+                {
+                    Some((key.clone(), output.clone()))
+                }
+                _ => None,
+            })
+            .collect();
+        // Every overridden key may take the place of one of the parent's entries
+        let mut entries = self.get_kv_store_entries_in_range_recurse(
+            kv_store_id,
+            start,
+            end,
+            limit.saturating_add(outputs.len()),
+            node.parent_id,
+        );
+        entries.extend(outputs);
+        entries.into_iter().take(limit).collect()
+    }
+
     fn is_root_recurse(&self, substate_id: &SubstateId, id: u64) -> bool {
         if id == 0 {
             return self.stores.root.is_root(substate_id);
@@ -165,6 +209,18 @@ impl<'t, 's, S: ReadableSubstateStore> ReadableSubstateStore for StagedSubstateS
     fn is_root(&self, substate_id: &SubstateId) -> bool {
         self.is_root_recurse(substate_id, self.id)
     }
+
+    fn get_kv_store_entries_in_range(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        start: &[u8],
+        end: &[u8],
+        limit: usize,
+    ) -> Vec<(Vec<u8>, OutputValue)> {
+        self.get_kv_store_entries_in_range_recurse(kv_store_id, start, end, limit, self.id)
+            .into_iter()
+            .collect()
+    }
 }
 
 impl<'t, 's, S: ReadableSubstateStore> WriteableSubstateStore for StagedSubstateStore<'t, 's, S> {
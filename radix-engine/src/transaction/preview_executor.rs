@@ -74,6 +74,7 @@ where
             current_epoch: 1,
             max_cost_unit_limit: DEFAULT_MAX_COST_UNIT_LIMIT,
             min_tip_percentage: 0,
+            min_cost_unit_price: Decimal::zero(),
         };
         let execution_params = ExecutionConfig::default();
         let validator = NotarizedTransactionValidator::new(validation_config);
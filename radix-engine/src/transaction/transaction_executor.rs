@@ -1,26 +1,25 @@
 use transaction::model::*;
 
-use crate::constants::{DEFAULT_COST_UNIT_PRICE, DEFAULT_MAX_CALL_DEPTH, DEFAULT_SYSTEM_LOAN};
+use crate::constants::{DEFAULT_MAX_CALL_DEPTH, DEFAULT_MAX_KV_VALUE_BYTES, DEFAULT_SYSTEM_LOAN};
 use crate::engine::Track;
 use crate::engine::*;
 use crate::fee::{FeeReserve, FeeTable, SystemLoanFeeReserve};
-use crate::ledger::{ReadableSubstateStore, WriteableSubstateStore};
+use crate::ledger::{ForkedSubstateStore, ReadableSubstateStore, WriteableSubstateStore};
 use crate::model::*;
 use crate::transaction::*;
 use crate::types::*;
 use crate::wasm::*;
+use sbor::rust::any::Any;
+use sbor::rust::cell::RefCell;
+use sbor::rust::ops;
 
 pub struct FeeReserveConfig {
-    pub cost_unit_price: Decimal,
     pub system_loan: u32,
 }
 
 impl FeeReserveConfig {
     pub fn standard() -> Self {
         Self {
-            cost_unit_price: DEFAULT_COST_UNIT_PRICE
-                .parse()
-                .expect("Invalid cost unit price"),
             system_loan: DEFAULT_SYSTEM_LOAN,
         }
     }
@@ -28,9 +27,25 @@ impl FeeReserveConfig {
 
 pub struct ExecutionConfig {
     pub max_call_depth: usize,
+    pub max_kv_value_bytes: usize,
     pub trace: bool,
+    /// Whether to record the peak WASM memory usage in the receipt.
+    pub profile_wasm_memory: bool,
+    /// Whether to record the auth zone state at each method invocation in the receipt.
+    pub trace_auth_zones: bool,
+    /// Opaque data of the embedder, handed back to the commit callback.
+    ///
+    /// The engine never reads it, so it can't affect the execution.
+    pub client_context: Option<Box<dyn Any>>,
+    /// Invoked by `execute_and_commit` after a transaction has been committed.
+    pub on_commit: Option<CommitCallback>,
+    /// The rates at which execution is charged.
+    pub fee_table: FeeTable,
 }
 
+/// A callback which receives the committed result and the client context.
+pub type CommitCallback = Box<dyn ops::Fn(&CommitResult, Option<&dyn Any>)>;
+
 impl Default for ExecutionConfig {
     fn default() -> Self {
         ExecutionConfig::standard()
@@ -41,16 +56,72 @@ impl ExecutionConfig {
     pub fn standard() -> Self {
         Self {
             max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+            max_kv_value_bytes: DEFAULT_MAX_KV_VALUE_BYTES,
             trace: false,
+            profile_wasm_memory: false,
+            trace_auth_zones: false,
+            client_context: None,
+            on_commit: None,
+            fee_table: FeeTable::new(),
         }
     }
 
     pub fn debug() -> Self {
         Self {
             max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+            max_kv_value_bytes: DEFAULT_MAX_KV_VALUE_BYTES,
             trace: true,
+            profile_wasm_memory: false,
+            trace_auth_zones: false,
+            client_context: None,
+            on_commit: None,
+            fee_table: FeeTable::new(),
         }
     }
+
+    /// Charges execution according to the given fee table, instead of the standard one.
+    pub fn with_fee_table(self, fee_table: FeeTable) -> Self {
+        Self { fee_table, ..self }
+    }
+}
+
+/// The part of a receipt which differed between two executions of the same transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NonDeterminismError {
+    Result,
+    Outcome,
+    StateUpdates,
+    EntityChanges,
+    ResourceChanges,
+    FeeSummary,
+    ApplicationLogs,
+}
+
+/// A call to a component method which must not change any state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadOnlyCall {
+    pub component_address: ComponentAddress,
+    pub method_name: String,
+    pub args: Vec<u8>,
+}
+
+impl ReadOnlyCall {
+    pub fn new<S: AsRef<str>>(
+        component_address: ComponentAddress,
+        method_name: S,
+        args: Vec<u8>,
+    ) -> Self {
+        Self {
+            component_address,
+            method_name: method_name.as_ref().to_owned(),
+            args,
+        }
+    }
+}
+
+// TODO: add to ExecutionConfig
+fn wasm_metering_params() -> WasmMeteringParams {
+    WasmMeteringParams::new(InstructionCostRules::tiered(1, 5, 10, 5000), 512)
 }
 
 /// An executor that runs transactions.
@@ -61,8 +132,8 @@ where
     I: WasmInstance,
 {
     substate_store: &'s mut S,
-    wasm_engine: &'w mut W,
-    wasm_instrumenter: &'w mut WasmInstrumenter,
+    wasm_engine: RefCell<&'w mut W>,
+    wasm_instrumenter: RefCell<&'w mut WasmInstrumenter>,
     phantom: PhantomData<I>,
 }
 
@@ -79,8 +150,8 @@ where
     ) -> Self {
         Self {
             substate_store,
-            wasm_engine,
-            wasm_instrumenter,
+            wasm_engine: RefCell::new(wasm_engine),
+            wasm_instrumenter: RefCell::new(wasm_instrumenter),
             phantom: PhantomData,
         }
     }
@@ -94,22 +165,250 @@ where
         let fee_reserve = SystemLoanFeeReserve::new(
             transaction.cost_unit_limit(),
             transaction.tip_percentage(),
-            fee_reserve_config.cost_unit_price,
+            transaction.cost_unit_price(),
             fee_reserve_config.system_loan,
         );
 
         self.execute_with_fee_reserve(transaction, execution_config, fee_reserve)
     }
 
+    /// Executes a transaction against a fork of the `base` store, leaving the base untouched.
+    ///
+    /// Returns the receipt and the forked state, which reflects the transaction if committed.
+    pub fn execute_on_fork<'b, B: ReadableSubstateStore, T: ExecutableTransaction>(
+        &self,
+        base: &'b B,
+        transaction: &T,
+        fee_reserve_config: &FeeReserveConfig,
+        execution_config: &ExecutionConfig,
+    ) -> (TransactionReceipt, ForkedSubstateStore<'b, B>) {
+        let fee_reserve = SystemLoanFeeReserve::new(
+            transaction.cost_unit_limit(),
+            transaction.tip_percentage(),
+            transaction.cost_unit_price(),
+            fee_reserve_config.system_loan,
+        );
+        let receipt = self.execute_on_store(base, transaction, execution_config, fee_reserve);
+        let mut fork = ForkedSubstateStore::new(base);
+        if let TransactionResult::Commit(commit) = &receipt.result {
+            commit.state_updates.commit(&mut fork);
+        }
+        (receipt, fork)
+    }
+
+    /// Executes a transaction twice against forks of the current state, and checks that both
+    /// executions produce the same receipt.
+    ///
+    /// Every collection in the receipts is compared exactly, including its order. Nothing is
+    /// committed to the store.
+    pub fn execute_deterministic_check<T: ExecutableTransaction>(
+        &mut self,
+        transaction: &T,
+        fee_reserve_config: &FeeReserveConfig,
+        execution_config: &ExecutionConfig,
+    ) -> Result<TransactionReceipt, NonDeterminismError> {
+        let base = &*self.substate_store;
+        let (first, _) =
+            self.execute_on_fork(base, transaction, fee_reserve_config, execution_config);
+        let (second, _) =
+            self.execute_on_fork(base, transaction, fee_reserve_config, execution_config);
+        Self::compare_receipts(&first, &second)?;
+        Ok(first)
+    }
+
+    fn compare_receipts(
+        first: &TransactionReceipt,
+        second: &TransactionReceipt,
+    ) -> Result<(), NonDeterminismError> {
+        let (a, b) = (&first.execution, &second.execution);
+        if a.application_logs != b.application_logs {
+            return Err(NonDeterminismError::ApplicationLogs);
+        }
+        let (fa, fb) = (&a.fee_summary, &b.fee_summary);
+        if fa.loan_fully_repaid != fb.loan_fully_repaid
+            || fa.cost_unit_consumed != fb.cost_unit_consumed
+            || fa.burned != fb.burned
+            || fa.tipped != fb.tipped
+            || scrypto_encode(&fa.payments) != scrypto_encode(&fb.payments)
+            || fa.cost_breakdown != fb.cost_breakdown
+        {
+            return Err(NonDeterminismError::FeeSummary);
+        }
+
+        match (&first.result, &second.result) {
+            (TransactionResult::Commit(a), TransactionResult::Commit(b)) => {
+                if scrypto_encode(&a.outcome) != scrypto_encode(&b.outcome) {
+                    return Err(NonDeterminismError::Outcome);
+                }
+                let (sa, sb) = (&a.state_updates, &b.state_updates);
+                if scrypto_encode(&sa.up_substates) != scrypto_encode(&sb.up_substates)
+                    || scrypto_encode(&sa.down_substates) != scrypto_encode(&sb.down_substates)
+                    || scrypto_encode(&sa.down_virtual_substates)
+                        != scrypto_encode(&sb.down_virtual_substates)
+                    || scrypto_encode(&sa.new_roots) != scrypto_encode(&sb.new_roots)
+                {
+                    return Err(NonDeterminismError::StateUpdates);
+                }
+                let (ea, eb) = (&a.entity_changes, &b.entity_changes);
+                if scrypto_encode(&ea.new_package_addresses)
+                    != scrypto_encode(&eb.new_package_addresses)
+                    || scrypto_encode(&ea.new_component_addresses)
+                        != scrypto_encode(&eb.new_component_addresses)
+                    || scrypto_encode(&ea.new_resource_addresses)
+                        != scrypto_encode(&eb.new_resource_addresses)
+                {
+                    return Err(NonDeterminismError::EntityChanges);
+                }
+                if scrypto_encode(&a.resource_changes) != scrypto_encode(&b.resource_changes) {
+                    return Err(NonDeterminismError::ResourceChanges);
+                }
+                Ok(())
+            }
+            (TransactionResult::Reject(a), TransactionResult::Reject(b))
+                if scrypto_encode(&a.error) == scrypto_encode(&b.error) =>
+            {
+                Ok(())
+            }
+            _ => Err(NonDeterminismError::Result),
+        }
+    }
+
+    /// Computes the smallest fee that has to be locked for the transaction to succeed.
+    ///
+    /// The transaction is dry-run with enough credit to cover any cost, so the fee locked
+    /// in its manifest doesn't affect the outcome. Returns the error if the transaction
+    /// fails regardless of the fee.
+    pub fn minimum_fee<T: ExecutableTransaction>(
+        &mut self,
+        transaction: &T,
+        fee_reserve_config: &FeeReserveConfig,
+        execution_config: &ExecutionConfig,
+    ) -> Result<Decimal, RuntimeError> {
+        let mut fee_reserve = SystemLoanFeeReserve::new(
+            transaction.cost_unit_limit(),
+            transaction.tip_percentage(),
+            transaction.cost_unit_price(),
+            fee_reserve_config.system_loan,
+        );
+        fee_reserve.credit(transaction.cost_unit_limit());
+
+        let receipt = self.execute_with_fee_reserve(transaction, execution_config, fee_reserve);
+        match receipt.result {
+            TransactionResult::Commit(CommitResult {
+                outcome: TransactionOutcome::Success(..),
+                ..
+            }) => {
+                let fee_summary = receipt.execution.fee_summary;
+                let effective_cost_unit_price = fee_summary.cost_unit_price
+                    + fee_summary.cost_unit_price * fee_summary.tip_percentage / 100;
+                Ok(effective_cost_unit_price * fee_summary.cost_unit_consumed)
+            }
+            TransactionResult::Commit(CommitResult {
+                outcome: TransactionOutcome::Failure(error),
+                ..
+            })
+            | TransactionResult::Reject(RejectResult {
+                error: RejectionError::ErrorBeforeFeeLoanRepaid(error),
+            }) => Err(error),
+            TransactionResult::Reject(RejectResult {
+                error: RejectionError::SuccessButFeeLoanNotRepaid,
+            }) => panic!("Fee loan should always be repaid by the credit"),
+        }
+    }
+
+    /// Runs read-only component method calls against the current state, returning their outputs.
+    ///
+    /// Queries are neither signed nor charged, and nothing is committed. Fails with
+    /// `RuntimeError::WriteInQuery` if any call changes state.
+    pub fn query(
+        &mut self,
+        calls: Vec<ReadOnlyCall>,
+        execution_config: &ExecutionConfig,
+    ) -> Result<Vec<ScryptoValue>, RuntimeError> {
+        let instructions: Vec<Instruction> = calls
+            .into_iter()
+            .map(|call| Instruction::CallMethod {
+                method_identifier: MethodIdentifier::Scrypto {
+                    component_address: call.component_address,
+                    ident: call.method_name,
+                },
+                args: call.args,
+            })
+            .collect();
+        let blobs = HashMap::new();
+
+        // No costing module is installed, so the fee reserve is never charged
+        let mut track = Track::new(
+            self.substate_store,
+            SystemLoanFeeReserve::default(),
+            FeeTable::new(),
+        );
+        let mut execution_trace = ExecutionTrace::new();
+        let mut wasm_engine = self.wasm_engine.borrow_mut();
+        let mut wasm_instrumenter = self.wasm_instrumenter.borrow_mut();
+        let mut modules = Vec::<Box<dyn Module<SystemLoanFeeReserve>>>::new();
+        if execution_config.trace {
+            modules.push(Box::new(LoggerModule::new()));
+        }
+        let mut kernel = Kernel::new(
+            hash(scrypto_encode(&instructions)),
+            Vec::new(),
+            &blobs,
+            execution_config.max_call_depth,
+            execution_config.max_kv_value_bytes,
+            &mut track,
+            &mut **wasm_engine,
+            &mut wasm_instrumenter,
+            wasm_metering_params(),
+            &mut execution_trace,
+            modules,
+        );
+        let output = kernel.invoke_function(
+            FnIdentifier::Native(NativeFnIdentifier::TransactionProcessor(
+                TransactionProcessorFnIdentifier::Run,
+            )),
+            ScryptoValue::from_typed(&TransactionProcessorRunInput { instructions }),
+        )?;
+
+        if let Some(substate_id) = track.changed_substate_ids().into_iter().next() {
+            return Err(RuntimeError::WriteInQuery(substate_id));
+        }
+
+        scrypto_decode::<Vec<Vec<u8>>>(&output.raw)
+            .and_then(|outputs| {
+                outputs
+                    .iter()
+                    .map(|raw| ScryptoValue::from_slice(raw))
+                    .collect()
+            })
+            .map_err(|e| RuntimeError::KernelError(KernelError::DecodeError(e)))
+    }
+
     pub fn execute_with_fee_reserve<T: ExecutableTransaction, R: FeeReserve>(
         &mut self,
         transaction: &T,
         execution_config: &ExecutionConfig,
         fee_reserve: R,
+    ) -> TransactionReceipt {
+        self.execute_on_store(
+            &*self.substate_store,
+            transaction,
+            execution_config,
+            fee_reserve,
+        )
+    }
+
+    fn execute_on_store<T: ExecutableTransaction, R: FeeReserve>(
+        &self,
+        substate_store: &dyn ReadableSubstateStore,
+        transaction: &T,
+        execution_config: &ExecutionConfig,
+        fee_reserve: R,
     ) -> TransactionReceipt {
         let transaction_hash = transaction.transaction_hash();
         let initial_proofs = transaction.initial_proofs();
         let instructions = transaction.instructions().to_vec();
+        let message = transaction.message().map(|m| m.to_vec());
         let blobs: HashMap<Hash, Vec<u8>> = transaction
             .blobs()
             .iter()
@@ -127,7 +426,11 @@ where
         }
 
         // Prepare state track and execution trace
-        let track = Track::new(self.substate_store, fee_reserve, FeeTable::new());
+        let track = Track::new(
+            substate_store,
+            fee_reserve,
+            execution_config.fee_table.clone(),
+        );
 
         // Apply pre execution costing
         let pre_execution_result = track.apply_pre_execution_costs(transaction);
@@ -135,10 +438,16 @@ where
             Ok(track) => track,
             Err(err) => {
                 return TransactionReceipt {
-                    contents: TransactionContents { instructions },
+                    contents: TransactionContents {
+                        instructions,
+                        message,
+                    },
                     execution: TransactionExecution {
                         fee_summary: err.fee_summary,
                         application_logs: vec![],
+                        peak_wasm_memory_bytes: None,
+                        auth_zone_trace: None,
+                        instruction_cost_units: vec![],
                     },
                     result: TransactionResult::Reject(RejectResult {
                         error: RejectionError::ErrorBeforeFeeLoanRepaid(RuntimeError::ModuleError(
@@ -151,7 +460,15 @@ where
 
         // Invoke the function/method
         let mut execution_trace = ExecutionTrace::new();
+        if execution_config.profile_wasm_memory {
+            execution_trace.enable_wasm_memory_profiling();
+        }
+        if execution_config.trace_auth_zones {
+            execution_trace.enable_auth_zone_tracing();
+        }
         let invoke_result = {
+            let mut wasm_engine = self.wasm_engine.borrow_mut();
+            let mut wasm_instrumenter = self.wasm_instrumenter.borrow_mut();
             let mut modules = Vec::<Box<dyn Module<R>>>::new();
             if execution_config.trace {
                 modules.push(Box::new(LoggerModule::new()));
@@ -162,10 +479,11 @@ where
                 initial_proofs,
                 &blobs,
                 execution_config.max_call_depth,
+                execution_config.max_kv_value_bytes,
                 &mut track,
-                self.wasm_engine,
-                self.wasm_instrumenter,
-                WasmMeteringParams::new(InstructionCostRules::tiered(1, 5, 10, 5000), 512), // TODO: add to ExecutionConfig
+                &mut **wasm_engine,
+                &mut wasm_instrumenter,
+                wasm_metering_params(),
                 &mut execution_trace,
                 modules,
             );
@@ -189,10 +507,16 @@ where
         let track_receipt = track.finalize(invoke_result, execution_trace_receipt.resource_changes);
 
         let receipt = TransactionReceipt {
-            contents: TransactionContents { instructions },
+            contents: TransactionContents {
+                instructions,
+                message,
+            },
             execution: TransactionExecution {
                 fee_summary: track_receipt.fee_summary,
                 application_logs: track_receipt.application_logs,
+                peak_wasm_memory_bytes: execution_trace_receipt.peak_wasm_memory_bytes,
+                auth_zone_trace: execution_trace_receipt.auth_zone_trace,
+                instruction_cost_units: execution_trace_receipt.instruction_cost_units,
             },
             result: track_receipt.result,
         };
@@ -236,6 +560,9 @@ where
         let receipt = self.execute(transaction, fee_reserve_config, execution_config);
         if let TransactionResult::Commit(commit) = &receipt.result {
             commit.state_updates.commit(self.substate_store);
+            if let Some(on_commit) = &execution_config.on_commit {
+                on_commit(commit, execution_config.client_context.as_deref());
+            }
         }
         receipt
     }
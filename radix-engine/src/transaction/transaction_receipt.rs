@@ -2,7 +2,9 @@ use colored::*;
 use scrypto::core::NetworkDefinition;
 use transaction::model::*;
 
-use crate::engine::{RejectionError, ResourceChange, RuntimeError};
+use crate::engine::{
+    AuthZoneTraceEntry, RejectionError, ResourceChange, ResourceEvent, RuntimeError,
+};
 use crate::fee::FeeSummary;
 use crate::state_manager::StateDiff;
 use crate::types::*;
@@ -10,12 +12,21 @@ use crate::types::*;
 #[derive(Debug, TypeId, Encode, Decode)]
 pub struct TransactionContents {
     pub instructions: Vec<Instruction>,
+    pub message: Option<Vec<u8>>,
 }
 
 #[derive(Debug, TypeId, Encode, Decode)]
 pub struct TransactionExecution {
     pub fee_summary: FeeSummary,
     pub application_logs: Vec<(Level, String)>,
+    /// The peak linear memory of any WASM invocation, if memory profiling is enabled.
+    pub peak_wasm_memory_bytes: Option<usize>,
+    /// The auth zone state at each method invocation, if auth zone tracing is enabled.
+    pub auth_zone_trace: Option<Vec<AuthZoneTraceEntry>>,
+    /// The cost units consumed by each executed manifest instruction, by instruction index.
+    ///
+    /// Costs outside of the instructions, e.g. for validating the transaction, aren't included.
+    pub instruction_cost_units: Vec<(usize, u32)>,
 }
 
 /// Captures whether a transaction should be committed, and its other results
@@ -31,6 +42,8 @@ pub struct CommitResult {
     pub state_updates: StateDiff,
     pub entity_changes: EntityChanges,
     pub resource_changes: Vec<ResourceChange>,
+    /// The mints and burns of this transaction, in execution order.
+    pub resource_events: Vec<ResourceEvent>,
 }
 
 /// Captures whether a transaction's commit outcome is Success or Failure
@@ -194,6 +207,15 @@ impl fmt::Debug for TransactionReceipt {
             execution.fee_summary.cost_unit_price,
         )?;
 
+        if let Some(peak_wasm_memory_bytes) = execution.peak_wasm_memory_bytes {
+            write!(
+                f,
+                "\n{} {} bytes",
+                "Peak WASM Memory:".bold().green(),
+                peak_wasm_memory_bytes
+            )?;
+        }
+
         write!(
             f,
             "\n{} {}",
@@ -220,6 +242,15 @@ impl fmt::Debug for TransactionReceipt {
         // TODO - Need to fix the hardcoding of local simulator HRPs for transaction receipts, and for address formatting
         let bech32_encoder = Bech32Encoder::new(&NetworkDefinition::simulator());
 
+        if let Some(message) = &contents.message {
+            write!(
+                f,
+                "\n{} {}",
+                "Message:".bold().green(),
+                hex::encode(message)
+            )?;
+        }
+
         write!(f, "\n{}", "Instructions:".bold().green())?;
         for (i, inst) in contents.instructions.iter().enumerate() {
             write!(
@@ -14,18 +14,22 @@ pub use sbor::rust::string::ToString;
 pub use sbor::rust::vec;
 pub use sbor::rust::vec::Vec;
 pub use sbor::{Decode, DecodeError, Encode, Type, TypeId, Value};
-pub use scrypto::abi::{BlueprintAbi, Fn, ScryptoType};
+pub use scrypto::abi::{BlueprintAbi, Fn, MethodSignature, ScryptoType};
 pub use scrypto::address::{AddressError, Bech32Decoder, Bech32Encoder};
 pub use scrypto::component::{
-    ComponentAddAccessCheckInput, ComponentAddress, PackageAddress, PackagePublishInput,
+    ComponentAddAccessCheckInput, ComponentAddress, ComponentRestrictResourcesInput,
+    PackageAddress, PackagePublishInput,
 };
 pub use scrypto::constants::*;
 pub use scrypto::core::{
     AuthZoneFnIdentifier, BucketFnIdentifier, ComponentFnIdentifier, Expression, FnIdentifier,
     Level, NativeFnIdentifier, NetworkDefinition, PackageFnIdentifier, ProofFnIdentifier, Receiver,
-    ResourceManagerFnIdentifier, ScryptoActor, ScryptoRENode, SystemFnIdentifier,
-    SystemGetCurrentEpochInput, SystemGetTransactionHashInput, SystemSetEpochInput,
-    TransactionProcessorFnIdentifier, VaultFnIdentifier, WorktopFnIdentifier,
+    ResourceManagerFnIdentifier, ScryptoActor, ScryptoRENode, SubCallBudgetExceeded,
+    SystemFnIdentifier, SystemGetComponentMethodsInput, SystemGetCurrentEpochInput,
+    SystemGetCurrentTimeInput, SystemGetTransactionHashInput, SystemSetEpochInput,
+    SystemSetTimeInput, SystemVerifyEcdsaSecp256k1SignatureInput,
+    SystemVerifyEddsaEd25519SignatureInput, TransactionProcessorFnIdentifier, VaultFnIdentifier,
+    WorktopFnIdentifier,
 };
 pub use scrypto::crypto::{
     EcdsaSecp256k1PublicKey, EcdsaSecp256k1Signature, EddsaEd25519PublicKey, EddsaEd25519Signature,
@@ -39,18 +43,20 @@ pub use scrypto::resource::{
     BucketCreateProofInput, BucketGetAmountInput, BucketGetNonFungibleIdsInput,
     BucketGetResourceAddressInput, BucketPutInput, BucketTakeInput, BucketTakeNonFungiblesInput,
     ConsumingBucketBurnInput, ConsumingProofDropInput, MintParams, Mutability, NonFungibleAddress,
-    NonFungibleId, ProofCloneInput, ProofGetAmountInput, ProofGetNonFungibleIdsInput,
-    ProofGetResourceAddressInput, ProofRule, ResourceAddress, ResourceManagerCreateBucketInput,
-    ResourceManagerCreateInput, ResourceManagerCreateVaultInput, ResourceManagerGetMetadataInput,
+    NonFungibleDataSchema, NonFungibleId, ProofCloneInput, ProofGetAmountInput,
+    ProofGetNonFungibleIdsInput, ProofGetResourceAddressInput, ProofRule, ResourceAddress,
+    ResourceManagerCreateBucketInput, ResourceManagerCreateInput, ResourceManagerCreateVaultInput,
+    ResourceManagerCreateWithAddressSeedInput, ResourceManagerGetMetadataInput,
     ResourceManagerGetNonFungibleInput, ResourceManagerGetResourceTypeInput,
     ResourceManagerGetTotalSupplyInput, ResourceManagerLockAuthInput, ResourceManagerMintInput,
-    ResourceManagerNonFungibleExistsInput, ResourceManagerUpdateAuthInput,
-    ResourceManagerUpdateMetadataInput, ResourceManagerUpdateNonFungibleDataInput,
-    ResourceMethodAuthKey, ResourceType, SoftCount, SoftDecimal, SoftResource,
-    SoftResourceOrNonFungible, SoftResourceOrNonFungibleList, VaultCreateProofByAmountInput,
-    VaultCreateProofByIdsInput, VaultCreateProofInput, VaultGetAmountInput,
-    VaultGetNonFungibleIdsInput, VaultGetResourceAddressInput, VaultLockFeeInput, VaultPutInput,
-    VaultTakeInput, VaultTakeNonFungiblesInput, LOCKED, MUTABLE,
+    ResourceManagerNonFungibleExistsInput, ResourceManagerSetTransferHookInput,
+    ResourceManagerUpdateAuthInput, ResourceManagerUpdateMetadataInput,
+    ResourceManagerUpdateNonFungibleDataInput, ResourceMethodAuthKey, ResourceType, SoftCount,
+    SoftDecimal, SoftResource, SoftResourceOrNonFungible, SoftResourceOrNonFungibleList,
+    TransferHook, TransferHookInput, TransferHookOutcome, TransferKind,
+    VaultCreateProofByAmountInput, VaultCreateProofByIdsInput, VaultCreateProofInput,
+    VaultGetAmountInput, VaultGetNonFungibleIdsInput, VaultGetResourceAddressInput,
+    VaultLockFeeInput, VaultPutInput, VaultTakeInput, VaultTakeNonFungiblesInput, LOCKED, MUTABLE,
 };
 pub use scrypto::values::{ScryptoValue, ScryptoValueReplaceError};
 
@@ -9,9 +9,15 @@ pub const EXPORT_MEMORY: &str = "memory";
 pub const EXPORT_SCRYPTO_ALLOC: &str = "scrypto_alloc";
 pub const EXPORT_SCRYPTO_FREE: &str = "scrypto_free";
 
+/// The maximum size of the package code: `3 MiB`, well below the transaction payload limit
+pub const DEFAULT_MAX_CODE_SIZE: usize = 3 * 1024 * 1024;
+
 /// The maximum initial memory size: `64 Pages * 64 KiB per Page = 4 MiB`
 pub const DEFAULT_MAX_INITIAL_MEMORY_SIZE_PAGES: u32 = 64;
 
+/// The maximum declared memory size: `64 Pages * 64 KiB per Page = 4 MiB`
+pub const DEFAULT_MAX_MEMORY_SIZE_PAGES: u32 = 64;
+
 /// The maximum initial table size
 pub const DEFAULT_MAX_INITIAL_TABLE_SIZE: u32 = 1024;
 
@@ -7,6 +7,8 @@ use crate::types::*;
 /// Represents an error when validating a WASM file.
 #[derive(Debug, PartialEq, Eq, Clone, TypeId, Encode, Decode)]
 pub enum PrepareError {
+    /// The code exceeds the maximum size.
+    CodeTooLarge { size: usize, limit: usize },
     /// Failed to deserialize.
     /// See https://webassembly.github.io/spec/core/syntax/index.html
     DeserializationError,
@@ -53,6 +55,8 @@ pub enum PrepareError {
 pub enum InvalidImport {
     /// The import is not allowed
     ImportNotAllowed,
+    /// The import is not a known host function
+    DisallowedImport { module: String, name: String },
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, TypeId, Encode, Decode)]
@@ -65,6 +69,8 @@ pub enum InvalidMemory {
     TooManyMemories,
     /// The initial memory size is too large.
     InitialMemorySizeLimitExceeded,
+    /// The declared maximum memory size is too large.
+    MemoryTooLarge { declared: u32, limit: u32 },
     /// The wasm module does not have the `memory` export.
     MemoryNotExported,
 }
@@ -185,9 +185,16 @@ impl WasmModule {
                             continue;
                         }
                     }
+
+                    return Err(PrepareError::InvalidImport(InvalidImport::ImportNotAllowed));
                 }
 
-                return Err(PrepareError::InvalidImport(InvalidImport::ImportNotAllowed));
+                return Err(PrepareError::InvalidImport(
+                    InvalidImport::DisallowedImport {
+                        module: entry.module().to_string(),
+                        name: entry.field().to_string(),
+                    },
+                ));
             }
         }
 
@@ -234,6 +241,28 @@ impl WasmModule {
         Ok(self)
     }
 
+    pub fn enforce_max_memory_limit(
+        self,
+        max_memory_size_pages: u32,
+    ) -> Result<Self, PrepareError> {
+        // A module without a declared maximum can only grow through `memory.grow`, which is
+        // metered; a declared maximum is reserved upfront by some engines.
+        if let Some(section) = self.module.memory_section() {
+            for memory in section.entries() {
+                if let Some(declared) = memory.limits().maximum() {
+                    if declared > max_memory_size_pages {
+                        return Err(PrepareError::InvalidMemory(InvalidMemory::MemoryTooLarge {
+                            declared,
+                            limit: max_memory_size_pages,
+                        }));
+                    }
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
     pub fn enforce_table_limit(self, max_initial_table_size: u32) -> Result<Self, PrepareError> {
         if let Some(section) = self.module.table_section() {
             if section.entries().len() > 1 {
@@ -388,6 +417,25 @@ impl WasmModule {
         Ok(self)
     }
 
+    pub fn function_count(&self) -> usize {
+        self.module
+            .code_section()
+            .map(|sec| sec.bodies().len())
+            .unwrap_or(0)
+    }
+
+    pub fn instruction_count(&self) -> usize {
+        self.module
+            .code_section()
+            .map(|sec| {
+                sec.bodies()
+                    .iter()
+                    .map(|body| body.code().elements().len())
+                    .sum()
+            })
+            .unwrap_or(0)
+    }
+
     pub fn to_bytes(self) -> Result<(Vec<u8>, Vec<String>), PrepareError> {
         let function_exports = self
             .module
@@ -528,6 +576,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_import() {
+        let code = wat2wasm(
+            r#"
+            (module
+                (import "env" "radix_engine" (func $radix_engine (param i32) (result i32)))
+            )
+            "#,
+        )
+        .unwrap();
+        assert!(WasmModule::init(&code)
+            .unwrap()
+            .enforce_import_limit()
+            .is_ok());
+        assert_invalid_wasm!(
+            r#"
+            (module
+                (import "env" "my_func" (func $my_func (param i32) (result i32)))
+            )
+            "#,
+            PrepareError::InvalidImport(InvalidImport::DisallowedImport {
+                module: "env".to_string(),
+                name: "my_func".to_string()
+            }),
+            WasmModule::enforce_import_limit
+        );
+        assert_invalid_wasm!(
+            r#"
+            (module
+                (import "env" "radix_engine" (func $radix_engine (param i64) (result i32)))
+            )
+            "#,
+            PrepareError::InvalidImport(InvalidImport::ImportNotAllowed),
+            WasmModule::enforce_import_limit
+        );
+    }
+
     #[test]
     fn test_memory() {
         assert_invalid_wasm!(
@@ -620,7 +705,11 @@ mod tests {
                     },
                     output: sbor::Type::Unit,
                     export_name: "Test_f".to_string(),
+                    input_defaults: vec![],
+                    auth: None,
                 }],
+                events: vec![],
+                errors: vec![],
             },
         );
         assert_invalid_wasm!(
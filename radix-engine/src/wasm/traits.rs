@@ -26,6 +26,11 @@ pub trait WasmInstance {
         args: &ScryptoValue,
         runtime: &mut Box<dyn WasmRuntime + 'r>,
     ) -> Result<ScryptoValue, InvokeError<WasmError>>;
+
+    /// Returns the current size of the linear memory, in bytes.
+    ///
+    /// As linear memory never shrinks, this is also the peak usage of the instance so far.
+    fn memory_size(&self) -> usize;
 }
 
 /// A Scrypto WASM engine validates, instruments and runs Scrypto modules.
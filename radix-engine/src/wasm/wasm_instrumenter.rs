@@ -1,8 +1,20 @@
 use crate::types::*;
 use crate::wasm::{WasmMeteringParams, WasmModule};
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstrumentationReport {
+    /// The size of the code before instrumentation, in bytes
+    pub original_size: usize,
+    /// The size of the code after instrumentation, in bytes
+    pub instrumented_size: usize,
+    /// The number of functions defined by the code, excluding imports
+    pub function_count: usize,
+    /// The number of original instructions covered by instruction metering
+    pub metered_instruction_count: usize,
+}
+
 pub struct WasmInstrumenter {
-    cache: HashMap<(Hash, Hash), Vec<u8>>,
+    cache: HashMap<(Hash, Hash), (Vec<u8>, InstrumentationReport)>,
 }
 
 impl WasmInstrumenter {
@@ -14,18 +26,73 @@ impl WasmInstrumenter {
     }
 
     pub fn instrument(&mut self, code: &[u8], wasm_metering_params: &WasmMeteringParams) -> &[u8] {
+        &self.instrument_cached(code, wasm_metering_params).0
+    }
+
+    pub fn instrument_with_report(
+        &mut self,
+        code: &[u8],
+        wasm_metering_params: &WasmMeteringParams,
+    ) -> (Vec<u8>, InstrumentationReport) {
+        self.instrument_cached(code, wasm_metering_params).clone()
+    }
+
+    fn instrument_cached(
+        &mut self,
+        code: &[u8],
+        wasm_metering_params: &WasmMeteringParams,
+    ) -> &(Vec<u8>, InstrumentationReport) {
         let code_hash = hash(code);
         self.cache
             .entry((code_hash, wasm_metering_params.identifier()))
             .or_insert_with(|| {
-                WasmModule::init(code)
-                    .and_then(|m| {
-                        m.inject_instruction_metering(wasm_metering_params.instruction_cost_rules())
-                    })
+                let module = WasmModule::init(code).expect("Failed to instrument WASM module");
+                let function_count = module.function_count();
+                let metered_instruction_count = module.instruction_count();
+                let instrumented_code = module
+                    .inject_instruction_metering(wasm_metering_params.instruction_cost_rules())
                     .and_then(|m| m.inject_stack_metering(wasm_metering_params.max_stack_size()))
                     .and_then(|m| m.to_bytes())
                     .expect("Failed to instrument WASM module")
-                    .0
+                    .0;
+                let report = InstrumentationReport {
+                    original_size: code.len(),
+                    instrumented_size: instrumented_code.len(),
+                    function_count,
+                    metered_instruction_count,
+                };
+                (instrumented_code, report)
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wasm::InstructionCostRules;
+
+    #[test]
+    fn test_instrument_with_report() {
+        // (module (func i32.const 1 drop))
+        let code = vec![
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+            0x01, 0x04, 0x01, 0x60, 0x00, 0x00, // type section
+            0x03, 0x02, 0x01, 0x00, // function section
+            0x0a, 0x07, 0x01, 0x05, 0x00, 0x41, 0x01, 0x1a, 0x0b, // code section
+        ];
+        let params = WasmMeteringParams::new(InstructionCostRules::constant(1, 100), 512);
+        let mut instrumenter = WasmInstrumenter::new();
+
+        let (instrumented_code, report) = instrumenter.instrument_with_report(&code, &params);
+
+        assert_eq!(report.original_size, code.len());
+        assert_eq!(report.instrumented_size, instrumented_code.len());
+        assert!(report.instrumented_size > report.original_size);
+        assert_eq!(report.function_count, 1);
+        assert_eq!(report.metered_instruction_count, 3);
+        assert_eq!(
+            instrumenter.instrument(&code, &params),
+            &instrumented_code[..]
+        );
+    }
+}
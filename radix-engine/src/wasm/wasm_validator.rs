@@ -2,7 +2,9 @@ use crate::types::*;
 use crate::wasm::*;
 
 pub struct WasmValidator {
+    pub max_code_size: usize,
     pub max_initial_memory_size_pages: u32,
+    pub max_memory_size_pages: u32,
     pub max_initial_table_size: u32,
     pub max_number_of_br_table_targets: u32,
     pub max_number_of_functions: u32,
@@ -12,7 +14,9 @@ pub struct WasmValidator {
 impl Default for WasmValidator {
     fn default() -> Self {
         Self {
+            max_code_size: DEFAULT_MAX_CODE_SIZE,
             max_initial_memory_size_pages: DEFAULT_MAX_INITIAL_MEMORY_SIZE_PAGES,
+            max_memory_size_pages: DEFAULT_MAX_MEMORY_SIZE_PAGES,
             max_initial_table_size: DEFAULT_MAX_INITIAL_TABLE_SIZE,
             max_number_of_br_table_targets: DEFAULT_MAX_NUMBER_OF_BR_TABLE_TARGETS,
             max_number_of_functions: DEFAULT_MAX_NUMBER_OF_FUNCTIONS,
@@ -27,6 +31,13 @@ impl WasmValidator {
         code: &[u8],
         blueprints: &HashMap<String, BlueprintAbi>,
     ) -> Result<(), PrepareError> {
+        if code.len() > self.max_code_size {
+            return Err(PrepareError::CodeTooLarge {
+                size: code.len(),
+                limit: self.max_code_size,
+            });
+        }
+
         // Not all "valid" wasm modules are instrumentable, with the instrumentation library
         // we are using. To deal with this, we attempt to instrument the input module with
         // some mocked parameters and reject it if fails to do so.
@@ -38,6 +49,7 @@ impl WasmValidator {
             .enforce_no_start_function()?
             .enforce_import_limit()?
             .enforce_memory_limit(self.max_initial_memory_size_pages)?
+            .enforce_max_memory_limit(self.max_memory_size_pages)?
             .enforce_table_limit(self.max_initial_table_size)?
             .enforce_br_table_limit(self.max_number_of_br_table_targets)?
             .enforce_function_limit(self.max_number_of_functions)?
@@ -5,6 +5,7 @@ use wasmer::{
     imports, Function, HostEnvInitError, Instance, LazyInit, Module, RuntimeError, Store,
     Universal, Val, WasmerEnv,
 };
+use wasmer_compiler_cranelift::Cranelift;
 use wasmer_compiler_singlepass::Singlepass;
 
 use crate::types::*;
@@ -30,9 +31,22 @@ pub struct WasmerInstanceEnv {
     runtime_ptr: Arc<Mutex<usize>>,
 }
 
+/// The compiler backend of a `WasmerEngine`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompilerKind {
+    /// Fast and deterministic compilation, slower execution
+    Singlepass,
+    /// Slower compilation, optimized execution
+    Cranelift,
+}
+
+/// Compiled modules by code hash, which can be shared by engines on any thread.
+pub type WasmerModuleCache = Arc<Mutex<HashMap<Hash, Module>>>;
+
 pub struct WasmerEngine {
     store: Store,
-    modules: HashMap<Hash, WasmerModule>,
+    modules: WasmerModuleCache,
+    compiled_modules: usize,
 }
 
 pub fn send_value(
@@ -76,27 +90,19 @@ pub fn read_value(instance: &Instance, ptr: usize) -> Result<ScryptoValue, WasmE
         .exports
         .get_memory(EXPORT_MEMORY)
         .map_err(|_| WasmError::MemoryAccessError)?;
-    let size = memory.size().bytes().0;
+    // The memory is neither grown nor written while the slice is borrowed
+    let data = unsafe { memory.data_unchecked() };
+    let size = data.len();
     if size > ptr && size - ptr >= 4 {
         // read len
         let mut temp = [0u8; 4];
-        unsafe {
-            let from = memory.data_ptr().add(ptr);
-            ptr::copy(from, temp.as_mut_ptr(), 4);
-        }
+        temp.copy_from_slice(&data[ptr..ptr + 4]);
         let n = u32::from_le_bytes(temp) as usize;
 
         // read value
-        if size - ptr - 4 >= (n as usize) {
-            // TODO: avoid copying
-            let mut temp = Vec::with_capacity(n);
-            unsafe {
-                let from = memory.data_ptr().add(ptr).add(4);
-                ptr::copy(from, temp.as_mut_ptr(), n);
-                temp.set_len(n);
-            }
-
-            return ScryptoValue::from_slice(&temp).map_err(WasmError::InvalidScryptoValue);
+        if size - ptr - 4 >= n {
+            return ScryptoValue::from_wasm_memory(ptr + 4, n, data)
+                .map_err(WasmError::InvalidScryptoValue);
         }
     }
 
@@ -217,26 +223,126 @@ impl WasmInstance for WasmerInstance {
             Err(e) => Err(e.into()),
         }
     }
+
+    fn memory_size(&self) -> usize {
+        self.instance
+            .exports
+            .get_memory(EXPORT_MEMORY)
+            .map(|memory| memory.size().bytes().0)
+            .unwrap_or(0)
+    }
 }
 
 impl WasmerEngine {
     pub fn new() -> Self {
-        let compiler = Singlepass::new();
+        Self::new_with_compiler(CompilerKind::Singlepass)
+    }
+
+    pub fn new_with_compiler(compiler: CompilerKind) -> Self {
+        Self::create(compiler, Arc::new(Mutex::new(HashMap::new())))
+    }
+
+    /// Creates an engine which compiles modules into the given cache, and reuses the modules
+    /// compiled into it by other engines.
+    pub fn new_with_cache(cache: WasmerModuleCache) -> Self {
+        Self::create(CompilerKind::Singlepass, cache)
+    }
+
+    fn create(compiler: CompilerKind, cache: WasmerModuleCache) -> Self {
+        let engine = match compiler {
+            CompilerKind::Singlepass => Universal::new(Singlepass::new()).engine(),
+            CompilerKind::Cranelift => Universal::new(Cranelift::new()).engine(),
+        };
         Self {
-            store: Store::new(&Universal::new(compiler).engine()),
-            modules: HashMap::new(),
+            store: Store::new(&engine),
+            modules: cache,
+            compiled_modules: 0,
         }
     }
+
+    /// Returns the number of modules compiled by this engine, rather than taken from the cache.
+    pub fn compiled_modules(&self) -> usize {
+        self.compiled_modules
+    }
 }
 
 impl WasmEngine<WasmerInstance> for WasmerEngine {
     fn instantiate(&mut self, code: &[u8]) -> WasmerInstance {
         let code_hash = hash(code);
-        self.modules
+        // The lock is held while compiling, so that concurrent engines compile each code once
+        let module = self
+            .modules
+            .lock()
+            .expect("Failed to lock WASM module cache")
             .entry(code_hash)
-            .or_insert_with(|| WasmerModule {
-                module: Module::new(&self.store, code).expect("Failed to parse WASM module"),
+            .or_insert_with(|| {
+                self.compiled_modules += 1;
+                Module::new(&self.store, code).expect("Failed to parse WASM module")
             })
-            .instantiate()
+            .clone();
+        WasmerModule { module }.instantiate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::NopWasmRuntime;
+    use crate::fee::SystemLoanFeeReserve;
+
+    // An empty module
+    const CODE: [u8; 8] = [0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+
+    #[test]
+    fn test_shared_module_cache() {
+        let cache: WasmerModuleCache = Arc::new(Mutex::new(HashMap::new()));
+        let mut engine1 = WasmerEngine::new_with_cache(cache.clone());
+        let mut engine2 = WasmerEngine::new_with_cache(cache.clone());
+
+        engine1.instantiate(&CODE);
+        engine2.instantiate(&CODE);
+
+        assert_eq!(engine1.compiled_modules(), 1);
+        assert_eq!(engine2.compiled_modules(), 0);
+        assert_eq!(cache.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_compilers_produce_same_output() {
+        let code = wabt::wat2wasm(
+            r#"
+            (module
+                (func $scrypto_alloc (param $0 i32) (result i32)
+                    (i32.const 2048)
+                )
+                (func $scrypto_free (param $0 i32))
+                (func $Test_f (param $0 i32) (result i32)
+                    (i32.const 1024)
+                )
+                (memory $0 1)
+                (data (i32.const 1024) "\02\00\00\00\00\00")
+                (export "memory" (memory $0))
+                (export "scrypto_alloc" (func $scrypto_alloc))
+                (export "scrypto_free" (func $scrypto_free))
+                (export "Test_f" (func $Test_f))
+            )
+            "#,
+        )
+        .unwrap();
+
+        let mut outputs = Vec::new();
+        for compiler in [CompilerKind::Singlepass, CompilerKind::Cranelift] {
+            let mut engine = WasmerEngine::new_with_compiler(compiler);
+            let mut instance = engine.instantiate(&code);
+            let mut runtime: Box<dyn WasmRuntime> =
+                Box::new(NopWasmRuntime::new(SystemLoanFeeReserve::default()));
+            let output = instance
+                .invoke_export("Test_f", &ScryptoValue::unit(), &mut runtime)
+                .unwrap();
+            outputs.push(output.raw);
+        }
+
+        assert_eq!(outputs[0], ScryptoValue::unit().raw);
+        assert_eq!(outputs[0], outputs[1]);
     }
 }
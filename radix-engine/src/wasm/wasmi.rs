@@ -207,6 +207,10 @@ impl WasmInstance for WasmiInstance {
             _ => Err(InvokeError::Error(WasmError::InvalidReturnData)),
         }
     }
+
+    fn memory_size(&self) -> usize {
+        memory_units::Bytes::from(self.memory_ref.current_size()).0
+    }
 }
 
 impl WasmiEngine {
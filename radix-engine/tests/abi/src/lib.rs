@@ -44,6 +44,8 @@ pub extern "C" fn AbiComponent2_abi(_input: *mut u8) -> *mut u8 {
                 input: Type::Unit,
                 output: Type::U8,
                 export_name: "AbiComponent2_main".to_string(),
+                input_defaults: vec![],
+                auth: None,
             },
             Fn {
                 ident: "unit".to_string(),
@@ -51,6 +53,8 @@ pub extern "C" fn AbiComponent2_abi(_input: *mut u8) -> *mut u8 {
                 input: Type::Unit,
                 output: Type::Unit,
                 export_name: "AbiComponent2_main".to_string(),
+                input_defaults: vec![],
+                auth: None,
             },
             Fn {
                 ident: "bool".to_string(),
@@ -58,6 +62,8 @@ pub extern "C" fn AbiComponent2_abi(_input: *mut u8) -> *mut u8 {
                 input: Type::Bool,
                 output: Type::Unit,
                 export_name: "AbiComponent2_main".to_string(),
+                input_defaults: vec![],
+                auth: None,
             },
             Fn {
                 ident: "i8".to_string(),
@@ -65,6 +71,8 @@ pub extern "C" fn AbiComponent2_abi(_input: *mut u8) -> *mut u8 {
                 input: Type::I8,
                 output: Type::Unit,
                 export_name: "AbiComponent2_main".to_string(),
+                input_defaults: vec![],
+                auth: None,
             },
             Fn {
                 ident: "i16".to_string(),
@@ -72,6 +80,8 @@ pub extern "C" fn AbiComponent2_abi(_input: *mut u8) -> *mut u8 {
                 input: Type::I16,
                 output: Type::Unit,
                 export_name: "AbiComponent2_main".to_string(),
+                input_defaults: vec![],
+                auth: None,
             },
             Fn {
                 ident: "i32".to_string(),
@@ -79,6 +89,8 @@ pub extern "C" fn AbiComponent2_abi(_input: *mut u8) -> *mut u8 {
                 input: Type::I32,
                 output: Type::Unit,
                 export_name: "AbiComponent2_main".to_string(),
+                input_defaults: vec![],
+                auth: None,
             },
             Fn {
                 ident: "i64".to_string(),
@@ -86,6 +98,8 @@ pub extern "C" fn AbiComponent2_abi(_input: *mut u8) -> *mut u8 {
                 input: Type::I64,
                 output: Type::Unit,
                 export_name: "AbiComponent2_main".to_string(),
+                input_defaults: vec![],
+                auth: None,
             },
             Fn {
                 ident: "i128".to_string(),
@@ -93,6 +107,8 @@ pub extern "C" fn AbiComponent2_abi(_input: *mut u8) -> *mut u8 {
                 input: Type::I128,
                 output: Type::Unit,
                 export_name: "AbiComponent2_main".to_string(),
+                input_defaults: vec![],
+                auth: None,
             },
             Fn {
                 ident: "u8".to_string(),
@@ -100,6 +116,8 @@ pub extern "C" fn AbiComponent2_abi(_input: *mut u8) -> *mut u8 {
                 input: Type::U8,
                 output: Type::Unit,
                 export_name: "AbiComponent2_main".to_string(),
+                input_defaults: vec![],
+                auth: None,
             },
             Fn {
                 ident: "u16".to_string(),
@@ -107,6 +125,8 @@ pub extern "C" fn AbiComponent2_abi(_input: *mut u8) -> *mut u8 {
                 input: Type::U16,
                 output: Type::Unit,
                 export_name: "AbiComponent2_main".to_string(),
+                input_defaults: vec![],
+                auth: None,
             },
             Fn {
                 ident: "u32".to_string(),
@@ -114,6 +134,8 @@ pub extern "C" fn AbiComponent2_abi(_input: *mut u8) -> *mut u8 {
                 input: Type::U32,
                 output: Type::Unit,
                 export_name: "AbiComponent2_main".to_string(),
+                input_defaults: vec![],
+                auth: None,
             },
             Fn {
                 ident: "u64".to_string(),
@@ -121,6 +143,8 @@ pub extern "C" fn AbiComponent2_abi(_input: *mut u8) -> *mut u8 {
                 input: Type::U64,
                 output: Type::Unit,
                 export_name: "AbiComponent2_main".to_string(),
+                input_defaults: vec![],
+                auth: None,
             },
             Fn {
                 ident: "u128".to_string(),
@@ -128,6 +152,8 @@ pub extern "C" fn AbiComponent2_abi(_input: *mut u8) -> *mut u8 {
                 input: Type::U128,
                 output: Type::Unit,
                 export_name: "AbiComponent2_main".to_string(),
+                input_defaults: vec![],
+                auth: None,
             },
             Fn {
                 ident: "result".to_string(),
@@ -138,6 +164,8 @@ pub extern "C" fn AbiComponent2_abi(_input: *mut u8) -> *mut u8 {
                 },
                 output: Type::Unit,
                 export_name: "AbiComponent2_main".to_string(),
+                input_defaults: vec![],
+                auth: None,
             },
             Fn {
                 ident: "tree_map".to_string(),
@@ -148,6 +176,8 @@ pub extern "C" fn AbiComponent2_abi(_input: *mut u8) -> *mut u8 {
                 },
                 output: Type::Unit,
                 export_name: "AbiComponent2_main".to_string(),
+                input_defaults: vec![],
+                auth: None,
             },
             Fn {
                 ident: "hash_set".to_string(),
@@ -157,8 +187,12 @@ pub extern "C" fn AbiComponent2_abi(_input: *mut u8) -> *mut u8 {
                 },
                 output: Type::Unit,
                 export_name: "AbiComponent2_main".to_string(),
+                input_defaults: vec![],
+                auth: None,
             },
         ],
+        events: vec![],
+        errors: vec![],
     };
 
     ::scrypto::buffer::scrypto_encode_to_buffer(&abi)
@@ -157,3 +157,35 @@ fn none_optional_bucket_argument_should_succeed() {
     // Assert
     receipt.expect_commit_success();
 }
+
+#[test]
+fn defaulted_trailing_argument_can_be_omitted() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/arguments");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(package_address, "Arguments", "new", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component_address = receipt
+        .expect_commit()
+        .entity_changes
+        .new_component_addresses[0];
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_method(component_address, "add", args!(5u32, 3u32))
+        .call_method(component_address, "add", args!(5u32))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let outputs = receipt.expect_commit_success();
+    let with_argument: u32 = scrypto_decode(&outputs[1]).unwrap();
+    let without_argument: u32 = scrypto_decode(&outputs[2]).unwrap();
+    assert_eq!(with_argument, 8);
+    assert_eq!(without_argument, 5);
+}
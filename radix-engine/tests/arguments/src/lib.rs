@@ -4,6 +4,12 @@ blueprint! {
     struct Arguments {}
 
     impl Arguments {
+        pub fn new() -> ComponentAddress {
+            Self {}.instantiate().globalize()
+        }
+        pub fn add(&self, a: u32, #[default] b: u32) -> u32 {
+            a + b
+        }
         pub fn vector_argument(arg: Vec<Bucket>) -> Vec<Bucket> {
             arg
         }
@@ -1,3 +1,4 @@
+use radix_engine::engine::RuntimeError;
 use radix_engine::ledger::TypedInMemorySubstateStore;
 use radix_engine::types::*;
 use scrypto_unit::*;
@@ -134,3 +135,73 @@ fn can_make_cross_component_call_with_authorization() {
     // Assert
     receipt.expect_commit_success();
 }
+
+fn create_method_auth_component(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    account: ComponentAddress,
+) -> (ComponentAddress, ResourceAddress) {
+    let package_address = test_runner.compile_and_publish("./tests/component");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(
+            package_address,
+            "MethodAuthComponent",
+            "create_component",
+            args!(),
+        )
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let commit = receipt.expect_commit();
+    (
+        commit.entity_changes.new_component_addresses[0],
+        commit.entity_changes.new_resource_addresses[0],
+    )
+}
+
+#[test]
+fn cannot_call_method_with_declared_auth_without_badge() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_account();
+    let (component, _) = create_method_auth_component(&mut test_runner, account);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_method(component, "increment", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(
+        |e| matches!(e, RuntimeError::AccessDenied { method } if method == "increment"),
+    );
+}
+
+#[test]
+fn can_call_method_with_declared_auth_with_badge() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_account();
+    let (component, admin_badge) = create_method_auth_component(&mut test_runner, account);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .create_proof_from_account(admin_badge, account)
+        .call_method(component, "increment", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![public_key.into()]);
+
+    // Assert
+    let outputs = receipt.expect_commit_success();
+    let counter: u32 = scrypto_decode(&outputs[2]).unwrap();
+    assert_eq!(counter, 1);
+}
@@ -294,3 +294,64 @@ fn chess_should_allow_second_player_to_move_after_first_player() {
     // Assert
     receipt.expect_commit_success();
 }
+
+fn test_runtime_built_rule(signer_public_keys: &[usize], should_succeed: bool) {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let key_and_addresses: Vec<(
+        EcdsaSecp256k1PublicKey,
+        EcdsaSecp256k1PrivateKey,
+        NonFungibleAddress,
+    )> = (0..3)
+        .map(|_| test_runner.new_key_pair_with_auth_address())
+        .collect();
+    let voters: Vec<NonFungibleAddress> = key_and_addresses
+        .iter()
+        .map(|(_, _, addr)| addr.clone())
+        .collect();
+    let public_keys: Vec<PublicKey> = signer_public_keys
+        .iter()
+        .map(|index| key_and_addresses.get(*index).unwrap().0.into())
+        .collect();
+    let package = test_runner.compile_and_publish("./tests/component");
+    let manifest1 = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(
+            package,
+            "AuthListComponent",
+            "create_component_with_voters",
+            args!(2u8, voters),
+        )
+        .build();
+    let receipt1 = test_runner.execute_manifest(manifest1, vec![]);
+    receipt1.expect_commit_success();
+    let component = receipt1
+        .expect_commit()
+        .entity_changes
+        .new_component_addresses[0];
+
+    // Act
+    let manifest2 = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_method(component, "get_secret", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest2, public_keys);
+
+    // Assert
+    if should_succeed {
+        receipt.expect_commit_success();
+    } else {
+        receipt.expect_specific_failure(is_auth_error);
+    }
+}
+
+#[test]
+fn runtime_built_rule_should_allow_me_to_call_method_when_signed_by_enough_voters() {
+    test_runtime_built_rule(&[0, 2], true);
+}
+
+#[test]
+fn runtime_built_rule_should_fail_if_not_signed_by_enough_voters() {
+    test_runtime_built_rule(&[1], false);
+}
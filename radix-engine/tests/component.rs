@@ -1,5 +1,5 @@
 use radix_engine::engine::{KernelError, RuntimeError};
-use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::ledger::{QueryableSubstateStore, TypedInMemorySubstateStore};
 use radix_engine::types::*;
 use scrypto::address::Bech32Decoder;
 use scrypto::engine::types::SubstateId;
@@ -136,3 +136,183 @@ fn missing_component_address_in_manifest_should_cause_rejection() {
     // Assert
     receipt.expect_rejection();
 }
+
+fn create_xrd_only_component(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+) -> ComponentAddress {
+    let package_address = test_runner.compile_and_publish("./tests/component");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(
+            package_address,
+            "RestrictedComponent",
+            "create",
+            args!(BTreeSet::from([RADIX_TOKEN])),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt
+        .expect_commit()
+        .entity_changes
+        .new_component_addresses[0]
+}
+
+#[test]
+fn restricted_component_should_accept_allowed_resource() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_account();
+    let component_address = create_xrd_only_component(&mut test_runner);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), account)
+        .withdraw_from_account_by_amount(dec!("10"), RADIX_TOKEN, account)
+        .take_from_worktop(RADIX_TOKEN, |builder, bucket_id| {
+            builder.call_method(
+                component_address,
+                "deposit",
+                args!(scrypto::resource::Bucket(bucket_id)),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![public_key.into()]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn restricted_component_should_reject_other_resource() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_account();
+    let token = test_runner.create_fungible_resource(dec!("100"), 0, account);
+    let component_address = create_xrd_only_component(&mut test_runner);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), account)
+        .withdraw_from_account_by_amount(dec!("10"), token, account)
+        .take_from_worktop(token, |builder, bucket_id| {
+            builder.call_method(
+                component_address,
+                "deposit",
+                args!(scrypto::resource::Bucket(bucket_id)),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![public_key.into()]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ResourceNotAccepted {
+                component_address: address,
+                resource_address,
+            } if *address == component_address && *resource_address == token
+        )
+    });
+}
+
+#[test]
+fn component_dependencies_should_include_transitive_references() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/component");
+    let mut create_component = |dependency: Option<ComponentAddress>| {
+        let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+            .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+            .call_function(
+                package_address,
+                "DependentComponent",
+                "create_component",
+                args!(dependency),
+            )
+            .build();
+        let receipt = test_runner.execute_manifest(manifest, vec![]);
+        receipt
+            .expect_commit()
+            .entity_changes
+            .new_component_addresses[0]
+    };
+    let c = create_component(None);
+    let b = create_component(Some(c));
+    let a = create_component(Some(b));
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_method(c, "set_dependency", args!(Some(a)))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success();
+
+    // Act
+    let direct = store.component_dependencies(a).unwrap();
+    let transitive = store.transitive_component_dependencies(a).unwrap();
+
+    // Assert
+    let mut expected = vec![b, c];
+    expected.sort();
+    assert_eq!(direct, vec![b]);
+    assert_eq!(transitive, expected);
+}
+
+#[test]
+fn storage_rent_should_cover_component_and_owned_vaults() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/component");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(
+            package_address,
+            "ComponentTest",
+            "create_component",
+            args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component = receipt
+        .expect_commit()
+        .entity_changes
+        .new_component_addresses[0];
+    // Act
+    let size = store.component_storage_size(component).unwrap();
+    let rent = store.storage_rent(component, dec!("0.001"), 10).unwrap();
+
+    // Assert
+    // ComponentInfo (85 bytes) + ComponentState (91 bytes) + the test vault (114 bytes)
+    assert_eq!(size, 290);
+    assert_eq!(rent, dec!("2.9"));
+}
+
+#[test]
+fn component_storage_size_should_include_key_value_store_entries() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/vault");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(package_address, "VaultTest", "new_vault_into_map", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component = receipt
+        .expect_commit()
+        .entity_changes
+        .new_component_addresses[0];
+
+    // Act
+    let size = store.component_storage_size(component).unwrap();
+
+    // Assert
+    // ComponentInfo (81 bytes) + ComponentState (127 bytes) + its own vault (114 bytes)
+    // + the key value store length (41 bytes) + its entry (17 byte key, 81 byte value)
+    // + the vault it holds (114 bytes)
+    assert_eq!(size, 575);
+}
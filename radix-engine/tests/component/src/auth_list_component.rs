@@ -17,6 +17,22 @@ blueprint! {
             component.globalize()
         }
 
+        pub fn create_component_with_voters(
+            count: u8,
+            voters: Vec<NonFungibleAddress>,
+        ) -> ComponentAddress {
+            let rule = AccessRuleBuilder::new()
+                .or(require_n_of(count, voters.clone()))
+                .build();
+            let mut component = Self {
+                count,
+                auth: voters,
+            }
+            .instantiate();
+            component.add_access_check(AccessRules::new().method("get_secret", rule));
+            component.globalize()
+        }
+
         pub fn update_count(&mut self, count: u8) {
             self.count = count;
         }
@@ -34,6 +34,10 @@ blueprint! {
             self.secret.clone()
         }
 
+        pub fn set_component_state(&mut self, secret: String) {
+            self.secret = secret;
+        }
+
         pub fn put_component_state(&mut self) -> Bucket {
             // Take resource from vault
             let bucket = self.test_vault.take(1);
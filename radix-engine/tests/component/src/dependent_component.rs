@@ -0,0 +1,17 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct DependentComponent {
+        dependency: Option<ComponentAddress>,
+    }
+
+    impl DependentComponent {
+        pub fn create_component(dependency: Option<ComponentAddress>) -> ComponentAddress {
+            Self { dependency }.instantiate().globalize()
+        }
+
+        pub fn set_dependency(&mut self, dependency: Option<ComponentAddress>) {
+            self.dependency = dependency;
+        }
+    }
+}
@@ -3,5 +3,8 @@ pub mod auth_list_component;
 pub mod chess;
 pub mod component;
 pub mod cross_component;
+pub mod dependent_component;
 pub mod external_blueprint_target;
+pub mod method_auth_component;
 pub mod reentrant_component;
+pub mod restricted_component;
@@ -0,0 +1,29 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct MethodAuthComponent {
+        admin_badge: ResourceAddress,
+        counter: u32,
+    }
+
+    impl MethodAuthComponent {
+        pub fn create_component() -> (ComponentAddress, Bucket) {
+            let admin_badge = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_NONE)
+                .initial_supply(1);
+            let component = Self {
+                admin_badge: admin_badge.resource_address(),
+                counter: 0,
+            }
+            .instantiate()
+            .globalize();
+            (component, admin_badge)
+        }
+
+        #[auth(require(admin_badge))]
+        pub fn increment(&mut self) -> u32 {
+            self.counter += 1;
+            self.counter
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct RestrictedComponent {
+        vaults: Vec<Vault>,
+    }
+
+    impl RestrictedComponent {
+        pub fn create(accepted: BTreeSet<ResourceAddress>) -> ComponentAddress {
+            let mut component = Self { vaults: Vec::new() }.instantiate();
+            component.restrict_resources(accepted);
+            component.globalize()
+        }
+
+        pub fn deposit(&mut self, bucket: Bucket) {
+            self.vaults.push(Vault::with_bucket(bucket));
+        }
+    }
+}
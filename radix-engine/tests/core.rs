@@ -37,3 +37,23 @@ fn test_call() {
     let receipt = test_runner.execute_manifest(manifest, vec![public_key.into()]);
     receipt.expect_commit_success();
 }
+
+#[test]
+fn call_method_raw_should_return_undecoded_value() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/core");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(package_address, "RawCallTest", "call_price_raw", args![])
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let outputs = receipt.expect_commit_success();
+    let type_id: u8 = scrypto_decode(&outputs[1]).unwrap();
+    assert_eq!(type_id, ScryptoType::Decimal.id());
+}
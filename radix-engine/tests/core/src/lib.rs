@@ -57,3 +57,27 @@ blueprint! {
         }
     }
 }
+
+blueprint! {
+    struct RawCallTest {
+        price: Decimal,
+    }
+
+    impl RawCallTest {
+        pub fn price(&self) -> Decimal {
+            self.price
+        }
+
+        pub fn call_price_raw() -> u8 {
+            let component_address = RawCallTest { price: dec!("1.5") }
+                .instantiate()
+                .globalize();
+
+            let value = Runtime::call_method_raw(component_address, "price", args!());
+            match value.dom {
+                sbor::Value::Custom { type_id, .. } => type_id,
+                _ => panic!("Expected a custom value"),
+            }
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig};
 use radix_engine::types::*;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
+use transaction::model::TestTransaction;
 
 #[test]
 fn test_trace_resource_transfers() {
@@ -48,3 +50,117 @@ fn test_trace_resource_transfers() {
             && r.component_address == target_component
             && r.amount == Decimal::from(transfer_amount)));
 }
+
+#[test]
+fn test_trace_peak_wasm_memory() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/execution_trace");
+    let buffer_size = 256 * 1024u32;
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(
+            package_address,
+            "ExecutionTraceTest",
+            "allocate_buffer",
+            args!(buffer_size),
+        )
+        .build();
+    let transaction = TestTransaction::new(manifest, test_runner.next_transaction_nonce(), vec![]);
+    let receipt = test_runner.execute_transaction(
+        &transaction,
+        &FeeReserveConfig::standard(),
+        &ExecutionConfig {
+            profile_wasm_memory: true,
+            ..ExecutionConfig::standard()
+        },
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let peak_wasm_memory_bytes = receipt
+        .execution
+        .peak_wasm_memory_bytes
+        .expect("Memory should be profiled");
+    assert!(peak_wasm_memory_bytes >= buffer_size as usize);
+}
+
+#[test]
+fn test_peak_wasm_memory_not_traced_by_default() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/execution_trace");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(
+            package_address,
+            "ExecutionTraceTest",
+            "allocate_buffer",
+            args!(16u32),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(receipt.execution.peak_wasm_memory_bytes, None);
+}
+
+#[test]
+fn test_trace_auth_zone_during_protected_method() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_account();
+    test_runner.set_trace_auth_zones(true);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .withdraw_from_account_by_amount(1.into(), RADIX_TOKEN, account)
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![public_key.into()]);
+
+    // Assert
+    receipt.expect_commit_success();
+    let auth_zone_trace = receipt
+        .execution
+        .auth_zone_trace
+        .expect("Auth zones should be traced");
+    let withdraw = auth_zone_trace
+        .iter()
+        .find(|entry| {
+            entry.node_id == RENodeId::Component(account)
+                && matches!(&entry.fn_identifier, FnIdentifier::Scrypto { ident, .. } if ident == "withdraw_by_amount")
+        })
+        .expect("Withdraw should be traced");
+    assert!(withdraw.contains_proof_of(&ECDSA_TOKEN));
+}
+
+#[test]
+fn test_auth_zone_not_traced_by_default() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(receipt.execution.auth_zone_trace, None);
+}
@@ -41,5 +41,9 @@ blueprint! {
         pub fn put(&mut self, b: Bucket) {
             self.vault.put(b)
         }
+
+        pub fn allocate_buffer(size: u32) -> Vec<u8> {
+            vec![0xff; size as usize]
+        }
     }
 }
@@ -1,10 +1,12 @@
 use radix_engine::engine::ApplicationError;
 use radix_engine::engine::RuntimeError;
+use radix_engine::fee::{FeeEntry, FeeTable};
 use radix_engine::ledger::TypedInMemorySubstateStore;
 use radix_engine::ledger::WriteableSubstateStore;
 use radix_engine::model::KeyValueStoreEntryWrapper;
 use radix_engine::model::WorktopError;
 use radix_engine::transaction::TransactionReceipt;
+use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig};
 use radix_engine::types::*;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
@@ -353,3 +355,103 @@ fn test_contingent_fee_accounting_failure() {
     );
     assert_eq!(account2_new_balance, account2_balance);
 }
+
+#[test]
+fn instruction_cost_units_should_break_down_execution_cost() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_account();
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_method(account, "balance", args!(RADIX_TOKEN))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    let instruction_cost_units = &receipt.execution.instruction_cost_units;
+    assert_eq!(
+        instruction_cost_units
+            .iter()
+            .map(|(index, _)| *index)
+            .collect::<Vec<usize>>(),
+        vec![0, 1]
+    );
+    assert!(instruction_cost_units.iter().all(|(_, cost)| *cost > 0));
+    let instructions_total: u32 = instruction_cost_units.iter().map(|(_, cost)| cost).sum();
+    assert!(instructions_total < receipt.execution.fee_summary.cost_unit_consumed);
+}
+
+#[test]
+fn custom_fee_table_should_change_wasm_execution_cost() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/fee");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_method(SYS_FAUCET_COMPONENT, "free_xrd", args!())
+        .take_from_worktop(RADIX_TOKEN, |builder, bucket_id| {
+            builder.call_function(
+                package_address,
+                "Fee",
+                "new",
+                args!(scrypto::resource::Bucket(bucket_id)),
+            )
+        })
+        .build();
+    let transaction = TestTransaction::new(manifest, 1, vec![]);
+    let mut table = HashMap::new();
+    table.insert(FeeEntry::WasmExecutionPerUnit, 2);
+
+    // Act
+    let standard_receipt = test_runner.execute_transaction(
+        &transaction,
+        &FeeReserveConfig::standard(),
+        &ExecutionConfig::standard(),
+    );
+    let custom_receipt = test_runner.execute_transaction(
+        &transaction,
+        &FeeReserveConfig::standard(),
+        &ExecutionConfig::standard().with_fee_table(FeeTable::custom(table)),
+    );
+
+    // Assert
+    standard_receipt.expect_commit_success();
+    custom_receipt.expect_commit_success();
+    let standard = &standard_receipt.execution.fee_summary;
+    let custom = &custom_receipt.execution.fee_summary;
+    let standard_wasm_cost = standard.cost_breakdown["run_wasm"];
+    assert!(standard_wasm_cost > 0);
+    assert_eq!(custom.cost_breakdown["run_wasm"], standard_wasm_cost * 2);
+    assert_eq!(
+        custom.cost_unit_consumed,
+        standard.cost_unit_consumed + standard_wasm_cost
+    );
+}
+
+#[test]
+fn unused_locked_fee_should_be_refunded() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_account();
+    let account_balance = query_account_balance(&mut test_runner, account, RADIX_TOKEN);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(100.into(), account)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![public_key.into()]);
+
+    // Assert
+    receipt.expect_commit_success();
+    let summary = &receipt.execution.fee_summary;
+    let fee_charged = summary.burned + summary.tipped;
+    assert!(fee_charged < 100.into());
+    let account_new_balance = query_account_balance(&mut test_runner, account, RADIX_TOKEN);
+    assert_eq!(account_new_balance, account_balance - fee_charged);
+}
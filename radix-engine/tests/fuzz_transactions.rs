@@ -1,6 +1,8 @@
 use radix_engine::constants::{
-    DEFAULT_COST_UNIT_LIMIT, DEFAULT_COST_UNIT_PRICE, DEFAULT_MAX_CALL_DEPTH, DEFAULT_SYSTEM_LOAN,
+    DEFAULT_COST_UNIT_LIMIT, DEFAULT_COST_UNIT_PRICE, DEFAULT_MAX_CALL_DEPTH,
+    DEFAULT_MAX_KV_VALUE_BYTES, DEFAULT_SYSTEM_LOAN,
 };
+use radix_engine::fee::FeeTable;
 use radix_engine::ledger::TypedInMemorySubstateStore;
 use radix_engine::state_manager::StagedSubstateStoreManager;
 use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig, TransactionExecutor};
@@ -24,6 +26,7 @@ fn execute_single_transaction(transaction: NotarizedTransaction) {
         current_epoch: 1,
         max_cost_unit_limit: DEFAULT_COST_UNIT_LIMIT,
         min_tip_percentage: 0,
+        min_cost_unit_price: Decimal::zero(),
     });
 
     let transaction = validator
@@ -35,10 +38,15 @@ fn execute_single_transaction(transaction: NotarizedTransaction) {
     let mut wasm_instrumenter = WasmInstrumenter::new();
     let execution_config = ExecutionConfig {
         max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+        max_kv_value_bytes: DEFAULT_MAX_KV_VALUE_BYTES,
         trace: false,
+        profile_wasm_memory: false,
+        trace_auth_zones: false,
+        client_context: None,
+        on_commit: None,
+        fee_table: FeeTable::new(),
     };
     let fee_reserve_config = FeeReserveConfig {
-        cost_unit_price: DEFAULT_COST_UNIT_PRICE.parse().unwrap(),
         system_loan: DEFAULT_SYSTEM_LOAN,
     };
 
@@ -114,6 +122,8 @@ impl TransactionFuzzer {
             notary_as_signatory: false,
             cost_unit_limit: 10_000_000,
             tip_percentage: 0,
+            cost_unit_price: DEFAULT_COST_UNIT_PRICE.parse().unwrap(),
+            message: None,
         };
 
         TransactionBuilder::new()
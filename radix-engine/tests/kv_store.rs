@@ -1,8 +1,10 @@
 use radix_engine::engine::{KernelError, RuntimeError};
 use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig, TransactionReceipt};
 use radix_engine::types::*;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
+use transaction::model::TestTransaction;
 
 #[test]
 fn can_insert_in_child_nodes() {
@@ -381,3 +383,192 @@ fn multiple_reads_should_work() {
     // Assert
     receipt.expect_commit_success();
 }
+
+#[test]
+fn entries_in_range_should_return_inclusive_sub_range() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/kv_store");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(package_address, "RangeReads", "new", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component_address = receipt
+        .expect_commit()
+        .entity_changes
+        .new_component_addresses[0];
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_method(
+            component_address,
+            "read_range",
+            args!("k2".to_owned(), "k4".to_owned(), 10u32),
+        )
+        .call_method(
+            component_address,
+            "insert_and_read_range",
+            args!(
+                "k0".to_owned(),
+                0u32,
+                "k0".to_owned(),
+                "k2".to_owned(),
+                10u32
+            ),
+        )
+        .call_method(
+            component_address,
+            "read_range",
+            args!("k0".to_owned(), "k5".to_owned(), 2u32),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let outputs = receipt.expect_commit_success();
+    let entries: Vec<(String, u32)> = scrypto_decode(&outputs[1]).unwrap();
+    assert_eq!(
+        entries,
+        vec![
+            ("k2".to_owned(), 2),
+            ("k3".to_owned(), 3),
+            ("k4".to_owned(), 4)
+        ]
+    );
+    let entries: Vec<(String, u32)> = scrypto_decode(&outputs[2]).unwrap();
+    assert_eq!(
+        entries,
+        vec![
+            ("k0".to_owned(), 0),
+            ("k1".to_owned(), 1),
+            ("k2".to_owned(), 2)
+        ]
+    );
+    let entries: Vec<(String, u32)> = scrypto_decode(&outputs[3]).unwrap();
+    assert_eq!(entries, vec![("k0".to_owned(), 0), ("k1".to_owned(), 1)]);
+}
+
+#[test]
+fn update_should_apply_closure_to_existing_entry_only() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/kv_store");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(package_address, "Counters", "new", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component_address = receipt
+        .expect_commit()
+        .entity_changes
+        .new_component_addresses[0];
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_method(component_address, "increment", args!("apples".to_owned()))
+        .call_method(component_address, "increment", args!("pears".to_owned()))
+        .call_method(component_address, "get_count", args!("apples".to_owned()))
+        .call_method(component_address, "get_count", args!("pears".to_owned()))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let outputs = receipt.expect_commit_success();
+    assert!(scrypto_decode::<bool>(&outputs[1]).unwrap());
+    assert!(!scrypto_decode::<bool>(&outputs[2]).unwrap());
+    assert_eq!(scrypto_decode::<Option<u32>>(&outputs[3]).unwrap(), Some(1));
+    assert_eq!(scrypto_decode::<Option<u32>>(&outputs[4]).unwrap(), None);
+}
+
+#[test]
+fn len_should_count_distinct_keys() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/kv_store");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(package_address, "Counters", "new", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component_address = receipt
+        .expect_commit()
+        .entity_changes
+        .new_component_addresses[0];
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_method(
+            component_address,
+            "set_count",
+            args!("pears".to_owned(), 1u32),
+        )
+        .call_method(
+            component_address,
+            "set_count",
+            args!("plums".to_owned(), 2u32),
+        )
+        .call_method(
+            component_address,
+            "set_count",
+            args!("apples".to_owned(), 3u32),
+        )
+        .call_method(component_address, "number_of_counts", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let outputs = receipt.expect_commit_success();
+    assert_eq!(scrypto_decode::<u64>(&outputs[4]).unwrap(), 3);
+}
+
+fn insert_value_with_kv_limit(size: usize, max_kv_value_bytes: usize) -> TransactionReceipt {
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/kv_store");
+
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(
+            package_address,
+            "LargeValueTest",
+            "new_with_value",
+            args!(size),
+        )
+        .build();
+    let transaction = TestTransaction::new(manifest, test_runner.next_transaction_nonce(), vec![]);
+    test_runner.execute_transaction(
+        &transaction,
+        &FeeReserveConfig::standard(),
+        &ExecutionConfig {
+            max_kv_value_bytes,
+            ..ExecutionConfig::standard()
+        },
+    )
+}
+
+#[test]
+fn kv_value_under_size_limit_should_succeed() {
+    // Arrange, Act
+    let receipt = insert_value_with_kv_limit(1000, 1024);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn kv_value_over_size_limit_should_fail() {
+    // Arrange, Act
+    let receipt = insert_value_with_kv_limit(1024, 1024);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(e, RuntimeError::KvValueTooLarge { max_size: 1024, .. })
+    });
+}
@@ -0,0 +1,32 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct Counters {
+        counts: KeyValueStore<String, u32>,
+    }
+
+    impl Counters {
+        pub fn new() -> ComponentAddress {
+            let counts = KeyValueStore::new();
+            counts.insert("apples".to_owned(), 0);
+            assert_eq!(counts.len(), 1);
+            Counters { counts }.instantiate().globalize()
+        }
+
+        pub fn increment(&mut self, key: String) -> bool {
+            self.counts.update(&key, |count| *count += 1)
+        }
+
+        pub fn get_count(&self, key: String) -> Option<u32> {
+            self.counts.get(&key).map(|count| *count)
+        }
+
+        pub fn set_count(&mut self, key: String, count: u32) {
+            self.counts.insert(key, count);
+        }
+
+        pub fn number_of_counts(&self) -> u64 {
+            self.counts.len()
+        }
+    }
+}
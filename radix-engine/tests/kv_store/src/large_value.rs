@@ -0,0 +1,15 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct LargeValueTest {
+        map: KeyValueStore<u32, Vec<u8>>,
+    }
+
+    impl LargeValueTest {
+        pub fn new_with_value(size: usize) -> ComponentAddress {
+            let map = KeyValueStore::new();
+            map.insert(0u32, vec![0u8; size]);
+            LargeValueTest { map }.instantiate().globalize()
+        }
+    }
+}
@@ -1,6 +1,9 @@
+pub mod counters;
 pub mod cyclic_map;
 pub mod kv_store;
+pub mod large_value;
 pub mod multiple_reads;
 pub mod precommitted;
+pub mod range_reads;
 pub mod ref_check;
 pub mod super_key_value_store;
@@ -0,0 +1,37 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct RangeReads {
+        map: KeyValueStore<String, u32>,
+    }
+
+    impl RangeReads {
+        pub fn new() -> ComponentAddress {
+            let map = KeyValueStore::new();
+            for i in [5u32, 3, 1, 4, 2] {
+                map.insert(format!("k{}", i), i);
+            }
+            assert_eq!(
+                map.entries_in_range(&"k2".to_owned(), &"k3".to_owned(), 10),
+                vec![("k2".to_owned(), 2), ("k3".to_owned(), 3)]
+            );
+            RangeReads { map }.instantiate().globalize()
+        }
+
+        pub fn read_range(&self, start: String, end: String, limit: u32) -> Vec<(String, u32)> {
+            self.map.entries_in_range(&start, &end, limit)
+        }
+
+        pub fn insert_and_read_range(
+            &mut self,
+            key: String,
+            value: u32,
+            start: String,
+            end: String,
+            limit: u32,
+        ) -> Vec<(String, u32)> {
+            self.map.insert(key, value);
+            self.map.entries_in_range(&start, &end, limit)
+        }
+    }
+}
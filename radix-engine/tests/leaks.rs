@@ -22,7 +22,7 @@ fn dangling_component_should_fail() {
     receipt.expect_specific_failure(|e| {
         matches!(
             e,
-            RuntimeError::KernelError(KernelError::DropFailure(DropFailure::Component))
+            RuntimeError::KernelError(KernelError::DropFailure(DropFailure::Component(..)))
         )
     });
 }
@@ -45,7 +45,37 @@ fn dangling_bucket_should_fail() {
     receipt.expect_specific_failure(|e| {
         matches!(
             e,
-            RuntimeError::KernelError(KernelError::DropFailure(DropFailure::Bucket))
+            RuntimeError::KernelError(KernelError::DropFailure(DropFailure::Bucket(..)))
+        )
+    });
+}
+
+#[test]
+fn dangling_bucket_failure_should_name_the_bucket() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/leaks");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(
+            package_address,
+            "Leaks",
+            "dangling_bucket_with_logged_id",
+            args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let bucket_id: BucketId = receipt.execution.application_logs[0].1.parse().unwrap();
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::KernelError(KernelError::DropFailure(DropFailure::Bucket(id)))
+                if *id == bucket_id
         )
     });
 }
@@ -68,7 +98,7 @@ fn dangling_vault_should_fail() {
     receipt.expect_specific_failure(|e| {
         matches!(
             e,
-            RuntimeError::KernelError(KernelError::DropFailure(DropFailure::Vault))
+            RuntimeError::KernelError(KernelError::DropFailure(DropFailure::Vault(..)))
         )
     });
 }
@@ -114,7 +144,7 @@ fn dangling_kv_store_should_fail() {
     receipt.expect_specific_failure(|e| {
         matches!(
             e,
-            RuntimeError::KernelError(KernelError::DropFailure(DropFailure::KeyValueStore))
+            RuntimeError::KernelError(KernelError::DropFailure(DropFailure::KeyValueStore(..)))
         )
     });
 }
@@ -142,7 +172,7 @@ fn dangling_bucket_with_proof_should_fail() {
     receipt.expect_specific_failure(|e| {
         matches!(
             e,
-            RuntimeError::KernelError(KernelError::DropFailure(DropFailure::Bucket))
+            RuntimeError::KernelError(KernelError::DropFailure(DropFailure::Bucket(..)))
         )
     });
 }
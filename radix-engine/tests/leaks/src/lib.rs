@@ -15,6 +15,14 @@ blueprint! {
                 .initial_supply(1);
         }
 
+        pub fn dangling_bucket_with_logged_id() {
+            let bucket = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_MAXIMUM)
+                .metadata("name", "TestToken")
+                .initial_supply(1);
+            info!("{}", bucket.0);
+        }
+
         pub fn dangling_vault() {
             let bucket = ResourceBuilder::new_fungible()
                 .divisibility(DIVISIBILITY_MAXIMUM)
@@ -167,6 +167,32 @@ fn test_basic_transfer() {
     );
 }
 
+#[test]
+fn test_basic_transfer_cost_is_deterministic_and_within_budget() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key1, _, account1) = test_runner.new_account();
+    let (_, _, account2) = test_runner.new_account();
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), account1)
+        .withdraw_from_account_by_amount(100.into(), RADIX_TOKEN, account1)
+        .call_method(
+            account2,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+
+    // Act
+    let cost1 = test_runner.measure_cost(manifest.clone(), vec![public_key1.into()]);
+    let cost2 = test_runner.measure_cost(manifest, vec![public_key1.into()]);
+
+    // Assert
+    assert_eq!(cost1, cost2);
+    assert!(cost1 < 400_000, "Transfer cost {} exceeds budget", cost1);
+}
+
 #[test]
 fn test_publish_large_package() {
     // Arrange
@@ -1,4 +1,8 @@
-use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::engine::{ApplicationError, RuntimeError, Substate};
+use radix_engine::ledger::{
+    non_fungible_prefix, QueryableSubstateStore, SubstatePrefix, TypedInMemorySubstateStore,
+};
+use radix_engine::model::ResourceManagerError;
 use radix_engine::types::*;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
@@ -245,3 +249,157 @@ fn test_mint_update_and_withdraw() {
     let receipt = test_runner.execute_manifest(manifest, vec![public_key.into()]);
     receipt.expect_commit_success();
 }
+
+#[test]
+fn scan_prefix_should_return_non_fungibles_of_resource() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_account();
+    let package_address = test_runner.compile_and_publish("./tests/non_fungible");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(
+            package_address,
+            "NonFungibleTest",
+            "create_non_fungible_fixed",
+            args!(),
+        )
+        .call_function(
+            package_address,
+            "NonFungibleTest",
+            "create_non_fungible_fixed",
+            args!(),
+        )
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![public_key.into()]);
+    let resource_address = receipt
+        .expect_commit()
+        .entity_changes
+        .new_resource_addresses[0];
+
+    // Act
+    let substates = store.scan_prefix(&non_fungible_prefix(resource_address));
+
+    // Assert
+    let ids: Vec<SubstateId> = substates.into_iter().map(|(id, _)| id).collect();
+    assert_eq!(
+        ids,
+        vec![
+            SubstateId::NonFungible(resource_address, NonFungibleId::from_u32(1)),
+            SubstateId::NonFungible(resource_address, NonFungibleId::from_u32(2)),
+            SubstateId::NonFungible(resource_address, NonFungibleId::from_u32(3)),
+        ]
+    );
+}
+
+#[test]
+fn list_substates_should_return_non_fungibles_of_resource() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_account();
+    let package_address = test_runner.compile_and_publish("./tests/non_fungible");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(
+            package_address,
+            "NonFungibleTest",
+            "create_non_fungible_fixed",
+            args!(),
+        )
+        .call_function(
+            package_address,
+            "NonFungibleTest",
+            "create_non_fungible_fixed",
+            args!(),
+        )
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![public_key.into()]);
+    let resource_address = receipt
+        .expect_commit()
+        .entity_changes
+        .new_resource_addresses[0];
+
+    // Act
+    let non_fungibles = store.list_substates(SubstatePrefix::NonFungibles(resource_address));
+    let vaults = store.list_substates(SubstatePrefix::Vaults);
+
+    // Assert
+    assert_eq!(non_fungibles.len(), 3);
+    assert!(non_fungibles.iter().all(|(id, substate)| matches!(
+        (id, substate),
+        (SubstateId::NonFungible(address, _), Substate::NonFungible(_)) if *address == resource_address
+    )));
+    assert!(!vaults.is_empty());
+    assert!(vaults
+        .iter()
+        .all(|(id, _)| matches!(id, SubstateId::Vault(_))));
+}
+
+#[test]
+fn test_mint_non_fungible_matching_data_schema() {
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_account();
+    let package_address = test_runner.compile_and_publish("./tests/non_fungible");
+
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(
+            package_address,
+            "NonFungibleTest",
+            "mint_with_data_schema",
+            args!(true),
+        )
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![public_key.into()]);
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_mint_non_fungible_mismatching_data_schema_fails() {
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_account();
+    let package_address = test_runner.compile_and_publish("./tests/non_fungible");
+
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(
+            package_address,
+            "NonFungibleTest",
+            "mint_with_data_schema",
+            args!(false),
+        )
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![public_key.into()]);
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(
+                ResourceManagerError::NonFungibleDataSchemaMismatch
+            ))
+        )
+    });
+}
@@ -7,12 +7,47 @@ pub struct Sandwich {
     pub available: bool,
 }
 
+#[derive(NonFungibleData)]
+pub struct Drink {
+    pub volume: u32,
+    #[scrypto(mutable)]
+    pub sold: bool,
+}
+
 blueprint! {
     struct NonFungibleTest {
         vault: Vault,
     }
 
     impl NonFungibleTest {
+        pub fn mint_with_data_schema(mint_sandwich: bool) -> Bucket {
+            // Create non-fungible resource which only accepts sandwiches
+            let resource_address = ResourceBuilder::new_non_fungible()
+                .metadata("name", "Katz's Sandwiches")
+                .data_schema::<Sandwich>()
+                .mintable(rule!(allow_all), LOCKED)
+                .no_initial_supply();
+
+            let resource_manager = borrow_resource_manager!(resource_address);
+            if mint_sandwich {
+                resource_manager.mint_non_fungible(
+                    &NonFungibleId::from_u32(0),
+                    Sandwich {
+                        name: "Test".to_owned(),
+                        available: false,
+                    },
+                )
+            } else {
+                resource_manager.mint_non_fungible(
+                    &NonFungibleId::from_u32(0),
+                    Drink {
+                        volume: 330,
+                        sold: false,
+                    },
+                )
+            }
+        }
+
         pub fn create_non_fungible_mutable() -> (Bucket, ResourceAddress, Bucket) {
             // Create a mint badge
             let mint_badge = ResourceBuilder::new_fungible()
@@ -1,6 +1,6 @@
 use radix_engine::engine::{ApplicationError, KernelError, RuntimeError};
 use radix_engine::ledger::TypedInMemorySubstateStore;
-use radix_engine::model::PackageError;
+use radix_engine::model::{extract_abi, ExtractAbiError, PackageError};
 use radix_engine::types::*;
 use radix_engine::wasm::*;
 use sbor::Type;
@@ -160,7 +160,11 @@ fn test_basic_package_missing_export() {
                 input: Type::Unit,
                 output: Type::Unit,
                 export_name: "f".to_string(),
+                input_defaults: vec![],
+                auth: None,
             }],
+            events: vec![],
+            errors: vec![],
         },
     );
 
@@ -182,3 +186,15 @@ fn test_basic_package_missing_export() {
         )
     });
 }
+
+#[test]
+fn malformed_blueprint_abi_should_fail_abi_extraction() {
+    // Arrange
+    let code = wat2wasm(include_str!("wasm/invalid_abi.wat"));
+
+    // Act
+    let result = extract_abi(&code);
+
+    // Assert
+    assert!(matches!(result, Err(ExtractAbiError::AbiDecodeError(_))));
+}
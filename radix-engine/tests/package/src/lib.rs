@@ -39,7 +39,11 @@ pub extern "C" fn LargeReturnSize_abi(_input: *mut u8) -> *mut u8 {
             },
             output: Type::Unit,
             export_name: "LargeReturnSize_f_main".to_string(),
+            input_defaults: vec![],
+            auth: None,
         }],
+        events: vec![],
+        errors: vec![],
     };
     ::scrypto::buffer::scrypto_encode_to_buffer(&abi)
 }
@@ -61,7 +65,11 @@ pub extern "C" fn MaxReturnSize_abi(_input: *mut u8) -> *mut u8 {
             },
             output: Type::Unit,
             export_name: "MaxReturnSize_f_main".to_string(),
+            input_defaults: vec![],
+            auth: None,
         }],
+        events: vec![],
+        errors: vec![],
     };
 
     ::scrypto::buffer::scrypto_encode_to_buffer(&abi)
@@ -84,7 +92,11 @@ pub extern "C" fn ZeroReturnSize_abi(_input: *mut u8) -> *mut u8 {
             },
             output: Type::Unit,
             export_name: "ZeroReturnSize_f_main".to_string(),
+            input_defaults: vec![],
+            auth: None,
         }],
+        events: vec![],
+        errors: vec![],
     };
 
     ::scrypto::buffer::scrypto_encode_to_buffer(&abi)
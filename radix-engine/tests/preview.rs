@@ -1,3 +1,4 @@
+use radix_engine::constants::DEFAULT_COST_UNIT_PRICE;
 use radix_engine::ledger::TypedInMemorySubstateStore;
 use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig};
 use radix_engine::types::*;
@@ -55,6 +56,8 @@ fn prepare_test_tx_and_preview_intent(
             notary_as_signatory: false,
             cost_unit_limit: 10_000_000,
             tip_percentage: 0,
+            cost_unit_price: DEFAULT_COST_UNIT_PRICE.parse().unwrap(),
+            message: None,
         })
         .manifest(
             ManifestBuilder::new(&NetworkDefinition::simulator())
@@ -71,6 +74,7 @@ fn prepare_test_tx_and_preview_intent(
         current_epoch: 1,
         max_cost_unit_limit: 10_000_000,
         min_tip_percentage: 0,
+        min_cost_unit_price: Decimal::zero(),
     });
 
     let validated_transaction = validator
@@ -317,6 +317,29 @@ fn cant_move_locked_bucket() {
     });
 }
 
+#[test]
+fn proof_left_behind_with_its_bucket_should_be_reported_as_dangling() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_account();
+    let resource_address =
+        test_runner.create_fungible_resource(100.into(), DIVISIBILITY_MAXIMUM, account);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .withdraw_from_account(resource_address, account)
+        .take_from_worktop(resource_address, |builder, bucket_id| {
+            builder.create_proof_from_bucket(bucket_id, |builder, _| builder)
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![public_key.into()]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| matches!(e, RuntimeError::DanglingProof { .. }));
+}
+
 #[test]
 fn can_compose_bucket_and_vault_proof() {
     // Arrange
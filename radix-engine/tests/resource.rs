@@ -1,5 +1,7 @@
-use radix_engine::engine::{ApplicationError, RuntimeError};
-use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::engine::{
+    ApplicationError, KernelError, REActor, ResourceEventKind, RuntimeError,
+};
+use radix_engine::ledger::{QueryableSubstateStore, TypedInMemorySubstateStore};
 use radix_engine::model::ResourceManagerError;
 use radix_engine::types::*;
 use scrypto_unit::*;
@@ -61,6 +63,93 @@ fn test_resource_manager() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn resources_created_by_package_should_be_listed() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_account();
+    let package_address = test_runner.compile_and_publish("./tests/resource");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(package_address, "ResourceTest", "create_fungible", args!())
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![public_key.into()]);
+    let mut expected = receipt
+        .expect_commit()
+        .entity_changes
+        .new_resource_addresses
+        .clone();
+
+    // Act
+    let mut resources = store.resources_created_by(package_address);
+
+    // Assert
+    expected.sort();
+    resources.sort();
+    assert_eq!(expected.len(), 2);
+    assert_eq!(resources, expected);
+    assert!(store.resources_created_by(SYS_FAUCET_PACKAGE).is_empty());
+}
+
+#[test]
+fn mint_and_burn_should_emit_resource_events() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_account();
+    let package_address = test_runner.compile_and_publish("./tests/resource");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(package_address, "ResourceTest", "burn", args!())
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![public_key.into()]);
+
+    // Assert
+    receipt.expect_commit_success();
+    let new_resources = &receipt
+        .expect_commit()
+        .entity_changes
+        .new_resource_addresses;
+    let (badge, token) = (new_resources[0], new_resources[1]);
+    let events = &receipt.expect_commit().resource_events;
+    let summary: Vec<(ResourceEventKind, ResourceAddress, Decimal)> = events
+        .iter()
+        .map(|e| (e.kind, e.resource_address, e.amount))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (ResourceEventKind::Minted, badge, Decimal::one()),
+            (ResourceEventKind::Minted, token, Decimal::one()),
+            (ResourceEventKind::Burned, token, Decimal::one()),
+        ]
+    );
+    let blueprint_actor = REActor {
+        fn_identifier: FnIdentifier::Scrypto {
+            package_address,
+            blueprint_name: "ResourceTest".to_owned(),
+            ident: "burn".to_owned(),
+        },
+        receiver: None,
+    };
+    assert!(events
+        .iter()
+        .all(|e| e.actor.as_ref() == Some(&blueprint_actor)));
+}
+
 #[test]
 fn mint_with_bad_granularity_should_fail() {
     // Arrange
@@ -99,6 +188,134 @@ fn mint_with_bad_granularity_should_fail() {
     });
 }
 
+#[test]
+fn mint_up_to_supply_cap_should_succeed() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_account();
+    let package_address = test_runner.compile_and_publish("./tests/resource");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(
+            package_address,
+            "ResourceTest",
+            "create_capped_fungible_and_mint",
+            args!(dec!("100"), dec!("60"), dec!("40")),
+        )
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![public_key.into()]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn mint_beyond_supply_cap_should_fail() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_account();
+    let package_address = test_runner.compile_and_publish("./tests/resource");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(
+            package_address,
+            "ResourceTest",
+            "create_capped_fungible_and_mint",
+            args!(dec!("100"), dec!("60"), dec!("41")),
+        )
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![public_key.into()]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(
+                ResourceManagerError::SupplyCapExceeded
+            ))
+        )
+    })
+}
+
+#[test]
+fn create_with_address_seed_should_use_predicted_address() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/resource");
+    let address_seed = hash("token");
+    let predicted = ResourceAddress::from_address_seed(package_address, address_seed);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(
+            package_address,
+            "ResourceTest",
+            "create_with_address_seed",
+            args!(address_seed),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let (predicted_in_blueprint, resource_address): (ResourceAddress, ResourceAddress) =
+        receipt.output(1);
+    assert_eq!(predicted_in_blueprint, predicted);
+    assert_eq!(resource_address, predicted);
+    assert_eq!(receipt.new_resource_addresses(), &vec![predicted]);
+}
+
+#[test]
+fn create_with_same_address_seed_twice_should_fail() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/resource");
+    let address_seed = hash("token");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(
+            package_address,
+            "ResourceTest",
+            "create_with_address_seed",
+            args!(address_seed),
+        )
+        .build();
+    test_runner
+        .execute_manifest(manifest.clone(), vec![])
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::KernelError(KernelError::RENodeAlreadyExists(RENodeId::ResourceManager(
+                address
+            ))) if *address == ResourceAddress::from_address_seed(package_address, address_seed)
+        )
+    });
+}
+
 #[test]
 fn mint_too_much_should_fail() {
     // Arrange
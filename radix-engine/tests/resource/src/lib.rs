@@ -45,6 +45,42 @@ blueprint! {
             (badge, tokens, token_address)
         }
 
+        pub fn create_capped_fungible_and_mint(
+            supply_cap: Decimal,
+            initial_supply: Decimal,
+            amount: Decimal,
+        ) -> (Bucket, Bucket, Bucket) {
+            let badge = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_NONE)
+                .initial_supply(1);
+            let initial_tokens = ResourceBuilder::new_fungible()
+                .metadata("name", "TestToken")
+                .mintable(rule!(require(badge.resource_address())), LOCKED)
+                .supply_cap(supply_cap)
+                .initial_supply(initial_supply);
+            let tokens = badge.authorize(|| {
+                borrow_resource_manager!(initial_tokens.resource_address()).mint(amount)
+            });
+            (badge, initial_tokens, tokens)
+        }
+
+        pub fn create_with_address_seed(address_seed: Hash) -> (ResourceAddress, ResourceAddress) {
+            let predicted =
+                ResourceAddress::from_address_seed(Runtime::package_address(), address_seed);
+            let (resource_address, _) = ResourceManager::create_with_address_seed(
+                address_seed,
+                ResourceType::Fungible {
+                    divisibility: DIVISIBILITY_MAXIMUM,
+                },
+                HashMap::new(),
+                HashMap::new(),
+                None,
+                None,
+                None,
+            );
+            (predicted, resource_address)
+        }
+
         pub fn create_fungible_wrong_resource_flags_should_fail() -> ResourceAddress {
             let token_address = ResourceBuilder::new_fungible()
                 .divisibility(DIVISIBILITY_MAXIMUM)
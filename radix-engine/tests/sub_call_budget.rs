@@ -0,0 +1,69 @@
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+fn instantiate(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    package_address: PackageAddress,
+) -> ComponentAddress {
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(package_address, "SubCallBudget", "new", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt
+        .expect_commit()
+        .entity_changes
+        .new_component_addresses[0]
+}
+
+fn try_spin(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    caller: ComponentAddress,
+    worker: ComponentAddress,
+    max_cost_units: u32,
+) -> (bool, u32) {
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_method(caller, "try_spin", args!(worker, 100u32, max_cost_units))
+        .call_method(worker, "counter", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success();
+    (receipt.output(1), receipt.output(2))
+}
+
+#[test]
+fn sub_call_exceeding_budget_should_be_reverted_without_failing_transaction() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/sub_call_budget");
+    let caller = instantiate(&mut test_runner, package_address);
+    let worker = instantiate(&mut test_runner, package_address);
+
+    // Act
+    let (succeeded, counter) = try_spin(&mut test_runner, caller, worker, 10_000);
+
+    // Assert
+    assert!(!succeeded);
+    assert_eq!(counter, 0);
+}
+
+#[test]
+fn sub_call_within_budget_should_succeed() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/sub_call_budget");
+    let caller = instantiate(&mut test_runner, package_address);
+    let worker = instantiate(&mut test_runner, package_address);
+
+    // Act
+    let (succeeded, counter) = try_spin(&mut test_runner, caller, worker, 10_000_000);
+
+    // Assert
+    assert!(succeeded);
+    assert_eq!(counter, 1);
+}
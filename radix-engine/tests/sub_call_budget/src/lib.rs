@@ -0,0 +1,34 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct SubCallBudget {
+        counter: u32,
+    }
+
+    impl SubCallBudget {
+        pub fn new() -> ComponentAddress {
+            Self { counter: 0 }.instantiate().globalize()
+        }
+
+        pub fn spin(&mut self, iterations: u32) {
+            self.counter += 1;
+            for i in 0..iterations {
+                info!("Iteration {}", i);
+            }
+        }
+
+        pub fn try_spin(&self, worker: ComponentAddress, iterations: u32, max_cost_units: u32) -> bool {
+            Runtime::call_method_with_budget::<_, ()>(
+                worker,
+                "spin",
+                args!(iterations),
+                max_cost_units,
+            )
+            .is_ok()
+        }
+
+        pub fn counter(&self) -> u32 {
+            self.counter
+        }
+    }
+}
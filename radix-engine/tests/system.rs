@@ -3,6 +3,7 @@ use radix_engine::ledger::TypedInMemorySubstateStore;
 use radix_engine::types::*;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
+use transaction::signing::{EcdsaSecp256k1PrivateKey, EddsaEd25519PrivateKey};
 
 #[test]
 fn test_get_epoch() {
@@ -48,3 +49,114 @@ fn test_set_epoch_without_system_auth_fails() {
         )
     });
 }
+
+#[test]
+fn test_verify_ecdsa_signature() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/system");
+    let private_key = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap();
+    let public_key = private_key.public_key();
+    let message_hash = hash("approved");
+    let signature = private_key.sign(&message_hash.to_vec());
+    let mut tampered_signature = signature.clone();
+    tampered_signature.0[10] ^= 0xff;
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(
+            package_address,
+            "SystemTest",
+            "verify_ecdsa_signature",
+            args!(public_key, message_hash, signature),
+        )
+        .call_function(
+            package_address,
+            "SystemTest",
+            "verify_ecdsa_signature",
+            args!(public_key, message_hash, tampered_signature),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let outputs = receipt.expect_commit_success();
+    let valid: bool = scrypto_decode(&outputs[1]).unwrap();
+    let tampered: bool = scrypto_decode(&outputs[2]).unwrap();
+    assert!(valid);
+    assert!(!tampered);
+}
+
+#[test]
+fn test_verify_ed25519_signature() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/system");
+    let private_key = EddsaEd25519PrivateKey::from_u64(1).unwrap();
+    let public_key = private_key.public_key();
+    let message_hash = hash("approved");
+    let signature = private_key.sign(&message_hash.to_vec());
+    let mut tampered_signature = signature.clone();
+    tampered_signature.0[10] ^= 0xff;
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(
+            package_address,
+            "SystemTest",
+            "verify_ed25519_signature",
+            args!(public_key, message_hash, signature),
+        )
+        .call_function(
+            package_address,
+            "SystemTest",
+            "verify_ed25519_signature",
+            args!(public_key, message_hash, tampered_signature),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let outputs = receipt.expect_commit_success();
+    let valid: bool = scrypto_decode(&outputs[1]).unwrap();
+    let tampered: bool = scrypto_decode(&outputs[2]).unwrap();
+    assert!(valid);
+    assert!(!tampered);
+}
+
+#[test]
+fn test_get_component_methods() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/system");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(
+            package_address,
+            "SystemTest",
+            "get_component_method_idents",
+            args!(SYS_FAUCET_COMPONENT),
+        )
+        .call_function(
+            package_address,
+            "SystemTest",
+            "lock_fee_signature_matches",
+            args!(SYS_FAUCET_COMPONENT),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let outputs = receipt.expect_commit_success();
+    let idents: Vec<String> = scrypto_decode(&outputs[1]).unwrap();
+    assert_eq!(idents, vec!["free_xrd", "lock_fee"]);
+    let lock_fee_signature_matches: bool = scrypto_decode(&outputs[2]).unwrap();
+    assert!(lock_fee_signature_matches);
+}
@@ -1,3 +1,6 @@
+use sbor::describe::Fields;
+use sbor::Type;
+use scrypto::abi::{MethodSignature, SelfMutability};
 use scrypto::engine::{api::*, call_engine, types::*};
 use scrypto::prelude::*;
 
@@ -9,6 +12,10 @@ blueprint! {
             Runtime::current_epoch()
         }
 
+        pub fn get_time() -> u64 {
+            Runtime::current_time()
+        }
+
         pub fn set_epoch(epoch: u64) {
             let input = RadixEngineInput::InvokeMethod(
                 Receiver::Ref(RENodeId::System),
@@ -17,5 +24,47 @@ blueprint! {
             );
             call_engine(input)
         }
+
+        pub fn verify_ecdsa_signature(
+            public_key: EcdsaSecp256k1PublicKey,
+            message_hash: Hash,
+            signature: EcdsaSecp256k1Signature,
+        ) -> bool {
+            Runtime::verify_ecdsa_signature(public_key, message_hash, signature)
+        }
+
+        pub fn verify_ed25519_signature(
+            public_key: EddsaEd25519PublicKey,
+            message_hash: Hash,
+            signature: EddsaEd25519Signature,
+        ) -> bool {
+            Runtime::verify_ed25519_signature(public_key, message_hash, signature)
+        }
+
+        pub fn get_component_method_idents(component_address: ComponentAddress) -> Vec<String> {
+            Runtime::component_methods(component_address)
+                .into_iter()
+                .map(|method| method.ident)
+                .collect()
+        }
+
+        // Method signatures carry recursive `Type`s, which cannot be returned, so they are
+        // compared here.
+        pub fn lock_fee_signature_matches(component_address: ComponentAddress) -> bool {
+            let expected = MethodSignature {
+                ident: "lock_fee".to_owned(),
+                mutability: SelfMutability::Mutable,
+                input: Type::Struct {
+                    name: "SysFaucet_lock_fee_Input".to_owned(),
+                    fields: Fields::Named {
+                        named: vec![("arg0".to_owned(), Decimal::describe())],
+                    },
+                },
+                output: Type::Unit,
+            };
+            Runtime::component_methods(component_address)
+                .into_iter()
+                .any(|method| method == expected)
+        }
     }
 }
@@ -0,0 +1,43 @@
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+#[test]
+fn setting_time_succeeds() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let time = 1_660_000_000u64;
+
+    // Act
+    test_runner.set_time(time);
+
+    // Assert
+    assert_eq!(test_runner.get_current_time(), time);
+}
+
+#[test]
+fn blueprint_reads_time_set_by_test() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/system");
+    let get_time = || {
+        ManifestBuilder::new(&NetworkDefinition::simulator())
+            .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+            .call_function(package_address, "SystemTest", "get_time", args!())
+            .build()
+    };
+
+    for time in [1_660_000_000u64, 1_660_000_000u64, 1_660_003_600u64] {
+        // Act
+        test_runner.set_time(time);
+        let receipt = test_runner.execute_manifest(get_time(), vec![]);
+
+        // Assert
+        let outputs = receipt.expect_commit_success();
+        let current_time: u64 = scrypto_decode(&outputs[1]).unwrap();
+        assert_eq!(current_time, time);
+    }
+}
@@ -1,4 +1,5 @@
-use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::engine::Substate;
+use radix_engine::ledger::{TrackingSubstateStore, TypedInMemorySubstateStore};
 use radix_engine::types::*;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
@@ -88,3 +89,46 @@ fn batched_executions_should_result_in_the_same_result() {
     assert_eq!(store2, store3);
     assert_eq!(store3, store4);
 }
+
+#[test]
+fn tracking_store_should_record_vault_changes_of_transfer() {
+    // Arrange
+    let mut store = TrackingSubstateStore::new(TypedInMemorySubstateStore::with_bootstrap());
+    let (public_key, account1, account2) = {
+        let mut test_runner = TestRunner::new(true, &mut store);
+        let (public_key, _, account1) = test_runner.new_account();
+        let (_, _, account2) = test_runner.new_account();
+        (public_key, account1, account2)
+    };
+    store.drain_changes();
+
+    // Act
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .withdraw_from_account_by_amount(1.into(), RADIX_TOKEN, account1)
+        .call_method(
+            account2,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![public_key.into()]);
+    receipt.expect_commit_success();
+    drop(test_runner);
+
+    // Assert
+    let vault_amounts: Vec<Decimal> = store
+        .drain_changes()
+        .into_iter()
+        .filter_map(
+            |(substate_id, output)| match (substate_id, output.substate) {
+                (SubstateId::Vault(_), Substate::Vault(vault)) => Some(vault.total_amount()),
+                _ => None,
+            },
+        )
+        .collect();
+    assert!(vault_amounts.contains(&Decimal::from(999)));
+    assert!(vault_amounts.contains(&Decimal::from(1001)));
+    assert!(store.drain_changes().is_empty());
+}
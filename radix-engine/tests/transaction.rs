@@ -1,10 +1,11 @@
 use radix_engine::engine::KernelError;
 use radix_engine::engine::RuntimeError;
 use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::transaction::ReadOnlyCall;
 use radix_engine::types::*;
 use scrypto::core::Blob;
 use scrypto_unit::*;
-use transaction::builder::ManifestBuilder;
+use transaction::builder::{split_manifest, ManifestBuilder};
 use transaction::model::Instruction;
 
 #[test]
@@ -147,3 +148,56 @@ fn test_faucet_drain_attempt_should_fail() {
     // Assert
     receipt.expect_commit_failure();
 }
+
+#[test]
+fn test_split_airdrop_manifest_executes_in_sequence() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_account();
+    let resource_address = test_runner.create_fungible_resource(100.into(), 0, account);
+    let recipients: Vec<ComponentAddress> = (0..6).map(|_| test_runner.new_account().2).collect();
+
+    let mut builder = ManifestBuilder::new(&NetworkDefinition::simulator());
+    builder.lock_fee(dec!("10"), account);
+    for recipient in &recipients {
+        builder
+            .withdraw_from_account_by_amount(dec!("2"), resource_address, account)
+            .take_from_worktop_by_amount(dec!("1"), resource_address, |builder, bucket_id| {
+                builder.call_method(
+                    *recipient,
+                    "deposit",
+                    args!(scrypto::resource::Bucket(bucket_id)),
+                )
+            })
+            .call_method(
+                account,
+                "deposit_batch",
+                args!(Expression::entire_worktop()),
+            );
+    }
+    let manifest = builder.build();
+    let max_size = scrypto_encode(&manifest).len() / 2;
+
+    // Act
+    let manifests = split_manifest(manifest, max_size).unwrap();
+
+    // Assert
+    assert!(manifests.len() > 1);
+    for manifest in manifests {
+        assert!(scrypto_encode(&manifest).len() <= max_size);
+        let receipt = test_runner.execute_manifest(manifest, vec![public_key.into()]);
+        receipt.expect_commit_success();
+    }
+    let balances = test_runner
+        .query(
+            recipients
+                .iter()
+                .map(|recipient| ReadOnlyCall::new(*recipient, "balance", args!(resource_address)))
+                .collect(),
+        )
+        .unwrap();
+    for balance in balances {
+        assert_eq!(scrypto_decode::<Decimal>(&balance.raw), Ok(dec!("1")));
+    }
+}
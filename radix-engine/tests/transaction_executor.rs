@@ -1,16 +1,20 @@
 use radix_engine::constants::*;
 use radix_engine::engine::RuntimeError;
+use radix_engine::engine::Substate;
 use radix_engine::engine::{ModuleError, RejectionError};
-use radix_engine::ledger::TypedInMemorySubstateStore;
-use radix_engine::transaction::TransactionExecutor;
+use radix_engine::ledger::{OutputValue, ReadableSubstateStore, TypedInMemorySubstateStore};
+use radix_engine::transaction::{CommitResult, TransactionOutcome, TransactionResult};
 use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig};
+use radix_engine::transaction::{NonDeterminismError, ReadOnlyCall, TransactionExecutor};
 use radix_engine::types::*;
 use radix_engine::wasm::DefaultWasmEngine;
 use radix_engine::wasm::WasmInstrumenter;
 use scrypto_unit::*;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
 use transaction::builder::ManifestBuilder;
 use transaction::builder::TransactionBuilder;
-use transaction::model::{NotarizedTransaction, TransactionHeader, Validated};
+use transaction::model::{NotarizedTransaction, TestTransaction, TransactionHeader, Validated};
 use transaction::signing::EcdsaSecp256k1PrivateKey;
 use transaction::validation::{
     NotarizedTransactionValidator, TestIntentHashManager, TransactionValidator, ValidationConfig,
@@ -21,7 +25,7 @@ fn pre_execution_rejection_should_return_rejected_receipt() {
     // Arrange
     let mut substate_store = TypedInMemorySubstateStore::with_bootstrap();
     let mut test_runner = TestRunner::new(true, &mut substate_store);
-    let executable_transaction = create_executable_transaction(1);
+    let executable_transaction = create_executable_transaction(1, None);
 
     // Act
     let receipt = test_runner.execute_transaction(
@@ -51,13 +55,14 @@ fn test_normal_transaction_flow() {
     let intent_hash_manager = TestIntentHashManager::new();
     let fee_reserve_config = FeeReserveConfig::standard();
     let execution_config = ExecutionConfig::debug();
-    let raw_transaction = create_notarized_transaction(1_000_000).to_bytes();
+    let raw_transaction = create_notarized_transaction(1_000_000, None).to_bytes();
 
     let validator = NotarizedTransactionValidator::new(ValidationConfig {
         network_id: NetworkDefinition::simulator().id,
         current_epoch: 1,
         max_cost_unit_limit: DEFAULT_COST_UNIT_LIMIT,
         min_tip_percentage: 0,
+        min_cost_unit_price: Decimal::zero(),
     });
 
     let validated_transaction: Validated<NotarizedTransaction> = validator
@@ -78,16 +83,310 @@ fn test_normal_transaction_flow() {
 
     // Assert
     receipt.expect_commit_success();
+    assert_eq!(
+        receipt.execution.fee_summary.cost_unit_price,
+        validated_transaction.cost_unit_price
+    );
+}
+
+#[test]
+fn minimum_fee_should_be_the_tight_boundary() {
+    // Arrange
+    let mut substate_store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut substate_store);
+    let (public_key, _, account) = test_runner.new_account();
+    let (_, _, other_account) = test_runner.new_account();
+    let fee_reserve_config = FeeReserveConfig::standard();
+    let transfer = |fee: Decimal, nonce: u64| {
+        let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+            .lock_fee(fee, account)
+            .withdraw_from_account_by_amount(1.into(), RADIX_TOKEN, account)
+            .call_method(
+                other_account,
+                "deposit_batch",
+                args!(Expression::entire_worktop()),
+            )
+            .build();
+        TestTransaction::new(manifest, nonce, vec![public_key.into()])
+    };
+
+    // Act
+    let minimum_fee = test_runner
+        .minimum_fee(
+            &transfer(Decimal::zero(), 1),
+            &fee_reserve_config,
+            &ExecutionConfig::standard(),
+        )
+        .unwrap();
+
+    // Assert
+    let receipt = test_runner.execute_transaction(
+        &transfer(minimum_fee, 2),
+        &fee_reserve_config,
+        &ExecutionConfig::standard(),
+    );
+    receipt.expect_commit_success();
+    let receipt = test_runner.execute_transaction(
+        &transfer(minimum_fee - Decimal::from(DEFAULT_COST_UNIT_PRICE), 3),
+        &fee_reserve_config,
+        &ExecutionConfig::standard(),
+    );
+    assert!(!matches!(
+        receipt.result,
+        TransactionResult::Commit(CommitResult {
+            outcome: TransactionOutcome::Success(..),
+            ..
+        })
+    ));
+}
+
+#[test]
+fn transaction_message_should_be_surfaced_on_receipt() {
+    // Arrange
+    let mut substate_store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut substate_store);
+    let message = b"memo: invoice #42".to_vec();
+    let executable_transaction = create_executable_transaction(1_000_000, Some(message.clone()));
+
+    // Act
+    let receipt = test_runner.execute_transaction(
+        &executable_transaction,
+        &FeeReserveConfig::standard(),
+        &ExecutionConfig::standard(),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(receipt.contents.message, Some(message));
+}
+
+fn create_component_test(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+) -> ComponentAddress {
+    let package_address = test_runner.compile_and_publish("./tests/component");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(
+            package_address,
+            "ComponentTest",
+            "create_component",
+            args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt
+        .expect_commit()
+        .entity_changes
+        .new_component_addresses[0]
+}
+
+#[test]
+fn query_should_return_getter_output_without_transaction() {
+    // Arrange
+    let mut substate_store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut substate_store);
+    let component_address = create_component_test(&mut test_runner);
+
+    // Act
+    let outputs = test_runner
+        .query(vec![ReadOnlyCall::new(
+            component_address,
+            "get_component_state",
+            args!(),
+        )])
+        .unwrap();
+
+    // Assert
+    assert_eq!(outputs.len(), 1);
+    let secret: String = scrypto_decode(&outputs[0].raw).unwrap();
+    assert_eq!(secret, "Secret");
+}
+
+#[test]
+fn query_should_reject_state_change() {
+    // Arrange
+    let mut substate_store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut substate_store);
+    let component_address = create_component_test(&mut test_runner);
+
+    // Act
+    let result = test_runner.query(vec![ReadOnlyCall::new(
+        component_address,
+        "set_component_state",
+        args!("Changed".to_string()),
+    )]);
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(RuntimeError::WriteInQuery(SubstateId::ComponentState(address)))
+            if address == component_address
+    ));
+}
+
+#[test]
+fn executing_on_fork_should_leave_base_store_unchanged() {
+    // Arrange
+    let mut substate_store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut substate_store);
+    let (_, _, account) = test_runner.new_account();
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_method(SYS_FAUCET_COMPONENT, "free_xrd", args!())
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let transaction = TestTransaction::new(manifest, 1, vec![]);
+    let mut wasm_engine = DefaultWasmEngine::new();
+    let mut wasm_instrumenter = WasmInstrumenter::new();
+    let mut executor_store = TypedInMemorySubstateStore::new();
+    let executor = TransactionExecutor::new(
+        &mut executor_store,
+        &mut wasm_engine,
+        &mut wasm_instrumenter,
+    );
+
+    // Act
+    let (receipt, fork) = executor.execute_on_fork(
+        &substate_store,
+        &transaction,
+        &FeeReserveConfig::standard(),
+        &ExecutionConfig::standard(),
+    );
+
+    // Assert
+    let state_updates = &receipt.expect_commit().state_updates;
+    assert!(!state_updates.up_substates.is_empty());
+    for (substate_id, output_value) in &state_updates.up_substates {
+        assert_eq!(fork.get_substate(substate_id).as_ref(), Some(output_value));
+    }
+    drop(fork);
+    for (substate_id, output_value) in &state_updates.up_substates {
+        assert_ne!(
+            substate_store.get_substate(substate_id).as_ref(),
+            Some(output_value)
+        );
+    }
+}
+
+#[test]
+fn commit_callback_should_receive_client_context() {
+    // Arrange
+    let mut substate_store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut substate_store);
+    let (_, _, account) = test_runner.new_account();
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_method(SYS_FAUCET_COMPONENT, "free_xrd", args!())
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let transaction = TestTransaction::new(manifest, 1, vec![]);
+    let committed = Rc::new(RefCell::new(Vec::<(String, usize)>::new()));
+    let committed_by_callback = committed.clone();
+    let execution_config = ExecutionConfig {
+        client_context: Some(Box::new("request-42".to_string())),
+        on_commit: Some(Box::new(move |commit, context| {
+            let request_id = context
+                .and_then(|c| c.downcast_ref::<String>())
+                .expect("Client context should be passed to the callback");
+            committed_by_callback
+                .borrow_mut()
+                .push((request_id.clone(), commit.state_updates.up_substates.len()));
+        })),
+        ..ExecutionConfig::standard()
+    };
+    let mut wasm_engine = DefaultWasmEngine::new();
+    let mut wasm_instrumenter = WasmInstrumenter::new();
+    let mut executor = TransactionExecutor::new(
+        &mut substate_store,
+        &mut wasm_engine,
+        &mut wasm_instrumenter,
+    );
+
+    // Act
+    let receipt = executor.execute_and_commit(
+        &transaction,
+        &FeeReserveConfig::standard(),
+        &execution_config,
+    );
+
+    // Assert
+    let up_substates = receipt.expect_commit().state_updates.up_substates.len();
+    assert_eq!(
+        *committed.borrow(),
+        vec![("request-42".to_string(), up_substates)]
+    );
 }
 
-fn create_executable_transaction(cost_unit_limit: u32) -> Validated<NotarizedTransaction> {
-    let notarized_transaction = create_notarized_transaction(cost_unit_limit);
+#[test]
+fn execute_should_not_commit_state_updates() {
+    // Arrange
+    let mut substate_store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut substate_store);
+    let (public_key, _, account) = test_runner.new_account();
+    let (_, _, other_account) = test_runner.new_account();
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .withdraw_from_account_by_amount(100.into(), RADIX_TOKEN, account)
+        .call_method(
+            other_account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let transaction = TestTransaction::new(manifest, 1, vec![public_key.into()]);
+    let balance_calls = || {
+        vec![
+            ReadOnlyCall::new(account, "balance", args!(RADIX_TOKEN)),
+            ReadOnlyCall::new(other_account, "balance", args!(RADIX_TOKEN)),
+        ]
+    };
+    let mut wasm_engine = DefaultWasmEngine::new();
+    let mut wasm_instrumenter = WasmInstrumenter::new();
+    let mut executor = TransactionExecutor::new(
+        &mut substate_store,
+        &mut wasm_engine,
+        &mut wasm_instrumenter,
+    );
+    let balances_before = executor
+        .query(balance_calls(), &ExecutionConfig::standard())
+        .unwrap();
+
+    // Act
+    let receipt = executor.execute(
+        &transaction,
+        &FeeReserveConfig::standard(),
+        &ExecutionConfig::standard(),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert!(receipt.execution.fee_summary.cost_unit_consumed > 0);
+    let balances_after = executor
+        .query(balance_calls(), &ExecutionConfig::standard())
+        .unwrap();
+    assert_eq!(balances_after, balances_before);
+}
+
+fn create_executable_transaction(
+    cost_unit_limit: u32,
+    message: Option<Vec<u8>>,
+) -> Validated<NotarizedTransaction> {
+    let notarized_transaction = create_notarized_transaction(cost_unit_limit, message);
 
     let validator = NotarizedTransactionValidator::new(ValidationConfig {
         network_id: NetworkDefinition::simulator().id,
         current_epoch: 1,
         max_cost_unit_limit: 10_000_000,
         min_tip_percentage: 0,
+        min_cost_unit_price: Decimal::zero(),
     });
 
     validator
@@ -95,7 +394,10 @@ fn create_executable_transaction(cost_unit_limit: u32) -> Validated<NotarizedTra
         .unwrap()
 }
 
-fn create_notarized_transaction(cost_unit_limit: u32) -> NotarizedTransaction {
+fn create_notarized_transaction(
+    cost_unit_limit: u32,
+    message: Option<Vec<u8>>,
+) -> NotarizedTransaction {
     // create key pairs
     let sk1 = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap();
     let sk2 = EcdsaSecp256k1PrivateKey::from_u64(2).unwrap();
@@ -112,6 +414,8 @@ fn create_notarized_transaction(cost_unit_limit: u32) -> NotarizedTransaction {
             notary_as_signatory: false,
             cost_unit_limit,
             tip_percentage: 5,
+            cost_unit_price: DEFAULT_COST_UNIT_PRICE.parse().unwrap(),
+            message,
         })
         .manifest(
             ManifestBuilder::new(&NetworkDefinition::simulator())
@@ -124,3 +428,99 @@ fn create_notarized_transaction(cost_unit_limit: u32) -> NotarizedTransaction {
         .notarize(&sk_notary)
         .build()
 }
+
+/// A store which reports a different epoch on every read, to simulate a nondeterministic engine.
+struct DriftingEpochStore<'a> {
+    base: &'a TypedInMemorySubstateStore,
+    reads: Cell<u64>,
+}
+
+impl<'a> ReadableSubstateStore for DriftingEpochStore<'a> {
+    fn get_substate(&self, substate_id: &SubstateId) -> Option<OutputValue> {
+        let mut output_value = self.base.get_substate(substate_id)?;
+        if let Substate::System(system) = &mut output_value.substate {
+            self.reads.set(self.reads.get() + 1);
+            system.epoch = self.reads.get();
+        }
+        Some(output_value)
+    }
+
+    fn is_root(&self, substate_id: &SubstateId) -> bool {
+        self.base.is_root(substate_id)
+    }
+
+    fn get_kv_store_entries_in_range(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        start: &[u8],
+        end: &[u8],
+        limit: usize,
+    ) -> Vec<(Vec<u8>, OutputValue)> {
+        self.base
+            .get_kv_store_entries_in_range(kv_store_id, start, end, limit)
+    }
+}
+
+fn get_epoch_transaction(substate_store: &mut TypedInMemorySubstateStore) -> TestTransaction {
+    let mut test_runner = TestRunner::new(true, substate_store);
+    let package_address = test_runner.compile_and_publish("./tests/system");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .call_function(package_address, "SystemTest", "get_epoch", args!())
+        .build();
+    TestTransaction::new(manifest, test_runner.next_transaction_nonce(), vec![])
+}
+
+#[test]
+fn deterministic_check_should_pass_for_deterministic_execution() {
+    // Arrange
+    let mut substate_store = TypedInMemorySubstateStore::with_bootstrap();
+    let transaction = get_epoch_transaction(&mut substate_store);
+    let mut wasm_engine = DefaultWasmEngine::new();
+    let mut wasm_instrumenter = WasmInstrumenter::new();
+
+    // Act
+    let result = TransactionExecutor::new(
+        &mut substate_store,
+        &mut wasm_engine,
+        &mut wasm_instrumenter,
+    )
+    .execute_deterministic_check(
+        &transaction,
+        &FeeReserveConfig::standard(),
+        &ExecutionConfig::standard(),
+    );
+
+    // Assert
+    result
+        .expect("Execution should be deterministic")
+        .expect_commit_success();
+}
+
+#[test]
+fn deterministic_check_should_catch_nondeterministic_execution() {
+    // Arrange
+    let mut substate_store = TypedInMemorySubstateStore::with_bootstrap();
+    let transaction = get_epoch_transaction(&mut substate_store);
+    let mut drifting_store = DriftingEpochStore {
+        base: &substate_store,
+        reads: Cell::new(0),
+    };
+    let mut wasm_engine = DefaultWasmEngine::new();
+    let mut wasm_instrumenter = WasmInstrumenter::new();
+
+    // Act
+    let result = TransactionExecutor::new(
+        &mut drifting_store,
+        &mut wasm_engine,
+        &mut wasm_instrumenter,
+    )
+    .execute_deterministic_check(
+        &transaction,
+        &FeeReserveConfig::standard(),
+        &ExecutionConfig::standard(),
+    );
+
+    // Assert
+    assert_eq!(result.unwrap_err(), NonDeterminismError::Outcome);
+}
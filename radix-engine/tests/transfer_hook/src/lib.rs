@@ -0,0 +1,90 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct TransferHookTest {
+        blocklist: Vec<ComponentAddress>,
+        deposit_fee: Decimal,
+        /// Tokens guarded by this component, which the hook moves on every deposit.
+        reserve: Option<Vault>,
+    }
+
+    impl TransferHookTest {
+        pub fn create_token(
+            blocklist: Vec<ComponentAddress>,
+            deposit_fee: Decimal,
+        ) -> (ComponentAddress, Bucket, Bucket) {
+            let admin_badge = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_NONE)
+                .initial_supply(1);
+            let token = ResourceBuilder::new_fungible()
+                .updateable_transfer_hook(rule!(require(admin_badge.resource_address())), LOCKED)
+                .initial_supply(100);
+            let component = Self {
+                blocklist,
+                deposit_fee,
+                reserve: None,
+            }
+            .instantiate()
+            .globalize();
+
+            admin_badge.authorize(|| {
+                borrow_resource_manager!(token.resource_address())
+                    .set_transfer_hook(Some(TransferHook::new(component, "check_transfer")));
+            });
+
+            (component, token, admin_badge)
+        }
+
+        /// Creates a token whose hook moves another token guarded by the same hook.
+        pub fn create_reentrant_token() -> (ComponentAddress, Bucket, Bucket) {
+            let admin_badge = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_NONE)
+                .initial_supply(1);
+            let token = ResourceBuilder::new_fungible()
+                .updateable_transfer_hook(rule!(require(admin_badge.resource_address())), LOCKED)
+                .initial_supply(100);
+            let reserve = ResourceBuilder::new_fungible()
+                .updateable_transfer_hook(rule!(require(admin_badge.resource_address())), LOCKED)
+                .initial_supply(100);
+            let reserve_address = reserve.resource_address();
+            let component = Self {
+                blocklist: Vec::new(),
+                deposit_fee: Decimal::zero(),
+                reserve: Some(Vault::with_bucket(reserve)),
+            }
+            .instantiate()
+            .globalize();
+
+            admin_badge.authorize(|| {
+                let hook = TransferHook::new(component, "check_transfer");
+                borrow_resource_manager!(token.resource_address())
+                    .set_transfer_hook(Some(hook.clone()));
+                borrow_resource_manager!(reserve_address)
+                    .set_transfer_hook(Some(hook));
+            });
+
+            (component, token, admin_badge)
+        }
+
+        pub fn check_transfer(&mut self, input: TransferHookInput) -> TransferHookOutcome {
+            if input.kind != TransferKind::Deposit {
+                return TransferHookOutcome::Accept;
+            }
+            if let Some(reserve) = &mut self.reserve {
+                let bucket = reserve.take(1);
+                reserve.put(bucket);
+            }
+
+            let blocked = input
+                .component_address
+                .map_or(false, |address| self.blocklist.contains(&address));
+            if blocked {
+                TransferHookOutcome::Reject
+            } else if self.deposit_fee.is_zero() {
+                TransferHookOutcome::Accept
+            } else {
+                TransferHookOutcome::Adjust(input.amount - self.deposit_fee)
+            }
+        }
+    }
+}
@@ -0,0 +1,29 @@
+(module
+
+  ;; ABI export returning `()` rather than a blueprint ABI
+  (func $Test_abi (param $0 i32) (result i32)
+    (local $buffer i32)
+    (local.set 
+      $buffer
+      (call $scrypto_alloc
+        (i32.const 2)
+      )
+    )
+    (i32.add
+      (local.get $buffer)
+      (i32.const 4)
+    )
+    (i32.const 0)
+    (i32.store16)
+    (local.get $buffer)
+  )
+
+  (memory $0 1)
+  (export "memory" (memory $0))
+  (export "scrypto_alloc" (func $scrypto_alloc))
+  (export "scrypto_free" (func $scrypto_free))
+  (export "Test_abi" (func $Test_abi))
+
+  ${memcpy}
+  ${buffer}
+)
\ No newline at end of file
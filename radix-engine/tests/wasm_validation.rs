@@ -1,4 +1,7 @@
-use radix_engine::wasm::{InvalidMemory, PrepareError, WasmValidator};
+use radix_engine::wasm::{
+    InvalidMemory, PrepareError, WasmValidator, DEFAULT_MAX_CODE_SIZE,
+    DEFAULT_MAX_MEMORY_SIZE_PAGES,
+};
 use scrypto_unit::*;
 
 #[test]
@@ -23,3 +26,67 @@ fn test_large_memory() {
         result
     );
 }
+
+#[test]
+fn test_large_max_memory() {
+    let code = wat2wasm(&include_str!("wasm/large_max_memory.wat"));
+    let abi = test_abi_any_in_void_out("Test", "f");
+    let result = WasmValidator::default().validate(&code, &abi);
+
+    assert_eq!(
+        Err(PrepareError::InvalidMemory(InvalidMemory::MemoryTooLarge {
+            declared: 65536,
+            limit: DEFAULT_MAX_MEMORY_SIZE_PAGES
+        })),
+        result
+    );
+}
+
+#[test]
+fn test_max_memory_limit() {
+    let code = wat2wasm(&include_str!("wasm/large_max_memory.wat"));
+    let abi = test_abi_any_in_void_out("Test", "f");
+    let result = WasmValidator {
+        max_memory_size_pages: 65536,
+        ..Default::default()
+    }
+    .validate(&code, &abi);
+
+    assert_eq!(Ok(()), result);
+}
+
+#[test]
+fn test_code_size_limit() {
+    let code = wat2wasm(&include_str!("wasm/basic_package.wat"));
+    let abi = test_abi_any_in_void_out("Test", "f");
+
+    assert_eq!(Ok(()), WasmValidator::default().validate(&code, &abi));
+
+    let result = WasmValidator {
+        max_code_size: code.len() - 1,
+        ..Default::default()
+    }
+    .validate(&code, &abi);
+    assert_eq!(
+        Err(PrepareError::CodeTooLarge {
+            size: code.len(),
+            limit: code.len() - 1
+        }),
+        result
+    );
+}
+
+#[test]
+fn test_oversized_code() {
+    let code = vec![0u8; DEFAULT_MAX_CODE_SIZE + 1];
+    let abi = test_abi_any_in_void_out("Test", "f");
+    let result = WasmValidator::default().validate(&code, &abi);
+
+    assert_eq!(
+        Err(PrepareError::CodeTooLarge {
+            size: DEFAULT_MAX_CODE_SIZE + 1,
+            limit: DEFAULT_MAX_CODE_SIZE
+        }),
+        result
+    );
+}
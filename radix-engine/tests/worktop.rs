@@ -1,7 +1,10 @@
+use radix_engine::engine::ApplicationError;
 use radix_engine::engine::DropFailure;
 use radix_engine::engine::KernelError;
 use radix_engine::engine::RuntimeError;
 use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::model::WorktopError;
+use radix_engine::transaction::{ReadOnlyCall, TransactionReceipt};
 use radix_engine::types::*;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
@@ -28,3 +31,169 @@ fn test_worktop_resource_leak() {
         )
     });
 }
+
+#[test]
+fn assert_worktop_contains_should_pass_when_worktop_is_sufficient() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_account();
+    let non_fungible_resource = test_runner.create_non_fungible_resource(account);
+    let mut ids = BTreeSet::new();
+    ids.insert(NonFungibleId::from_u32(1));
+    ids.insert(NonFungibleId::from_u32(2));
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .withdraw_from_account_by_amount(100.into(), RADIX_TOKEN, account)
+        .withdraw_from_account_by_ids(&ids, non_fungible_resource, account)
+        .assert_worktop_contains(RADIX_TOKEN)
+        .assert_worktop_contains_by_amount(100.into(), RADIX_TOKEN)
+        .assert_worktop_contains_by_ids(&ids, non_fungible_resource)
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![public_key.into()]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+fn assert_worktop_assertion_failed(receipt: &TransactionReceipt) {
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::WorktopError(
+                WorktopError::AssertionFailed
+            ))
+        )
+    });
+}
+
+#[test]
+fn assert_worktop_contains_should_fail_when_resource_is_missing() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_account();
+    let non_fungible_resource = test_runner.create_non_fungible_resource(account);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .withdraw_from_account_by_amount(100.into(), RADIX_TOKEN, account)
+        .assert_worktop_contains(non_fungible_resource)
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![public_key.into()]);
+
+    // Assert
+    assert_worktop_assertion_failed(&receipt);
+}
+
+#[test]
+fn assert_worktop_contains_by_amount_should_fail_on_partial_amount() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_account();
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .withdraw_from_account_by_amount(5.into(), RADIX_TOKEN, account)
+        .assert_worktop_contains_by_amount(10.into(), RADIX_TOKEN)
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![public_key.into()]);
+
+    // Assert
+    assert_worktop_assertion_failed(&receipt);
+}
+
+#[test]
+fn assert_worktop_contains_by_ids_should_fail_on_missing_id() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_account();
+    let non_fungible_resource = test_runner.create_non_fungible_resource(account);
+    let mut withdrawn_ids = BTreeSet::new();
+    withdrawn_ids.insert(NonFungibleId::from_u32(1));
+    let mut asserted_ids = withdrawn_ids.clone();
+    asserted_ids.insert(NonFungibleId::from_u32(3));
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .withdraw_from_account_by_ids(&withdrawn_ids, non_fungible_resource, account)
+        .assert_worktop_contains_by_ids(&asserted_ids, non_fungible_resource)
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![public_key.into()]);
+
+    // Assert
+    assert_worktop_assertion_failed(&receipt);
+}
+
+#[test]
+fn returned_bucket_should_be_available_on_worktop() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_account();
+    let (_, _, other_account) = test_runner.new_account();
+    let resource_address = test_runner.create_fungible_resource(100.into(), 0, account);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+        .withdraw_from_account(resource_address, account)
+        .take_from_worktop_by_amount(100.into(), resource_address, |builder, bucket_id| {
+            builder.return_to_worktop(bucket_id)
+        })
+        .take_from_worktop_by_amount(40.into(), resource_address, |builder, bucket_id| {
+            builder.call_method(
+                other_account,
+                "deposit",
+                args!(scrypto::resource::Bucket(bucket_id)),
+            )
+        })
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![public_key.into()]);
+
+    // Assert
+    receipt.expect_commit_success();
+    let balances = test_runner
+        .query(vec![
+            ReadOnlyCall::new(account, "balance", args!(resource_address)),
+            ReadOnlyCall::new(other_account, "balance", args!(resource_address)),
+        ])
+        .unwrap();
+    let balances: Vec<Decimal> = balances
+        .iter()
+        .map(|value| scrypto_decode(&value.raw).unwrap())
+        .collect();
+    assert_eq!(balances, vec![Decimal::from(60), Decimal::from(40)]);
+}
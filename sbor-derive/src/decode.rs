@@ -1,5 +1,5 @@
 use proc_macro2::{Span, TokenStream};
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::*;
 
 use crate::utils::*;
@@ -14,7 +14,9 @@ macro_rules! trace {
 pub fn handle_decode(input: TokenStream) -> Result<TokenStream> {
     trace!("handle_decode() starts");
 
-    let DeriveInput { ident, data, .. } = parse2(input)?;
+    let DeriveInput {
+        attrs, ident, data, ..
+    } = parse2(input)?;
     trace!("Decoding: {}", ident);
 
     let output = match data {
@@ -36,11 +38,14 @@ pub fn handle_decode(input: TokenStream) -> Result<TokenStream> {
                         }
                         fn decode_value(decoder: &mut ::sbor::Decoder) -> Result<Self, ::sbor::DecodeError> {
                             use ::sbor::{self, Decode};
+                            decoder.enter_nested()?;
                             decoder.check_static_size(#ns_len)?;
-                            Ok(Self {
+                            let value = Self {
                                 #(#ns_ids: <#ns_types>::decode(decoder)?,)*
                                 #(#s_ids: <#s_types>::default()),*
-                            })
+                            };
+                            decoder.exit_nested();
+                            Ok(value)
                         }
                     }
                 }
@@ -64,10 +69,13 @@ pub fn handle_decode(input: TokenStream) -> Result<TokenStream> {
                         }
                         fn decode_value(decoder: &mut ::sbor::Decoder) -> Result<Self, ::sbor::DecodeError> {
                             use ::sbor::{self, Decode};
+                            decoder.enter_nested()?;
                             decoder.check_static_size(#ns_len)?;
-                            Ok(Self (
+                            let value = Self (
                                 #(#fields,)*
-                            ))
+                            );
+                            decoder.exit_nested();
+                            Ok(value)
                         }
                     }
                 }
@@ -80,7 +88,9 @@ pub fn handle_decode(input: TokenStream) -> Result<TokenStream> {
                             decoder.check_type_id(::sbor::type_id::TYPE_STRUCT)
                         }
                         fn decode_value(decoder: &mut ::sbor::Decoder) -> Result<Self, ::sbor::DecodeError> {
+                            decoder.enter_nested()?;
                             decoder.check_static_size(0)?;
+                            decoder.exit_nested();
                             Ok(Self {})
                         }
                     }
@@ -88,7 +98,8 @@ pub fn handle_decode(input: TokenStream) -> Result<TokenStream> {
             }
         },
         Data::Enum(DataEnum { variants, .. }) => {
-            let match_arms = variants.iter().map(|v| {
+            let tagged = is_tagged(&attrs);
+            let match_arms = variants.iter().map(|v| -> Result<TokenStream> {
                 let v_id = &v.ident;
                 let name_string = v_id.to_string();
                 let name: Expr = parse_quote! { #name_string };
@@ -102,7 +113,21 @@ pub fn handle_decode(input: TokenStream) -> Result<TokenStream> {
                         let s: Vec<&Field> = named.iter().filter(|f| is_skipped(f)).collect();
                         let s_ids = s.iter().map(|f| &f.ident);
                         let s_types = s.iter().map(|f| &f.ty);
-                        quote! {
+                        if tagged {
+                            let decode_fields = decode_tagged_fields(&ns)?;
+                            let vars = (0..ns.len()).map(|i| format_ident!("f{}", i));
+                            let tags = get_tags(&ns)?;
+                            return Ok(quote! {
+                                #name => {
+                                    #decode_fields
+                                    Ok(Self::#v_id {
+                                        #(#ns_ids: #vars.ok_or(::sbor::DecodeError::MissingTag(#tags))?,)*
+                                        #(#s_ids: <#s_types>::default(),)*
+                                    })
+                                }
+                            });
+                        }
+                        Ok(quote! {
                             #name => {
                                 decoder.check_static_size(#ns_len)?;
                                 Ok(Self::#v_id {
@@ -110,9 +135,37 @@ pub fn handle_decode(input: TokenStream) -> Result<TokenStream> {
                                     #(#s_ids: <#s_types>::default(),)*
                                 })
                             }
-                        }
+                        })
                     }
                     syn::Fields::Unnamed(FieldsUnnamed { unnamed, .. }) => {
+                        if tagged {
+                            let ns: Vec<&Field> = unnamed.iter().filter(|f| !is_skipped(f)).collect();
+                            let decode_fields = decode_tagged_fields(&ns)?;
+                            let tags = get_tags(&ns)?;
+                            let mut fields = Vec::<Expr>::new();
+                            let mut i = 0;
+                            for f in unnamed {
+                                let ty = &f.ty;
+                                if is_skipped(f) {
+                                    fields.push(parse_quote! {<#ty>::default()})
+                                } else {
+                                    let var = format_ident!("f{}", i);
+                                    let tag = tags[i];
+                                    fields.push(parse_quote! {
+                                        #var.ok_or(::sbor::DecodeError::MissingTag(#tag))?
+                                    });
+                                    i += 1;
+                                }
+                            }
+                            return Ok(quote! {
+                                #name => {
+                                    #decode_fields
+                                    Ok(Self::#v_id (
+                                        #(#fields),*
+                                    ))
+                                }
+                            });
+                        }
                         let mut fields = Vec::<Expr>::new();
                         for f in unnamed {
                             let ty = &f.ty;
@@ -123,25 +176,24 @@ pub fn handle_decode(input: TokenStream) -> Result<TokenStream> {
                             }
                         }
                         let ns_len = Index::from(unnamed.iter().filter(|f| !is_skipped(f)).count());
-                        quote! {
+                        Ok(quote! {
                             #name => {
                                 decoder.check_static_size(#ns_len)?;
                                 Ok(Self::#v_id (
                                     #(#fields),*
                                 ))
                             }
-                        }
+                        })
                     }
-                    syn::Fields::Unit => {
-                        quote! {
-                            #name => {
-                                decoder.check_static_size(0)?;
-                                Ok(Self::#v_id)
-                            }
+                    syn::Fields::Unit => Ok(quote! {
+                        #name => {
+                            decoder.check_static_size(0)?;
+                            Ok(Self::#v_id)
                         }
-                    }
+                    }),
                 }
             });
+            let match_arms = match_arms.collect::<Result<Vec<_>>>()?;
 
             quote! {
                 impl ::sbor::Decode for #ident {
@@ -153,11 +205,14 @@ pub fn handle_decode(input: TokenStream) -> Result<TokenStream> {
                     fn decode_value(decoder: &mut ::sbor::Decoder) -> Result<Self, ::sbor::DecodeError> {
                         use ::sbor::{self, Decode};
 
+                        decoder.enter_nested()?;
                         let name = decoder.read_variant_label()?;
-                        match name.as_str() {
+                        let value = match name.as_str() {
                             #(#match_arms,)*
                             _ => Err(::sbor::DecodeError::InvalidEnumVariant(name))
-                        }
+                        }?;
+                        decoder.exit_nested();
+                        Ok(value)
                     }
                 }
             }
@@ -174,6 +229,37 @@ pub fn handle_decode(input: TokenStream) -> Result<TokenStream> {
     Ok(output)
 }
 
+/// Decodes the tagged fields of an enum variant into `f0`, `f1`, ... in declaration order,
+/// skipping fields with unknown tags and rejecting repeated ones.
+fn decode_tagged_fields(fields: &[&Field]) -> Result<TokenStream> {
+    let tags = get_tags(fields)?;
+    let vars: Vec<Ident> = (0..fields.len()).map(|i| format_ident!("f{}", i)).collect();
+    let types = fields.iter().map(|f| &f.ty);
+    Ok(quote! {
+        let len = decoder.read_dynamic_size()?;
+        if len % 2 != 0 {
+            return Err(::sbor::DecodeError::InvalidLength {
+                expected: len + 1,
+                actual: len,
+            });
+        }
+        #(let mut #vars = None;)*
+        for _ in 0..len / 2 {
+            match <u8>::decode(decoder)? {
+                #(#tags => {
+                    if #vars.is_some() {
+                        return Err(::sbor::DecodeError::DuplicateTag(#tags));
+                    }
+                    #vars = Some(<#types>::decode(decoder)?);
+                })*
+                _ => {
+                    ::sbor::any::decode_next_any(decoder)?;
+                }
+            }
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use proc_macro2::TokenStream;
@@ -200,10 +286,13 @@ mod tests {
                     }
                     fn decode_value(decoder: &mut ::sbor::Decoder) -> Result<Self, ::sbor::DecodeError> {
                         use ::sbor::{self, Decode};
+                        decoder.enter_nested()?;
                         decoder.check_static_size(1)?;
-                        Ok(Self {
+                        let value = Self {
                             a: <u32>::decode(decoder)?,
-                        })
+                        };
+                        decoder.exit_nested();
+                        Ok(value)
                     }
                 }
             },
@@ -226,8 +315,9 @@ mod tests {
                     #[inline]
                     fn decode_value(decoder: &mut ::sbor::Decoder) -> Result<Self, ::sbor::DecodeError> {
                         use ::sbor::{self, Decode};
+                        decoder.enter_nested()?;
                         let name = decoder.read_variant_label()?;
-                        match name.as_str() {
+                        let value = match name.as_str() {
                             "A" => {
                                 decoder.check_static_size(0)?;
                                 Ok(Self::A)
@@ -243,7 +333,9 @@ mod tests {
                                 })
                             },
                             _ => Err(::sbor::DecodeError::InvalidEnumVariant(name))
-                        }
+                        }?;
+                        decoder.exit_nested();
+                        Ok(value)
                     }
                 }
             },
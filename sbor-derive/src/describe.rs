@@ -14,7 +14,9 @@ macro_rules! trace {
 pub fn handle_describe(input: TokenStream) -> Result<TokenStream> {
     trace!("handle_describe() starts");
 
-    let DeriveInput { ident, data, .. } = parse2(input)?;
+    let DeriveInput {
+        attrs, ident, data, ..
+    } = parse2(input)?;
     let ident_str = ident.to_string();
     trace!("Describing: {}", ident);
 
@@ -87,11 +89,20 @@ pub fn handle_describe(input: TokenStream) -> Result<TokenStream> {
             }
         },
         Data::Enum(DataEnum { variants, .. }) => {
+            let tagged = is_tagged(&attrs);
             let names = variants.iter().map(|v| v.ident.to_string());
-            let fields = variants.iter().map(|v| {
+            let fields = variants.iter().map(|v| -> Result<TokenStream> {
                 let f = &v.fields;
 
                 match f {
+                    syn::Fields::Named(FieldsNamed { named, .. }) if tagged => {
+                        let ns: Vec<&Field> = named.iter().filter(|f| !is_skipped(f)).collect();
+                        describe_tagged_fields(&ns)
+                    }
+                    syn::Fields::Unnamed(FieldsUnnamed { unnamed, .. }) if tagged => {
+                        let ns: Vec<&Field> = unnamed.iter().filter(|f| !is_skipped(f)).collect();
+                        describe_tagged_fields(&ns)
+                    }
                     syn::Fields::Named(FieldsNamed { named, .. }) => {
                         let ns: Vec<&Field> = named.iter().filter(|f| !is_skipped(f)).collect();
 
@@ -103,36 +114,35 @@ pub fn handle_describe(input: TokenStream) -> Result<TokenStream> {
                         });
                         let types = ns.iter().map(|f| &f.ty);
 
-                        quote! {
+                        Ok(quote! {
                             {
                                 ::sbor::describe::Fields::Named {
                                     named: vec![#((#names.to_owned(), <#types>::describe())),*]
                                 }
                             }
-                        }
+                        })
                     }
                     syn::Fields::Unnamed(FieldsUnnamed { unnamed, .. }) => {
                         let ns: Vec<&Field> = unnamed.iter().filter(|f| !is_skipped(f)).collect();
 
                         let types = ns.iter().map(|f| &f.ty);
 
-                        quote! {
+                        Ok(quote! {
                             {
                                 ::sbor::describe::Fields::Unnamed {
                                     unnamed: vec![#(<#types>::describe()),*]
                                 }
                             }
-                        }
+                        })
                     }
-                    syn::Fields::Unit => {
-                        quote! {
-                            {
-                                ::sbor::describe::Fields::Unit
-                            }
+                    syn::Fields::Unit => Ok(quote! {
+                        {
+                            ::sbor::describe::Fields::Unit
                         }
-                    }
+                    }),
                 }
             });
+            let fields = fields.collect::<Result<Vec<_>>>()?;
 
             quote! {
                 impl ::sbor::Describe for #ident {
@@ -166,6 +176,19 @@ pub fn handle_describe(input: TokenStream) -> Result<TokenStream> {
     Ok(output)
 }
 
+/// Describes the fields of a tagged enum variant by their tags, matching how they're encoded.
+fn describe_tagged_fields(fields: &[&Field]) -> Result<TokenStream> {
+    let tags = get_tags(fields)?;
+    let types = fields.iter().map(|f| &f.ty);
+    Ok(quote! {
+        {
+            ::sbor::describe::Fields::Tagged {
+                tagged: vec![#((#tags, <#types>::describe())),*]
+            }
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use proc_macro2::TokenStream;
@@ -310,4 +333,39 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_describe_tagged_enum() {
+        let input =
+            TokenStream::from_str("#[sbor(tagged)] enum Test {A {#[sbor(tag = 2)] x: u32}}")
+                .unwrap();
+        let output = handle_describe(input).unwrap();
+
+        assert_code_eq(
+            output,
+            quote! {
+                impl ::sbor::Describe for Test {
+                    fn describe() -> ::sbor::describe::Type {
+                        use ::sbor::rust::borrow::ToOwned;
+                        use ::sbor::rust::vec;
+                        use ::sbor::Describe;
+
+                        ::sbor::describe::Type::Enum {
+                            name: "Test".to_owned(),
+                            variants: vec![
+                                ::sbor::describe::Variant {
+                                    name: "A".to_owned(),
+                                    fields: {
+                                        ::sbor::describe::Fields::Tagged {
+                                            tagged: vec![(2u8, <u32>::describe())]
+                                        }
+                                    }
+                                }
+                            ]
+                        }
+                    }
+                }
+            },
+        );
+    }
 }
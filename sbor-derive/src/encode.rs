@@ -14,7 +14,9 @@ macro_rules! trace {
 pub fn handle_encode(input: TokenStream) -> Result<TokenStream> {
     trace!("handle_encode() starts");
 
-    let DeriveInput { ident, data, .. } = parse2(input)?;
+    let DeriveInput {
+        attrs, ident, data, ..
+    } = parse2(input)?;
     trace!("Encoding: {}", ident);
 
     let output = match data {
@@ -78,7 +80,8 @@ pub fn handle_encode(input: TokenStream) -> Result<TokenStream> {
             }
         },
         Data::Enum(DataEnum { variants, .. }) => {
-            let match_arms = variants.iter().map(|v| {
+            let tagged = is_tagged(&attrs);
+            let match_arms = variants.iter().map(|v| -> Result<TokenStream> {
                 let v_id = &v.ident;
                 let name_string = v_id.to_string();
                 let name: Expr = parse_quote! { #name_string };
@@ -88,14 +91,26 @@ pub fn handle_encode(input: TokenStream) -> Result<TokenStream> {
                         let ns: Vec<&Field> = named.iter().filter(|f| !is_skipped(f)).collect();
                         let ns_ids = ns.iter().map(|f| &f.ident);
                         let ns_ids2 = ns.iter().map(|f| &f.ident);
+                        if tagged {
+                            // Each field is preceded by its tag, so fields can be reordered
+                            let tags = get_tags(&ns)?;
+                            let len = Index::from(2 * ns.len());
+                            return Ok(quote! {
+                                Self::#v_id {#(#ns_ids,)* ..} => {
+                                    encoder.write_variant_label(#name);
+                                    encoder.write_dynamic_size(#len);
+                                    #(#tags.encode(encoder); #ns_ids2.encode(encoder);)*
+                                }
+                            });
+                        }
                         let ns_len = Index::from(ns.len());
-                        quote! {
+                        Ok(quote! {
                             Self::#v_id {#(#ns_ids,)* ..} => {
                                 encoder.write_variant_label(#name);
                                 encoder.write_static_size(#ns_len);
                                 #(#ns_ids2.encode(encoder);)*
                             }
-                        }
+                        })
                     }
                     syn::Fields::Unnamed(FieldsUnnamed { unnamed, .. }) => {
                         let args = (0..unnamed.len()).map(|i| format_ident!("a{}", i));
@@ -105,27 +120,39 @@ pub fn handle_encode(input: TokenStream) -> Result<TokenStream> {
                                 ns_args.push(format_ident!("a{}", i));
                             }
                         }
+                        if tagged {
+                            let ns: Vec<&Field> =
+                                unnamed.iter().filter(|f| !is_skipped(f)).collect();
+                            let tags = get_tags(&ns)?;
+                            let len = Index::from(2 * ns.len());
+                            return Ok(quote! {
+                                Self::#v_id (#(#args),*) => {
+                                    encoder.write_variant_label(#name);
+                                    encoder.write_dynamic_size(#len);
+                                    #(#tags.encode(encoder); #ns_args.encode(encoder);)*
+                                }
+                            });
+                        }
                         let ns_len = Index::from(ns_args.len());
-                        quote! {
+                        Ok(quote! {
                             Self::#v_id (#(#args),*) => {
                                 encoder.write_variant_label(#name);
                                 encoder.write_static_size(#ns_len);
                                 #(#ns_args.encode(encoder);)*
                             }
-                        }
+                        })
                     }
-                    syn::Fields::Unit => {
-                        quote! {
-                            Self::#v_id => {
-                                encoder.write_variant_label(#name);
-                                encoder.write_static_size(0);
-                            }
+                    syn::Fields::Unit => Ok(quote! {
+                        Self::#v_id => {
+                            encoder.write_variant_label(#name);
+                            encoder.write_static_size(0);
                         }
-                    }
+                    }),
                 }
             });
+            let match_arms = match_arms.collect::<Result<Vec<_>>>()?;
 
-            if match_arms.len() == 0 {
+            if match_arms.is_empty() {
                 quote! {
                     impl ::sbor::Encode for #ident {
                         #[inline]
@@ -240,4 +267,11 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_encode_tagged_enum_requires_tags() {
+        let input = TokenStream::from_str("#[sbor(tagged)] enum Test {A (u32)}").unwrap();
+
+        assert!(handle_encode(input).is_err());
+    }
 }
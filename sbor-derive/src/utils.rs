@@ -44,3 +44,43 @@ pub fn is_skipped(f: &syn::Field) -> bool {
     }
     skipped
 }
+
+pub fn is_tagged(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|att| {
+        att.path.is_ident("sbor")
+            && att
+                .parse_args::<syn::Path>()
+                .map(|p| p.is_ident("tagged"))
+                .unwrap_or(false)
+    })
+}
+
+/// Returns the `#[sbor(tag = N)]` of each field of a variant in a tagged enum.
+pub fn get_tags(fields: &[&syn::Field]) -> syn::Result<Vec<u8>> {
+    let mut tags = Vec::<u8>::new();
+    for f in fields {
+        let mut tag = None;
+        for att in &f.attrs {
+            if att.path.is_ident("sbor") {
+                if let Ok(syn::MetaNameValue {
+                    path,
+                    lit: syn::Lit::Int(lit),
+                    ..
+                }) = att.parse_args::<syn::MetaNameValue>()
+                {
+                    if path.is_ident("tag") {
+                        tag = Some(lit.base10_parse::<u8>()?);
+                    }
+                }
+            }
+        }
+        let tag = tag.ok_or_else(|| {
+            syn::Error::new_spanned(f, "Fields of a tagged enum require `#[sbor(tag = N)]`")
+        })?;
+        if tags.contains(&tag) {
+            return Err(syn::Error::new_spanned(f, "Duplicate field tag"));
+        }
+        tags.push(tag);
+    }
+    Ok(tags)
+}
@@ -1,10 +1,12 @@
 use sbor::path::MutableSborPath;
 
 use crate::decode::*;
+use crate::describe::*;
 use crate::encode::*;
 use crate::rust::borrow::Borrow;
 use crate::rust::boxed::Box;
 use crate::rust::string::String;
+use crate::rust::vec;
 use crate::rust::vec::Vec;
 use crate::type_id::*;
 
@@ -53,6 +55,17 @@ pub enum Value {
     String {
         value: String,
     },
+    Char {
+        value: char,
+    },
+    I256 {
+        #[cfg_attr(feature = "serde", serde(with = "hex::serde"))]
+        value: [u8; 32],
+    },
+    U256 {
+        #[cfg_attr(feature = "serde", serde(with = "hex::serde"))]
+        value: [u8; 32],
+    },
 
     Struct {
         fields: Vec<Value>,
@@ -127,6 +140,9 @@ fn encode_any_internal(ty_ctx: Option<u8>, value: &Value, enc: &mut Encoder) {
         Value::U64 { value } => encode_basic(ty_ctx, TYPE_U64, value, enc),
         Value::U128 { value } => encode_basic(ty_ctx, TYPE_U128, value, enc),
         Value::String { value } => encode_basic(ty_ctx, TYPE_STRING, value, enc),
+        Value::Char { value } => encode_basic(ty_ctx, TYPE_CHAR, value, enc),
+        Value::I256 { value } => encode_wide(ty_ctx, TYPE_I256, value, enc),
+        Value::U256 { value } => encode_wide(ty_ctx, TYPE_U256, value, enc),
         // struct & enum
         Value::Struct { fields } => {
             if ty_ctx.is_none() {
@@ -260,12 +276,35 @@ fn encode_basic<T: Encode>(ty_ctx: Option<u8>, t: u8, v: &T, enc: &mut Encoder)
     <T>::encode_value(v, enc);
 }
 
+fn encode_wide(ty_ctx: Option<u8>, t: u8, v: &[u8; 32], enc: &mut Encoder) {
+    if ty_ctx.is_none() {
+        enc.write_type_id(t);
+    }
+    enc.write_slice(v);
+}
+
+fn decode_wide(dec: &mut Decoder) -> Result<[u8; 32], DecodeError> {
+    let slice = dec.read_bytes(32)?;
+    let mut bytes = [0u8; 32];
+    bytes.copy_from_slice(slice);
+    Ok(bytes)
+}
+
 /// Decode any SBOR data.
 pub fn decode_any(data: &[u8]) -> Result<Value, DecodeError> {
     let mut decoder = Decoder::with_static_info(data);
-    let result = decode_next(None, &mut decoder);
+    let result = decode_next(None, &mut decoder)?;
     decoder.check_end()?;
-    result
+    Ok(result)
+}
+
+/// Decodes the next value of any type, e.g. to skip data which isn't understood by the caller.
+///
+/// Fails with `NoStaticInfo` if the data is encoded without static info, as the extent of the
+/// value can't be known without its type.
+pub fn decode_next_any(decoder: &mut Decoder) -> Result<Value, DecodeError> {
+    decoder.peek_type()?;
+    decode_next(None, decoder)
 }
 
 fn decode_next(ty_ctx: Option<u8>, dec: &mut Decoder) -> Result<Value, DecodeError> {
@@ -274,7 +313,23 @@ fn decode_next(ty_ctx: Option<u8>, dec: &mut Decoder) -> Result<Value, DecodeErr
         None => dec.read_type()?,
     };
 
-    match ty {
+    let nested = matches!(
+        ty,
+        TYPE_STRUCT
+            | TYPE_ENUM
+            | TYPE_OPTION
+            | TYPE_RESULT
+            | TYPE_ARRAY
+            | TYPE_TUPLE
+            | TYPE_LIST
+            | TYPE_SET
+            | TYPE_MAP
+    );
+    if nested {
+        dec.enter_nested()?;
+    }
+
+    let value = match ty {
         // primitive types
         TYPE_UNIT => {
             <()>::decode_value(dec)?;
@@ -316,6 +371,15 @@ fn decode_next(ty_ctx: Option<u8>, dec: &mut Decoder) -> Result<Value, DecodeErr
         TYPE_STRING => Ok(Value::String {
             value: <String>::decode_value(dec)?,
         }),
+        TYPE_CHAR => Ok(Value::Char {
+            value: <char>::decode_value(dec)?,
+        }),
+        TYPE_I256 => Ok(Value::I256 {
+            value: decode_wide(dec)?,
+        }),
+        TYPE_U256 => Ok(Value::U256 {
+            value: decode_wide(dec)?,
+        }),
         // struct & enum
         TYPE_STRUCT => {
             // number of fields
@@ -446,7 +510,7 @@ fn decode_next(ty_ctx: Option<u8>, dec: &mut Decoder) -> Result<Value, DecodeErr
         _ => {
             if ty >= TYPE_CUSTOM_START {
                 // length
-                let len = dec.read_dynamic_size()?;
+                let len = dec.read_len(ty)?;
                 let slice = dec.read_bytes(len)?;
                 Ok(Value::Custom {
                     type_id: ty,
@@ -459,6 +523,210 @@ fn decode_next(ty_ctx: Option<u8>, dec: &mut Decoder) -> Result<Value, DecodeErr
                 })
             }
         }
+    }?;
+
+    if nested {
+        dec.exit_nested();
+    }
+    Ok(value)
+}
+
+/// Decode any SBOR data, inferring the type of the decoded value from the encountered type ids.
+///
+/// Struct, enum and field names are not part of the encoding, so they're left empty/unnamed.
+pub fn decode_any_with_schema(data: &[u8]) -> Result<(Value, Type), DecodeError> {
+    let value = decode_any(data)?;
+    let ty = infer_type(&value);
+    Ok((value, ty))
+}
+
+/// Infers the type of a SBOR value.
+pub fn infer_type(value: &Value) -> Type {
+    match value {
+        Value::Unit => Type::Unit,
+        Value::Bool { .. } => Type::Bool,
+        Value::I8 { .. } => Type::I8,
+        Value::I16 { .. } => Type::I16,
+        Value::I32 { .. } => Type::I32,
+        Value::I64 { .. } => Type::I64,
+        Value::I128 { .. } => Type::I128,
+        Value::U8 { .. } => Type::U8,
+        Value::U16 { .. } => Type::U16,
+        Value::U32 { .. } => Type::U32,
+        Value::U64 { .. } => Type::U64,
+        Value::U128 { .. } => Type::U128,
+        Value::String { .. } => Type::String,
+        Value::Char { .. } => Type::Char,
+        Value::I256 { .. } => Type::I256,
+        Value::U256 { .. } => Type::U256,
+        Value::Struct { fields } => Type::Struct {
+            name: String::new(),
+            fields: infer_fields(fields),
+        },
+        Value::Enum { name, fields } => Type::Enum {
+            name: String::new(),
+            variants: vec![Variant {
+                name: name.clone(),
+                fields: infer_fields(fields),
+            }],
+        },
+        Value::Option { value } => Type::Option {
+            value: Box::new(match value.borrow() {
+                Some(v) => infer_type(v),
+                None => Type::Any,
+            }),
+        },
+        Value::Result { value } => match value.borrow() {
+            Ok(v) => Type::Result {
+                okay: Box::new(infer_type(v)),
+                error: Box::new(Type::Any),
+            },
+            Err(e) => Type::Result {
+                okay: Box::new(Type::Any),
+                error: Box::new(infer_type(e)),
+            },
+        },
+        Value::Array {
+            element_type_id,
+            elements,
+        } => Type::Array {
+            element: Box::new(infer_element_type(*element_type_id, elements.iter())),
+            length: elements.len() as u16,
+        },
+        Value::Tuple { elements } => Type::Tuple {
+            elements: elements.iter().map(infer_type).collect(),
+        },
+        Value::List {
+            element_type_id,
+            elements,
+        } => Type::Vec {
+            element: Box::new(infer_element_type(*element_type_id, elements.iter())),
+        },
+        Value::Set {
+            element_type_id,
+            elements,
+        } => Type::TreeSet {
+            element: Box::new(infer_element_type(*element_type_id, elements.iter())),
+        },
+        Value::Map {
+            key_type_id,
+            value_type_id,
+            elements,
+        } => Type::TreeMap {
+            key: Box::new(infer_element_type(*key_type_id, elements.iter().step_by(2))),
+            value: Box::new(infer_element_type(
+                *value_type_id,
+                elements.iter().skip(1).step_by(2),
+            )),
+        },
+        Value::Custom { type_id, .. } => Type::Custom {
+            type_id: *type_id,
+            generics: Vec::new(),
+        },
+    }
+}
+
+fn infer_fields(fields: &[Value]) -> Fields {
+    if fields.is_empty() {
+        Fields::Unit
+    } else {
+        Fields::Unnamed {
+            unnamed: fields.iter().map(infer_type).collect(),
+        }
+    }
+}
+
+fn infer_element_type<'a, I: Iterator<Item = &'a Value>>(type_id: u8, elements: I) -> Type {
+    elements
+        .map(infer_type)
+        .reduce(merge_types)
+        .unwrap_or_else(|| match type_id {
+            TYPE_UNIT => Type::Unit,
+            TYPE_BOOL => Type::Bool,
+            TYPE_I8 => Type::I8,
+            TYPE_I16 => Type::I16,
+            TYPE_I32 => Type::I32,
+            TYPE_I64 => Type::I64,
+            TYPE_I128 => Type::I128,
+            TYPE_U8 => Type::U8,
+            TYPE_U16 => Type::U16,
+            TYPE_U32 => Type::U32,
+            TYPE_U64 => Type::U64,
+            TYPE_U128 => Type::U128,
+            TYPE_STRING => Type::String,
+            TYPE_CHAR => Type::Char,
+            TYPE_I256 => Type::I256,
+            TYPE_U256 => Type::U256,
+            t if t >= TYPE_CUSTOM_START => Type::Custom {
+                type_id: t,
+                generics: Vec::new(),
+            },
+            _ => Type::Any,
+        })
+}
+
+/// Merges the types inferred from two values of the same collection, falling back to `Any`
+/// where they disagree.
+fn merge_types(a: Type, b: Type) -> Type {
+    match (a, b) {
+        (a, b) if a == b => a,
+        (Type::Any, t) | (t, Type::Any) => t,
+        (Type::Option { value: a }, Type::Option { value: b }) => Type::Option {
+            value: Box::new(merge_types(*a, *b)),
+        },
+        (
+            Type::Result {
+                okay: okay_a,
+                error: error_a,
+            },
+            Type::Result {
+                okay: okay_b,
+                error: error_b,
+            },
+        ) => Type::Result {
+            okay: Box::new(merge_types(*okay_a, *okay_b)),
+            error: Box::new(merge_types(*error_a, *error_b)),
+        },
+        (
+            Type::Enum {
+                name,
+                variants: mut variants_a,
+            },
+            Type::Enum {
+                variants: variants_b,
+                ..
+            },
+        ) => {
+            for variant in variants_b {
+                if !variants_a.iter().any(|v| v.name == variant.name) {
+                    variants_a.push(variant);
+                }
+            }
+            Type::Enum {
+                name,
+                variants: variants_a,
+            }
+        }
+        (Type::Vec { element: a }, Type::Vec { element: b }) => Type::Vec {
+            element: Box::new(merge_types(*a, *b)),
+        },
+        (Type::TreeSet { element: a }, Type::TreeSet { element: b }) => Type::TreeSet {
+            element: Box::new(merge_types(*a, *b)),
+        },
+        (
+            Type::TreeMap {
+                key: key_a,
+                value: value_a,
+            },
+            Type::TreeMap {
+                key: key_b,
+                value: value_b,
+            },
+        ) => Type::TreeMap {
+            key: Box::new(merge_types(*key_a, *key_b)),
+            value: Box::new(merge_types(*value_a, *value_b)),
+        },
+        _ => Type::Any,
     }
 }
 
@@ -484,7 +752,10 @@ where
         | Value::U32 { .. }
         | Value::U64 { .. }
         | Value::U128 { .. }
-        | Value::String { .. } => {}
+        | Value::String { .. }
+        | Value::Char { .. }
+        | Value::I256 { .. }
+        | Value::U256 { .. } => {}
         // struct & enum
         Value::Struct { fields } | Value::Enum { fields, .. } => {
             for (i, field) in fields.iter().enumerate() {
@@ -745,4 +1016,100 @@ mod tests {
             value
         );
     }
+
+    #[derive(TypeId, Encode, Describe)]
+    struct TestSchema {
+        a: u32,
+        b: Option<String>,
+        c: Vec<TestSchemaEnum>,
+        d: BTreeMap<u8, (bool, i64)>,
+    }
+
+    #[derive(TypeId, Encode, Describe)]
+    enum TestSchemaEnum {
+        A(u32),
+        B,
+    }
+
+    #[test]
+    pub fn test_decode_with_schema() {
+        let mut d = BTreeMap::new();
+        d.insert(1, (true, -1));
+        let data = TestSchema {
+            a: 1,
+            b: Some("abc".to_string()),
+            c: vec![
+                TestSchemaEnum::A(2),
+                TestSchemaEnum::B,
+                TestSchemaEnum::A(3),
+            ],
+            d,
+        };
+        let bytes = crate::encode_with_static_info(&data);
+
+        let (value, ty) = decode_any_with_schema(&bytes).unwrap();
+
+        assert_eq!(
+            Type::Struct {
+                name: String::new(),
+                fields: Fields::Unnamed {
+                    unnamed: vec![
+                        Type::U32,
+                        Type::Option {
+                            value: Box::new(Type::String)
+                        },
+                        Type::Vec {
+                            element: Box::new(Type::Enum {
+                                name: String::new(),
+                                variants: vec![
+                                    Variant {
+                                        name: "A".to_string(),
+                                        fields: Fields::Unnamed {
+                                            unnamed: vec![Type::U32]
+                                        }
+                                    },
+                                    Variant {
+                                        name: "B".to_string(),
+                                        fields: Fields::Unit
+                                    },
+                                ]
+                            })
+                        },
+                        Type::TreeMap {
+                            key: Box::new(Type::U8),
+                            value: Box::new(Type::Tuple {
+                                elements: vec![Type::Bool, Type::I64]
+                            })
+                        },
+                    ]
+                }
+            },
+            ty
+        );
+        assert!(ty.matches(&value));
+        assert!(TestSchema::describe().matches(&value));
+        assert_eq!(encode_any(&value), bytes);
+    }
+
+    #[test]
+    pub fn test_decode_with_schema_empty_collections() {
+        let data: (Vec<u32>, Vec<Option<u32>>) = (Vec::new(), Vec::new());
+        let bytes = crate::encode_with_static_info(&data);
+
+        let (_, ty) = decode_any_with_schema(&bytes).unwrap();
+
+        assert_eq!(
+            Type::Tuple {
+                elements: vec![
+                    Type::Vec {
+                        element: Box::new(Type::U32)
+                    },
+                    Type::Vec {
+                        element: Box::new(Type::Any)
+                    },
+                ]
+            },
+            ty
+        );
+    }
 }
@@ -14,29 +14,58 @@ use sbor::*;
 /// Represents an error ocurred during decoding.
 #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeId)]
 pub enum DecodeError {
-    Underflow { required: usize, remaining: usize },
-
-    InvalidType { expected: Option<u8>, actual: u8 },
-
-    InvalidName { expected: String, actual: String },
-
-    InvalidLength { expected: usize, actual: usize },
+    /// Ran out of bytes, while decoding a value of the type with id `while_decoding`, if known.
+    Underflow {
+        required: usize,
+        remaining: usize,
+        while_decoding: Option<u8>,
+    },
+
+    InvalidType {
+        expected: Option<u8>,
+        actual: u8,
+    },
+
+    InvalidName {
+        expected: String,
+        actual: String,
+    },
+
+    InvalidLength {
+        expected: usize,
+        actual: usize,
+    },
 
     InvalidIndex(u8),
 
     InvalidEnumVariant(String),
 
+    MissingTag(u8),
+
+    DuplicateTag(u8),
+
     InvalidUnit(u8),
 
     InvalidBool(u8),
 
     InvalidUtf8,
 
+    InvalidChar(u32),
+
     NotAllBytesUsed(usize),
 
+    MaxDepthExceeded {
+        depth: usize,
+    },
+
+    NoStaticInfo,
+
     CustomError(String),
 }
 
+/// The default limit on how deeply decoded values may nest.
+pub const DEFAULT_MAX_DEPTH: usize = 32;
+
 /// A data structure that can be decoded from a byte array using SBOR.
 pub trait Decode: Sized {
     fn decode(decoder: &mut Decoder) -> Result<Self, DecodeError> {
@@ -54,17 +83,39 @@ pub struct Decoder<'de> {
     input: &'de [u8],
     offset: usize,
     with_static_info: bool,
+    max_depth: usize,
+    depth: usize,
+    enforce_canonical_collections: bool,
+    /// The id of the type most recently checked or read, reported on underflow.
+    while_decoding: Option<u8>,
 }
 
 impl<'de> Decoder<'de> {
     pub fn new(input: &'de [u8], with_static_info: bool) -> Self {
+        Self::with_max_depth(input, with_static_info, DEFAULT_MAX_DEPTH)
+    }
+
+    /// Creates a decoder which rejects values nested deeper than `max_depth`.
+    pub fn with_max_depth(input: &'de [u8], with_static_info: bool, max_depth: usize) -> Self {
         Self {
             input,
             offset: 0,
             with_static_info,
+            max_depth,
+            depth: 0,
+            enforce_canonical_collections: false,
+            while_decoding: None,
         }
     }
 
+    /// Sets whether `HashMap` keys and `HashSet` elements must appear in ascending order of
+    /// their encoded bytes, as they are encoded, so that every collection has exactly one
+    /// accepted encoding.
+    pub fn enforce_canonical_collections(mut self, enforce: bool) -> Self {
+        self.enforce_canonical_collections = enforce;
+        self
+    }
+
     pub fn with_static_info(input: &'de [u8]) -> Self {
         Self::new(input, true)
     }
@@ -83,6 +134,7 @@ impl<'de> Decoder<'de> {
             Err(DecodeError::Underflow {
                 required: n,
                 remaining: self.remaining(),
+                while_decoding: self.while_decoding,
             })
         } else {
             Ok(())
@@ -90,7 +142,20 @@ impl<'de> Decoder<'de> {
     }
 
     pub fn read_type(&mut self) -> Result<u8, DecodeError> {
-        self.read_byte()
+        let ty = self.read_byte()?;
+        self.while_decoding = Some(ty);
+        Ok(ty)
+    }
+
+    /// Returns the upcoming type id without consuming it.
+    ///
+    /// Fails if the data is encoded without static info, as there's no type id to peek at.
+    pub fn peek_type(&self) -> Result<u8, DecodeError> {
+        if !self.with_static_info {
+            return Err(DecodeError::NoStaticInfo);
+        }
+        self.require(1)?;
+        Ok(self.input[self.offset])
     }
 
     pub fn read_variant_index(&mut self) -> Result<u8, DecodeError> {
@@ -98,9 +163,14 @@ impl<'de> Decoder<'de> {
     }
 
     pub fn read_variant_label(&mut self) -> Result<String, DecodeError> {
-        let n = self.read_dynamic_size()?;
+        self.read_str().map(ToString::to_string)
+    }
+
+    /// Reads a length-prefixed UTF-8 string, borrowing it from the input.
+    pub fn read_str(&mut self) -> Result<&'de str, DecodeError> {
+        let n = self.read_len(TYPE_STRING)?;
         let slice = self.read_bytes(n)?;
-        String::from_utf8(slice.to_vec()).map_err(|_| DecodeError::InvalidUtf8)
+        crate::rust::str::from_utf8(slice).map_err(|_| DecodeError::InvalidUtf8)
     }
 
     pub fn read_static_size(&mut self) -> Result<usize, DecodeError> {
@@ -115,6 +185,20 @@ impl<'de> Decoder<'de> {
         Ok(u32::from_le_bytes(bytes) as usize)
     }
 
+    /// Reads the length of a byte-backed value of type `type_id`, such as a string or a byte
+    /// list, failing early if it declares more bytes than are left in the input.
+    pub fn read_len(&mut self, type_id: u8) -> Result<usize, DecodeError> {
+        self.while_decoding = Some(type_id);
+        let len = self.read_dynamic_size()?;
+        if len > self.remaining() {
+            return Err(DecodeError::InvalidLength {
+                expected: self.remaining(),
+                actual: len,
+            });
+        }
+        Ok(len)
+    }
+
     pub fn read_byte(&mut self) -> Result<u8, DecodeError> {
         self.require(1)?;
         let result = self.input[self.offset];
@@ -130,6 +214,7 @@ impl<'de> Decoder<'de> {
     }
 
     pub fn check_type_id(&mut self, expected: u8) -> Result<(), DecodeError> {
+        self.while_decoding = Some(expected);
         if self.with_static_info {
             let ty = self.read_type()?;
             if ty != expected {
@@ -157,6 +242,47 @@ impl<'de> Decoder<'de> {
         Ok(())
     }
 
+    /// Steps into a nested value, failing if that goes beyond the max depth.
+    ///
+    /// Every successful call must be paired with an `exit_nested` once the value is decoded.
+    pub fn enter_nested(&mut self) -> Result<(), DecodeError> {
+        if self.depth >= self.max_depth {
+            return Err(DecodeError::MaxDepthExceeded {
+                depth: self.max_depth,
+            });
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    /// Steps out of a nested value.
+    pub fn exit_nested(&mut self) {
+        self.depth -= 1;
+    }
+
+    /// Checks that the entry decoded from `start` sorts after the previous entry, if canonical
+    /// collections are enforced.
+    fn check_canonical_entry(
+        &self,
+        previous: &mut Option<&'de [u8]>,
+        start: usize,
+        end: usize,
+    ) -> Result<(), DecodeError> {
+        if !self.enforce_canonical_collections {
+            return Ok(());
+        }
+        let current = &self.input[start..end];
+        if let Some(previous) = previous {
+            if *previous >= current {
+                return Err(DecodeError::CustomError(
+                    "Unordered map entries".to_string(),
+                ));
+            }
+        }
+        *previous = Some(current);
+        Ok(())
+    }
+
     pub fn check_end(&self) -> Result<(), DecodeError> {
         let n = self.remaining();
         if n != 0 {
@@ -270,9 +396,18 @@ impl Decode for String {
         decoder.check_type_id(Self::type_id())
     }
     fn decode_value(decoder: &mut Decoder) -> Result<Self, DecodeError> {
-        let len = decoder.read_dynamic_size()?;
-        let slice = decoder.read_bytes(len)?;
-        String::from_utf8(slice.to_vec()).map_err(|_| DecodeError::InvalidUtf8)
+        decoder.read_str().map(ToString::to_string)
+    }
+}
+
+impl Decode for char {
+    #[inline]
+    fn check_type_id(decoder: &mut Decoder) -> Result<(), DecodeError> {
+        decoder.check_type_id(Self::type_id())
+    }
+    fn decode_value(decoder: &mut Decoder) -> Result<Self, DecodeError> {
+        let value = u32::decode_value(decoder)?;
+        char::from_u32(value).ok_or(DecodeError::InvalidChar(value))
     }
 }
 
@@ -282,13 +417,16 @@ impl<T: Decode> Decode for Option<T> {
         decoder.check_type_id(Self::type_id())
     }
     fn decode_value(decoder: &mut Decoder) -> Result<Self, DecodeError> {
+        decoder.enter_nested()?;
         let index = decoder.read_variant_index()?;
 
-        match index {
-            OPTION_VARIANT_SOME => Ok(Some(T::decode(decoder)?)),
-            OPTION_VARIANT_NONE => Ok(None),
-            _ => Err(DecodeError::InvalidIndex(index)),
-        }
+        let value = match index {
+            OPTION_VARIANT_SOME => Some(T::decode(decoder)?),
+            OPTION_VARIANT_NONE => None,
+            _ => return Err(DecodeError::InvalidIndex(index)),
+        };
+        decoder.exit_nested();
+        Ok(value)
     }
 }
 
@@ -331,6 +469,7 @@ impl<T: Decode + TypeId, const N: usize> Decode for [T; N] {
         decoder.check_type_id(Self::type_id())
     }
     fn decode_value(decoder: &mut Decoder) -> Result<Self, DecodeError> {
+        decoder.enter_nested()?;
         decoder.check_type_id(T::type_id())?;
         decoder.check_static_size(N)?;
 
@@ -353,6 +492,7 @@ impl<T: Decode + TypeId, const N: usize> Decode for [T; N] {
         let res = unsafe { ptr.read() };
         core::mem::forget(data);
 
+        decoder.exit_nested();
         Ok(res)
     }
 }
@@ -365,9 +505,12 @@ macro_rules! decode_tuple {
                 decoder.check_type_id(Self::type_id())
             }
             fn decode_value(decoder: &mut Decoder) -> Result<Self, DecodeError> {
+                decoder.enter_nested()?;
                 decoder.check_static_size($n)?;
 
-                Ok(($($name::decode(decoder)?),+))
+                let value = ($($name::decode(decoder)?),+);
+                decoder.exit_nested();
+                Ok(value)
             }
         }
     };
@@ -389,12 +532,15 @@ impl<T: Decode + TypeId, E: Decode + TypeId> Decode for Result<T, E> {
         decoder.check_type_id(Self::type_id())
     }
     fn decode_value(decoder: &mut Decoder) -> Result<Self, DecodeError> {
+        decoder.enter_nested()?;
         let index = decoder.read_variant_index()?;
-        match index {
-            RESULT_VARIANT_OK => Ok(Ok(T::decode(decoder)?)),
-            RESULT_VARIANT_ERR => Ok(Err(E::decode(decoder)?)),
-            _ => Err(DecodeError::InvalidIndex(index)),
-        }
+        let value = match index {
+            RESULT_VARIANT_OK => Ok(T::decode(decoder)?),
+            RESULT_VARIANT_ERR => Err(E::decode(decoder)?),
+            _ => return Err(DecodeError::InvalidIndex(index)),
+        };
+        decoder.exit_nested();
+        Ok(value)
     }
 }
 
@@ -404,24 +550,29 @@ impl<T: Decode + TypeId> Decode for Vec<T> {
         decoder.check_type_id(Self::type_id())
     }
     fn decode_value(decoder: &mut Decoder) -> Result<Self, DecodeError> {
+        decoder.enter_nested()?;
         decoder.check_type_id(T::type_id())?;
-        let len = decoder.read_dynamic_size()?;
-
-        if T::type_id() == TYPE_U8 || T::type_id() == TYPE_I8 {
-            let slice = decoder.read_bytes(len)?; // length is checked here
+        let result = if T::type_id() == TYPE_U8 || T::type_id() == TYPE_I8 {
+            let len = decoder.read_len(Self::type_id())?;
+            let slice = decoder.read_bytes(len)?;
             let mut result = Vec::<T>::with_capacity(len);
             unsafe {
                 copy(slice.as_ptr(), result.as_mut_ptr() as *mut u8, slice.len());
                 result.set_len(slice.len());
             }
-            Ok(result)
+            result
         } else {
-            let mut result = Vec::<T>::with_capacity(if len <= 1024 { len } else { 1024 });
+            let len = decoder.read_dynamic_size()?;
+            // Elements may take no bytes at all, so the length can't be bound by the input,
+            // but there's no point in allocating for more elements than there are bytes left
+            let mut result = Vec::<T>::with_capacity(len.min(1024).min(decoder.remaining()));
             for _ in 0..len {
                 result.push(T::decode_value(decoder)?);
             }
-            Ok(result)
-        }
+            result
+        };
+        decoder.exit_nested();
+        Ok(result)
     }
 }
 
@@ -431,6 +582,7 @@ impl<T: Decode + TypeId + Ord> Decode for BTreeSet<T> {
         decoder.check_type_id(Self::type_id())
     }
     fn decode_value(decoder: &mut Decoder) -> Result<Self, DecodeError> {
+        decoder.enter_nested()?;
         decoder.check_type_id(T::type_id())?;
         let len = decoder.read_dynamic_size()?;
 
@@ -443,6 +595,7 @@ impl<T: Decode + TypeId + Ord> Decode for BTreeSet<T> {
                 ));
             }
         }
+        decoder.exit_nested();
         Ok(result)
     }
 }
@@ -453,6 +606,7 @@ impl<K: Decode + TypeId + Ord, V: Decode + TypeId> Decode for BTreeMap<K, V> {
         decoder.check_type_id(Self::type_id())
     }
     fn decode_value(decoder: &mut Decoder) -> Result<Self, DecodeError> {
+        decoder.enter_nested()?;
         decoder.check_type_id(K::type_id())?;
         decoder.check_type_id(V::type_id())?;
         let len = decoder.read_dynamic_size()?;
@@ -468,6 +622,7 @@ impl<K: Decode + TypeId + Ord, V: Decode + TypeId> Decode for BTreeMap<K, V> {
                 ));
             }
         }
+        decoder.exit_nested();
         Ok(map)
     }
 }
@@ -478,18 +633,24 @@ impl<T: Decode + TypeId + Hash + Eq> Decode for HashSet<T> {
         decoder.check_type_id(Self::type_id())
     }
     fn decode_value(decoder: &mut Decoder) -> Result<Self, DecodeError> {
+        decoder.enter_nested()?;
         decoder.check_type_id(T::type_id())?;
         let len = decoder.read_dynamic_size()?;
 
         let mut result = HashSet::new();
+        let mut previous = None;
         for _ in 0..len {
-            if !result.insert(T::decode_value(decoder)?) {
+            let start = decoder.offset;
+            let element = T::decode_value(decoder)?;
+            decoder.check_canonical_entry(&mut previous, start, decoder.offset)?;
+            if !result.insert(element) {
                 // This is a custom error because key duplication logic is defined by the application
                 return Err(DecodeError::CustomError(
                     "Duplicate HashSet entries".to_string(),
                 ));
             }
         }
+        decoder.exit_nested();
         Ok(result)
     }
 }
@@ -500,21 +661,24 @@ impl<K: Decode + TypeId + Hash + Eq, V: Decode + TypeId> Decode for HashMap<K, V
         decoder.check_type_id(Self::type_id())
     }
     fn decode_value(decoder: &mut Decoder) -> Result<Self, DecodeError> {
+        decoder.enter_nested()?;
         decoder.check_type_id(K::type_id())?;
         decoder.check_type_id(V::type_id())?;
         let len = decoder.read_dynamic_size()?;
         let mut map = HashMap::new();
+        let mut previous = None;
         for _ in 0..len {
-            if map
-                .insert(K::decode_value(decoder)?, V::decode_value(decoder)?)
-                .is_some()
-            {
+            let start = decoder.offset;
+            let key = K::decode_value(decoder)?;
+            decoder.check_canonical_entry(&mut previous, start, decoder.offset)?;
+            if map.insert(key, V::decode_value(decoder)?).is_some() {
                 // This is a custom error because key duplication logic is defined by the application
                 return Err(DecodeError::CustomError(
                     "Duplicate HashMap entries".to_string(),
                 ));
             }
         }
+        decoder.exit_nested();
         Ok(map)
     }
 }
@@ -654,6 +818,92 @@ mod tests {
         b: Vec<u8>,
     }
 
+    #[derive(sbor::TypeId, sbor::Encode, sbor::Decode, PartialEq, Eq, Debug)]
+    struct Order {
+        id: u32,
+        amount: u64,
+    }
+
+    #[test]
+    pub fn test_underflow_reports_type_being_decoded() {
+        let mut bytes = Vec::with_capacity(512);
+        let mut enc = Encoder::with_static_info(&mut bytes);
+        Order { id: 1, amount: 2 }.encode(&mut enc);
+        bytes.truncate(bytes.len() - 3);
+
+        let mut dec = Decoder::with_static_info(&bytes);
+        assert_eq!(
+            Err(DecodeError::Underflow {
+                required: 8,
+                remaining: 5,
+                while_decoding: Some(TYPE_U64),
+            }),
+            <Order>::decode(&mut dec)
+        );
+    }
+
+    #[test]
+    pub fn test_underflow_reports_type_of_truncated_length() {
+        let bytes = vec![
+            TYPE_LIST, TYPE_U8, // Vec<u8>
+            1, 0, // truncated len
+        ];
+        let mut dec = Decoder::with_static_info(&bytes);
+        assert_eq!(
+            Err(DecodeError::Underflow {
+                required: 4,
+                remaining: 2,
+                while_decoding: Some(TYPE_LIST),
+            }),
+            <Vec<u8>>::decode(&mut dec)
+        );
+    }
+
+    #[test]
+    pub fn test_huge_length_prefix_fails_before_reading() {
+        let bytes = vec![
+            TYPE_LIST, TYPE_U8, // Vec<u8>
+            0xff, 0xff, 0xff, 0xff, // len
+            1, 2, 3,
+        ];
+        let mut dec = Decoder::with_static_info(&bytes);
+        assert_eq!(
+            Err(DecodeError::InvalidLength {
+                expected: 3,
+                actual: 0xffffffff,
+            }),
+            <Vec<u8>>::decode(&mut dec)
+        );
+
+        let bytes = vec![
+            TYPE_STRING, // String
+            0xff,
+            0xff,
+            0xff,
+            0xff, // len
+            b'a',
+        ];
+        let mut dec = Decoder::with_static_info(&bytes);
+        assert_eq!(
+            Err(DecodeError::InvalidLength {
+                expected: 1,
+                actual: 0xffffffff,
+            }),
+            <String>::decode(&mut dec)
+        );
+
+        let bytes = vec![
+            TYPE_LIST, TYPE_U32, // Vec<u32>
+            0xff, 0xff, 0xff, 0xff, // len
+            1, 0, 0, 0, 2,
+        ];
+        let mut dec = Decoder::with_static_info(&bytes);
+        assert!(matches!(
+            <Vec<u32>>::decode(&mut dec),
+            Err(DecodeError::Underflow { .. })
+        ));
+    }
+
     #[test]
     pub fn test_generic_array() {
         let value1 = [
@@ -676,4 +926,367 @@ mod tests {
         let value2 = <[NFA; 2]>::decode(&mut dec).unwrap();
         assert_eq!(value1, value2);
     }
+    fn nested_options(depth: usize) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for _ in 0..depth {
+            bytes.push(TYPE_OPTION);
+            bytes.push(OPTION_VARIANT_SOME);
+        }
+        bytes.push(TYPE_U8);
+        bytes.push(0);
+        bytes
+    }
+
+    #[test]
+    pub fn test_decode_max_depth() {
+        assert!(crate::decode_any(&nested_options(DEFAULT_MAX_DEPTH - 1)).is_ok());
+        assert!(crate::decode_any(&nested_options(DEFAULT_MAX_DEPTH)).is_ok());
+        assert_eq!(
+            crate::decode_any(&nested_options(DEFAULT_MAX_DEPTH + 1)),
+            Err(DecodeError::MaxDepthExceeded {
+                depth: DEFAULT_MAX_DEPTH
+            })
+        );
+    }
+
+    #[test]
+    pub fn test_decode_custom_max_depth() {
+        let bytes = nested_options(2);
+        let mut dec = Decoder::with_max_depth(&bytes, true, 2);
+        assert_eq!(
+            Some(Some(0u8)),
+            <Option<Option<u8>>>::decode(&mut dec).unwrap()
+        );
+
+        let bytes = nested_options(3);
+        let mut dec = Decoder::with_max_depth(&bytes, true, 2);
+        assert_eq!(
+            Err(DecodeError::MaxDepthExceeded { depth: 2 }),
+            <Option<Option<Option<u8>>>>::decode(&mut dec)
+        );
+    }
+
+    #[test]
+    pub fn test_decode_max_depth_siblings() {
+        let value = (Some(Some(1u8)), Some(Some(2u8)), Some(Some(3u8)));
+        let mut bytes = Vec::with_capacity(512);
+        let mut enc = Encoder::with_static_info(&mut bytes);
+        value.encode(&mut enc);
+
+        let mut dec = Decoder::with_max_depth(&bytes, true, 3);
+        assert_eq!(
+            value,
+            <(Option<Option<u8>>, Option<Option<u8>>, Option<Option<u8>>)>::decode(&mut dec)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    pub fn test_peek_type() {
+        let bytes = vec![TYPE_U8, 5u8];
+        let mut dec = Decoder::with_static_info(&bytes);
+        assert_eq!(Ok(TYPE_U8), dec.peek_type());
+        assert_eq!(Ok(TYPE_U8), dec.peek_type());
+        assert_eq!(Ok(TYPE_U8), dec.read_type());
+        assert_eq!(Ok(5u8), dec.read_byte());
+        assert_eq!(
+            Err(DecodeError::Underflow {
+                required: 1,
+                remaining: 0,
+                while_decoding: Some(TYPE_U8),
+            }),
+            dec.peek_type()
+        );
+    }
+
+    #[test]
+    pub fn test_peek_type_no_static_info() {
+        let bytes = vec![5u8];
+        let dec = Decoder::no_static_info(&bytes);
+        assert_eq!(Err(DecodeError::NoStaticInfo), dec.peek_type());
+    }
+
+    #[test]
+    pub fn test_read_str() {
+        let bytes = vec![5u8, 0, 0, 0, 104, 101, 108, 108, 111];
+        let mut dec = Decoder::no_static_info(&bytes);
+        let s = dec.read_str().unwrap();
+        assert_eq!(s, "hello");
+        assert_eq!(s.as_ptr(), bytes[4..].as_ptr());
+        dec.check_end().unwrap();
+    }
+
+    #[test]
+    pub fn test_read_str_invalid_utf8() {
+        let bytes = vec![2u8, 0, 0, 0, 0xc3, 0x28];
+        let mut dec = Decoder::no_static_info(&bytes);
+        assert_eq!(Err(DecodeError::InvalidUtf8), dec.read_str());
+    }
+
+    #[test]
+    pub fn test_encode_decode_char() {
+        for c in ['a', '😀'] {
+            let mut bytes = Vec::with_capacity(512);
+            let mut enc = Encoder::with_static_info(&mut bytes);
+            c.encode(&mut enc);
+
+            let mut dec = Decoder::with_static_info(&bytes);
+            assert_eq!(c, <char>::decode(&mut dec).unwrap());
+            dec.check_end().unwrap();
+        }
+    }
+
+    #[test]
+    pub fn test_decode_surrogate_char() {
+        let bytes = vec![TYPE_CHAR, 0x00, 0xd8, 0x00, 0x00];
+        let mut dec = Decoder::with_static_info(&bytes);
+        assert_eq!(
+            Err(DecodeError::InvalidChar(0xd800)),
+            <char>::decode(&mut dec)
+        );
+    }
+
+    #[test]
+    pub fn test_decode_canonical_hash_map() {
+        let ordered = vec![2u8, 0, 0, 0, 1, 10, 2, 20];
+        let shuffled = vec![2u8, 0, 0, 0, 2, 20, 1, 10];
+        let expected = HashMap::from([(1u8, 10u8), (2u8, 20u8)]);
+
+        let mut dec = Decoder::no_static_info(&shuffled);
+        assert_eq!(Ok(expected.clone()), <HashMap<u8, u8>>::decode(&mut dec));
+
+        let mut dec = Decoder::no_static_info(&ordered).enforce_canonical_collections(true);
+        assert_eq!(Ok(expected), <HashMap<u8, u8>>::decode(&mut dec));
+
+        let mut dec = Decoder::no_static_info(&shuffled).enforce_canonical_collections(true);
+        assert_eq!(
+            Err(DecodeError::CustomError("Unordered map entries".to_owned())),
+            <HashMap<u8, u8>>::decode(&mut dec)
+        );
+    }
+
+    #[test]
+    pub fn test_decode_canonical_hash_set() {
+        let ordered = vec![3u8, 0, 0, 0, 1, 2, 3];
+        let shuffled = vec![3u8, 0, 0, 0, 1, 3, 2];
+        let expected = HashSet::from([1u8, 2u8, 3u8]);
+
+        let mut dec = Decoder::no_static_info(&shuffled);
+        assert_eq!(Ok(expected.clone()), <HashSet<u8>>::decode(&mut dec));
+
+        let mut dec = Decoder::no_static_info(&ordered).enforce_canonical_collections(true);
+        assert_eq!(Ok(expected), <HashSet<u8>>::decode(&mut dec));
+
+        let mut dec = Decoder::no_static_info(&shuffled).enforce_canonical_collections(true);
+        assert_eq!(
+            Err(DecodeError::CustomError("Unordered map entries".to_owned())),
+            <HashSet<u8>>::decode(&mut dec)
+        );
+    }
+
+    #[test]
+    pub fn test_decode_canonical_hash_map_with_multi_byte_keys() {
+        // The little-endian bytes of 256 sort before those of 1, so the keys aren't ordered by value
+        let map = HashMap::from([(1u32, 10u8), (256u32, 20u8)]);
+        let bytes = crate::encode_no_static_info(&map);
+        let mut dec = Decoder::no_static_info(&bytes).enforce_canonical_collections(true);
+        assert_eq!(Ok(map), <HashMap<u32, u8>>::decode(&mut dec));
+
+        let map = HashMap::from([(-1i32, 10u8), (1i32, 20u8)]);
+        let bytes = crate::encode_no_static_info(&map);
+        let mut dec = Decoder::no_static_info(&bytes).enforce_canonical_collections(true);
+        assert_eq!(Ok(map), <HashMap<i32, u8>>::decode(&mut dec));
+
+        #[rustfmt::skip]
+        let shuffled = vec![
+            2, 0, 0, 0, // number of entries
+            255, 255, 255, 255, 10, // -1i32
+            1, 0, 0, 0, 20, // 1i32
+        ];
+        let mut dec = Decoder::no_static_info(&shuffled).enforce_canonical_collections(true);
+        assert_eq!(
+            Err(DecodeError::CustomError("Unordered map entries".to_owned())),
+            <HashMap<i32, u8>>::decode(&mut dec)
+        );
+    }
+
+    #[test]
+    pub fn test_decode_canonical_hash_set_with_multi_byte_elements() {
+        let set = HashSet::from([1u32, 256u32, 65536u32]);
+        let bytes = crate::encode_no_static_info(&set);
+        let mut dec = Decoder::no_static_info(&bytes).enforce_canonical_collections(true);
+        assert_eq!(Ok(set), <HashSet<u32>>::decode(&mut dec));
+
+        let set = HashSet::from([-256i32, -1i32, 0i32, 1i32]);
+        let bytes = crate::encode_no_static_info(&set);
+        let mut dec = Decoder::no_static_info(&bytes).enforce_canonical_collections(true);
+        assert_eq!(Ok(set), <HashSet<i32>>::decode(&mut dec));
+    }
+
+    #[derive(Debug, PartialEq, crate::TypeId, crate::Encode, crate::Decode, crate::Describe)]
+    #[sbor(tagged)]
+    enum TaggedEnum {
+        A {
+            #[sbor(tag = 1)]
+            x: u32,
+            #[sbor(tag = 2)]
+            y: String,
+        },
+        B(#[sbor(tag = 1)] u32, #[sbor(skip)] u32),
+        C,
+    }
+
+    /// `TaggedEnum` with the fields of `A` reordered and a new field added.
+    #[derive(Debug, PartialEq, crate::TypeId, crate::Encode, crate::Decode)]
+    #[sbor(tagged)]
+    enum TaggedEnumV2 {
+        A {
+            #[sbor(tag = 2)]
+            y: String,
+            #[sbor(tag = 3)]
+            z: bool,
+            #[sbor(tag = 1)]
+            x: u32,
+        },
+        B(#[sbor(tag = 1)] u32, #[sbor(skip)] u32),
+        C,
+    }
+
+    #[test]
+    pub fn test_tagged_enum_round_trip() {
+        let a = TaggedEnum::A {
+            x: 1,
+            y: "a".to_owned(),
+        };
+        let bytes = crate::encode_with_static_info(&a);
+        #[rustfmt::skip]
+        assert_eq!(
+            vec![
+                17, // enum type
+                1, 0, 0, 0, // string size
+                65, // "A"
+                4, 0, 0, 0, // number of tags and fields
+                7, 1, // tag
+                9, 1, 0, 0, 0, // field value
+                7, 2, // tag
+                12, 1, 0, 0, 0, 97, // field value
+            ],
+            bytes
+        );
+        assert_eq!(Ok(a), crate::decode_with_static_info(&bytes));
+
+        let b = TaggedEnum::B(3, 4);
+        let bytes = crate::encode_with_static_info(&b);
+        assert_eq!(
+            Ok(TaggedEnum::B(3, 0)),
+            crate::decode_with_static_info(&bytes)
+        );
+
+        let bytes = crate::encode_with_static_info(&TaggedEnum::C);
+        assert_eq!(Ok(TaggedEnum::C), crate::decode_with_static_info(&bytes));
+
+        let a = TaggedEnum::A {
+            x: 1,
+            y: "a".to_owned(),
+        };
+        let bytes = crate::encode_no_static_info(&a);
+        assert_eq!(Ok(a), crate::decode_no_static_info(&bytes));
+    }
+
+    #[test]
+    pub fn test_tagged_enum_skips_unknown_tag() {
+        let v2 = TaggedEnumV2::A {
+            y: "a".to_owned(),
+            z: true,
+            x: 1,
+        };
+        let bytes = crate::encode_with_static_info(&v2);
+        assert_eq!(
+            Ok(TaggedEnum::A {
+                x: 1,
+                y: "a".to_owned()
+            }),
+            crate::decode_with_static_info(&bytes)
+        );
+    }
+
+    #[test]
+    pub fn test_tagged_enum_unknown_tag_no_static_info() {
+        let v2 = TaggedEnumV2::A {
+            y: "a".to_owned(),
+            z: true,
+            x: 1,
+        };
+        let bytes = crate::encode_no_static_info(&v2);
+        assert_eq!(
+            Err(DecodeError::NoStaticInfo),
+            crate::decode_no_static_info::<TaggedEnum>(&bytes)
+        );
+    }
+
+    #[test]
+    pub fn test_tagged_enum_missing_tag() {
+        let v1 = TaggedEnum::A {
+            x: 1,
+            y: "a".to_owned(),
+        };
+        let bytes = crate::encode_with_static_info(&v1);
+        assert_eq!(
+            Err(DecodeError::MissingTag(3)),
+            crate::decode_with_static_info::<TaggedEnumV2>(&bytes)
+        );
+    }
+
+    #[test]
+    pub fn test_tagged_enum_duplicate_tag() {
+        let mut bytes = Vec::new();
+        let mut enc = Encoder::with_static_info(&mut bytes);
+        enc.write_type_id(TYPE_ENUM);
+        enc.write_variant_label("A");
+        enc.write_dynamic_size(6);
+        1u8.encode(&mut enc);
+        1u32.encode(&mut enc);
+        2u8.encode(&mut enc);
+        "a".to_owned().encode(&mut enc);
+        1u8.encode(&mut enc);
+        2u32.encode(&mut enc);
+        assert_eq!(
+            Err(DecodeError::DuplicateTag(1)),
+            crate::decode_with_static_info::<TaggedEnum>(&bytes)
+        );
+    }
+
+    #[test]
+    pub fn test_tagged_enum_matches_description() {
+        let a = TaggedEnum::A {
+            x: 1,
+            y: "a".to_owned(),
+        };
+        let value = crate::decode_any(&crate::encode_with_static_info(&a)).unwrap();
+        assert!(TaggedEnum::describe().matches(&value));
+
+        let v2 = TaggedEnumV2::A {
+            y: "a".to_owned(),
+            z: true,
+            x: 1,
+        };
+        let value = crate::decode_any(&crate::encode_with_static_info(&v2)).unwrap();
+        assert!(TaggedEnum::describe().matches(&value));
+
+        // `x` is described as a `u32`
+        let value = crate::Value::Enum {
+            name: "A".to_owned(),
+            fields: vec![
+                crate::Value::U8 { value: 1 },
+                crate::Value::String {
+                    value: "a".to_owned(),
+                },
+                crate::Value::U8 { value: 2 },
+                crate::Value::String {
+                    value: "a".to_owned(),
+                },
+            ],
+        };
+        assert!(!TaggedEnum::describe().matches(&value));
+    }
 }
@@ -27,6 +27,9 @@ pub enum Type {
     U64,
     U128,
     String,
+    Char,
+    I256,
+    U256,
 
     Option {
         value: Box<Type>,
@@ -102,6 +105,9 @@ impl Type {
             Type::U64 => matches!(value, Value::U64 { .. }),
             Type::U128 => matches!(value, Value::U128 { .. }),
             Type::String => matches!(value, Value::String { .. }),
+            Type::Char => matches!(value, Value::Char { .. }),
+            Type::I256 => matches!(value, Value::I256 { .. }),
+            Type::U256 => matches!(value, Value::U256 { .. }),
             Type::Option { value: type_value } => {
                 if let Value::Option { value } = value {
                     match &**value {
@@ -252,6 +258,7 @@ impl Type {
                                     .enumerate()
                                     .all(|(i, (_, e))| e.matches(fields.get(i).unwrap()))
                         }
+                        Fields::Tagged { tagged } => matches_tagged(tagged, fields),
                     }
                 } else {
                     false
@@ -280,6 +287,7 @@ impl Type {
                                             .enumerate()
                                             .all(|(i, (_, e))| e.matches(fields.get(i).unwrap()))
                                 }
+                                Fields::Tagged { tagged } => matches_tagged(tagged, fields),
                             };
                         }
                     }
@@ -304,6 +312,29 @@ impl Type {
     }
 }
 
+/// Checks that `fields` are tag and value pairs, with every described tag present exactly once.
+///
+/// Values under unknown tags are accepted, as decoders skip them.
+fn matches_tagged(tagged: &[(u8, Type)], fields: &[Value]) -> bool {
+    let mut seen = Vec::new();
+    for i in (0..fields.len()).step_by(2) {
+        let (tag, value) = match (&fields[i], fields.get(i + 1)) {
+            (Value::U8 { value: tag }, Some(value)) => (*tag, value),
+            _ => return false,
+        };
+        if seen.contains(&tag) {
+            return false;
+        }
+        seen.push(tag);
+        if let Some((_, ty)) = tagged.iter().find(|(t, _)| *t == tag) {
+            if !ty.matches(value) {
+                return false;
+            }
+        }
+    }
+    tagged.iter().all(|(t, _)| seen.contains(t))
+}
+
 /// Represents the type info of an enum variant.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq, TypeId, Decode, Encode)]
@@ -320,9 +351,19 @@ pub struct Variant {
 )]
 #[derive(Debug, Clone, PartialEq, Eq, TypeId, Decode, Encode)]
 pub enum Fields {
-    Named { named: Vec<(String, Type)> },
+    Named {
+        named: Vec<(String, Type)>,
+    },
 
-    Unnamed { unnamed: Vec<Type> },
+    Unnamed {
+        unnamed: Vec<Type>,
+    },
+
+    /// The fields of a `#[sbor(tagged)]` enum variant, each encoded as its tag followed by
+    /// its value.
+    Tagged {
+        tagged: Vec<(u8, Type)>,
+    },
 
     Unit,
 }
@@ -365,6 +406,7 @@ describe_basic_type!(usize, Type::U64);
 
 describe_basic_type!(str, Type::String);
 describe_basic_type!(String, Type::String);
+describe_basic_type!(char, Type::Char);
 
 impl<T: Describe> Describe for Option<T> {
     fn describe() -> Type {
@@ -486,6 +528,7 @@ mod tests {
         assert_eq!(Type::U64, u64::describe());
         assert_eq!(Type::U128, u128::describe());
         assert_eq!(Type::String, String::describe());
+        assert_eq!(Type::Char, char::describe());
     }
 
     #[test]
@@ -19,20 +19,55 @@ pub trait Encode {
     fn encode_value(&self, encoder: &mut Encoder);
 }
 
+/// Where an `Encoder` writes to.
+enum Output<'a> {
+    Buffer(&'a mut Vec<u8>),
+    /// Only counts the bytes written, for measuring the encoded length.
+    Length(usize),
+}
+
 /// An `Encoder` abstracts the logic for writing core types into a byte buffer.
 pub struct Encoder<'a> {
-    buf: &'a mut Vec<u8>,
+    output: Output<'a>,
     with_static_info: bool,
 }
 
 impl<'a> Encoder<'a> {
     pub fn new(buf: &'a mut Vec<u8>, with_static_info: bool) -> Self {
         Self {
-            buf,
+            output: Output::Buffer(buf),
+            with_static_info,
+        }
+    }
+
+    /// Creates an encoder which doesn't write anything but counts the number of bytes
+    /// it would write.
+    pub fn measuring(with_static_info: bool) -> Encoder<'static> {
+        Encoder {
+            output: Output::Length(0),
             with_static_info,
         }
     }
 
+    /// Returns the number of bytes in the output.
+    pub fn len(&self) -> usize {
+        match &self.output {
+            Output::Buffer(buf) => buf.len(),
+            Output::Length(len) => *len,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Creates an encoder which reuses the given buffer, discarding its existing content
+    /// but keeping its allocation.
+    pub fn from_buffer(buf: &'a mut Vec<u8>, with_static_info: bool) -> Self {
+        buf.clear();
+        Self::new(buf, with_static_info)
+    }
+
     pub fn with_static_info(buf: &'a mut Vec<u8>) -> Self {
         Self::new(buf, true)
     }
@@ -44,7 +79,7 @@ impl<'a> Encoder<'a> {
     pub fn write_type_id(&mut self, ty: u8) {
         // May use compile-time feature flag, instead of runtime check, for performance.
         if self.with_static_info {
-            self.buf.push(ty);
+            self.write_byte(ty);
         }
     }
 
@@ -60,25 +95,50 @@ impl<'a> Encoder<'a> {
     pub fn write_static_size(&mut self, len: usize) {
         // May use compile-time feature flag, instead of runtime check, for performance.
         if self.with_static_info {
-            self.buf.extend(&(len as u32).to_le_bytes());
+            self.write_slice(&(len as u32).to_le_bytes());
         }
     }
 
     pub fn write_dynamic_size(&mut self, len: usize) {
-        self.buf.extend(&(len as u32).to_le_bytes());
+        self.write_slice(&(len as u32).to_le_bytes());
     }
 
     pub fn write_byte(&mut self, n: u8) {
-        self.buf.push(n);
+        match &mut self.output {
+            Output::Buffer(buf) => buf.push(n),
+            Output::Length(len) => *len += 1,
+        }
     }
 
     pub fn write_slice(&mut self, slice: &[u8]) {
-        self.buf.extend(slice);
+        match &mut self.output {
+            Output::Buffer(buf) => buf.extend(slice),
+            Output::Length(len) => *len += slice.len(),
+        }
+    }
+
+    /// Writes a slice of a known length, which is only produced if the bytes are needed.
+    pub fn write_slice_with<F: FnOnce() -> Vec<u8>>(&mut self, n: usize, slice: F) {
+        match &mut self.output {
+            Output::Buffer(buf) => {
+                let bytes = slice();
+                debug_assert_eq!(bytes.len(), n);
+                buf.extend(bytes);
+            }
+            Output::Length(len) => *len += n,
+        }
     }
 
     pub fn encode<T: Encode + ?Sized>(&mut self, value: &T) {
         value.encode(self)
     }
+
+    /// Encodes the value on its own, with the same settings, for writing it later.
+    fn encode_value_to_vec<T: Encode + ?Sized>(&self, value: &T) -> Vec<u8> {
+        let mut buf = Vec::new();
+        value.encode_value(&mut Encoder::new(&mut buf, self.with_static_info));
+        buf
+    }
 }
 
 impl Encode for () {
@@ -206,6 +266,17 @@ impl Encode for String {
     }
 }
 
+impl Encode for char {
+    #[inline]
+    fn encode_type_id(encoder: &mut Encoder) {
+        encoder.write_type_id(Self::type_id());
+    }
+    #[inline]
+    fn encode_value(&self, encoder: &mut Encoder) {
+        (*self as u32).encode_value(encoder);
+    }
+}
+
 impl<T: Encode + TypeId> Encode for Option<T> {
     #[inline]
     fn encode_type_id(encoder: &mut Encoder) {
@@ -385,10 +456,15 @@ impl<T: Encode + TypeId + Ord + Hash> Encode for HashSet<T> {
     fn encode_value(&self, encoder: &mut Encoder) {
         encoder.write_type_id(T::type_id());
         encoder.write_dynamic_size(self.len());
-        // Encode elements based on the order defined on the key type to generate deterministic bytes.
-        let values: BTreeSet<&T> = self.iter().collect();
+        // Encode elements in the order of their encoded bytes to generate deterministic bytes,
+        // which a decoder can check without knowing the element type.
+        let mut values: Vec<Vec<u8>> = self
+            .iter()
+            .map(|v| encoder.encode_value_to_vec(v))
+            .collect();
+        values.sort();
         for v in values {
-            v.encode_value(encoder);
+            encoder.write_slice(&v);
         }
     }
 }
@@ -403,11 +479,16 @@ impl<K: Encode + TypeId + Ord + Hash, V: Encode + TypeId> Encode for HashMap<K,
         encoder.write_type_id(K::type_id());
         encoder.write_type_id(V::type_id());
         encoder.write_dynamic_size(self.len());
-        // Encode elements based on the order defined on the key type to generate deterministic bytes.
-        let keys: BTreeSet<&K> = self.keys().collect();
-        for key in keys {
-            key.encode_value(encoder);
-            self.get(key).unwrap().encode_value(encoder);
+        // Encode entries in the order of their encoded keys to generate deterministic bytes,
+        // which a decoder can check without knowing the key type.
+        let mut entries: Vec<(Vec<u8>, &V)> = self
+            .iter()
+            .map(|(key, value)| (encoder.encode_value_to_vec(key), value))
+            .collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        for (key, value) in entries {
+            encoder.write_slice(&key);
+            value.encode_value(encoder);
         }
     }
 }
@@ -522,6 +603,47 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn test_measuring() {
+        for with_static_info in [true, false] {
+            let mut bytes = Vec::with_capacity(512);
+            let mut enc = Encoder::new(&mut bytes, with_static_info);
+            do_encoding(&mut enc);
+
+            let mut measuring = Encoder::measuring(with_static_info);
+            do_encoding(&mut measuring);
+            assert_eq!(measuring.len(), bytes.len());
+        }
+    }
+
+    #[test]
+    pub fn test_encoded_len() {
+        fn check<T: Encode + ?Sized>(v: &T) {
+            assert_eq!(
+                crate::encoded_len(v),
+                crate::encode_with_static_info(v).len()
+            );
+        }
+
+        check(&());
+        check(&true);
+        check(&1u8);
+        check(&-1i64);
+        check(&u128::MAX);
+        check(&'a');
+        check("");
+        check("hello");
+        check(&String::from("hello world"));
+        check(&vec![1u8; 100]);
+        check(&vec![String::from("a"), String::from("bc")]);
+        check(&(1u32, vec![Some(vec![1u16, 2u16]), None], ("x", [1i8; 3])));
+
+        let mut map = BTreeMap::<String, Vec<u64>>::new();
+        map.insert("a".to_owned(), vec![1, 2]);
+        map.insert("b".to_owned(), vec![]);
+        check(&map);
+    }
+
     #[test]
     pub fn test_encode_box() {
         let x = Box::new(5u8);
@@ -21,7 +21,7 @@ pub mod rust;
 pub mod type_id;
 mod utils;
 
-pub use any::{decode_any, encode_any, encode_any_with_buffer, Value};
+pub use any::{decode_any, decode_any_with_schema, encode_any, encode_any_with_buffer, Value};
 pub use decode::{Decode, DecodeError, Decoder};
 pub use describe::{Describe, Type};
 pub use encode::{Encode, Encoder};
@@ -17,6 +17,8 @@ pub use alloc::string;
 #[cfg(feature = "alloc")]
 pub use alloc::vec;
 #[cfg(feature = "alloc")]
+pub use core::any;
+#[cfg(feature = "alloc")]
 pub use core::cell;
 #[cfg(feature = "alloc")]
 pub use core::cmp;
@@ -37,6 +39,8 @@ pub use core::ptr;
 #[cfg(feature = "alloc")]
 pub use core::slice;
 
+#[cfg(not(feature = "alloc"))]
+pub use std::any;
 #[cfg(not(feature = "alloc"))]
 pub use std::borrow;
 #[cfg(not(feature = "alloc"))]
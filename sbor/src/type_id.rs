@@ -19,6 +19,9 @@ pub const TYPE_U32: u8 = 0x09;
 pub const TYPE_U64: u8 = 0x0a;
 pub const TYPE_U128: u8 = 0x0b;
 pub const TYPE_STRING: u8 = 0x0c;
+pub const TYPE_CHAR: u8 = 0x0d;
+pub const TYPE_I256: u8 = 0x0e; // 32 little-endian bytes
+pub const TYPE_U256: u8 = 0x0f; // 32 little-endian bytes
 
 // struct and enum
 pub const TYPE_STRUCT: u8 = 0x10;
@@ -131,6 +134,13 @@ impl TypeId for String {
     }
 }
 
+impl TypeId for char {
+    #[inline]
+    fn type_id() -> u8 {
+        TYPE_CHAR
+    }
+}
+
 impl<T> TypeId for Option<T> {
     #[inline]
     fn type_id() -> u8 {
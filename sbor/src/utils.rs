@@ -4,11 +4,25 @@ use crate::{Decode, DecodeError, Decoder, Encode, Encoder};
 /// Encode a `T` into byte array, with type info included.
 pub fn encode_with_static_info<T: Encode + ?Sized>(v: &T) -> Vec<u8> {
     let mut buf = Vec::with_capacity(512);
-    let mut enc = Encoder::with_static_info(&mut buf);
-    v.encode(&mut enc);
+    encode_with_static_info_into(v, &mut buf);
     buf
 }
 
+/// Encode a `T` into the given buffer, with type info included.
+///
+/// The buffer is cleared first, so its allocation can be reused across calls.
+pub fn encode_with_static_info_into<T: Encode + ?Sized>(v: &T, buf: &mut Vec<u8>) {
+    let mut enc = Encoder::from_buffer(buf, true);
+    v.encode(&mut enc);
+}
+
+/// Returns the length of a `T` encoded with type info, without encoding it into a buffer.
+pub fn encoded_len<T: Encode + ?Sized>(v: &T) -> usize {
+    let mut enc = Encoder::measuring(true);
+    v.encode(&mut enc);
+    enc.len()
+}
+
 /// Encode a `T` into byte array, with no type info.
 pub fn encode_no_static_info<T: Encode + ?Sized>(v: &T) -> Vec<u8> {
     let mut buf = Vec::with_capacity(512);
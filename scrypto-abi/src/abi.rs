@@ -6,7 +6,7 @@ use alloc::string::String;
 use alloc::vec::Vec;
 
 use sbor::describe::*;
-use sbor::{Decode, Encode, TypeId};
+use sbor::{decode_any, Decode, Encode, TypeId, Value};
 
 /// Represents a blueprint.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -22,6 +22,12 @@ pub struct Blueprint {
 pub struct BlueprintAbi {
     pub structure: Type,
     pub fns: Vec<Fn>,
+    /// The schemas of the events this blueprint may emit.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub events: Vec<Type>,
+    /// The schemas of the errors this blueprint may return.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub errors: Vec<Type>,
 }
 
 impl BlueprintAbi {
@@ -37,6 +43,21 @@ impl BlueprintAbi {
     pub fn contains_fn(&self, fn_ident: &str) -> bool {
         self.get_fn_abi(fn_ident).is_some()
     }
+
+    /// Returns the signatures of all methods, skipping functions which take no `self`.
+    pub fn method_signatures(&self) -> Vec<MethodSignature> {
+        self.fns
+            .iter()
+            .filter_map(|func| {
+                func.mutability.as_ref().map(|mutability| MethodSignature {
+                    ident: func.ident.clone(),
+                    mutability: mutability.clone(),
+                    input: func.input.clone(),
+                    output: func.output.clone(),
+                })
+            })
+            .collect()
+    }
 }
 
 /// Represents a method/function.
@@ -48,6 +69,51 @@ pub struct Fn {
     pub input: Type,
     pub output: Type,
     pub export_name: String,
+    /// The encoded default values of the trailing inputs callers may omit, in order.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub input_defaults: Vec<Vec<u8>>,
+    /// The encoded access rule callers must satisfy, if the method declares one with `#[auth]`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub auth: Option<Vec<u8>>,
+}
+
+impl Fn {
+    /// Completes call arguments which omit some of the trailing optional inputs with
+    /// their default values.
+    ///
+    /// Returns `None` if no input is omitted or the omitted inputs have no defaults.
+    pub fn fill_input_defaults(&self, args: &Value) -> Option<Value> {
+        let (named, fields) = match (&self.input, args) {
+            (
+                Type::Struct {
+                    fields: Fields::Named { named },
+                    ..
+                },
+                Value::Struct { fields },
+            ) => (named, fields),
+            _ => return None,
+        };
+        let omitted = named.len().checked_sub(fields.len())?;
+        if omitted == 0 || omitted > self.input_defaults.len() {
+            return None;
+        }
+
+        let mut fields = fields.clone();
+        for default in &self.input_defaults[self.input_defaults.len() - omitted..] {
+            fields.push(decode_any(default).ok()?);
+        }
+        Some(Value::Struct { fields })
+    }
+}
+
+/// Represents the signature of a component method.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, TypeId, Encode, Decode, PartialEq, Eq)]
+pub struct MethodSignature {
+    pub ident: String,
+    pub mutability: SelfMutability,
+    pub input: Type,
+    pub output: Type,
 }
 
 /// Whether a method is going to change the component state.
@@ -5,6 +5,20 @@ use sbor::rust::string::String;
 #[macro_export]
 macro_rules! scrypto_type {
     ($t:ty, $ct:expr, $generics: expr) => {
+        $crate::scrypto_type!(@impl $t, $ct, $generics, |self, encoder| {
+            let bytes = self.to_vec();
+            encoder.write_dynamic_size(bytes.len());
+            encoder.write_slice(&bytes);
+        });
+    };
+    // For types whose encoding always has the given length.
+    ($t:ty, $ct:expr, $generics: expr, $len: expr) => {
+        $crate::scrypto_type!(@impl $t, $ct, $generics, |self, encoder| {
+            encoder.write_dynamic_size($len);
+            encoder.write_slice_with($len, || self.to_vec());
+        });
+    };
+    (@impl $t:ty, $ct:expr, $generics: expr, |$self:ident, $encoder:ident| $encode_value:block) => {
         impl TypeId for $t {
             #[inline]
             fn type_id() -> u8 {
@@ -18,11 +32,7 @@ macro_rules! scrypto_type {
                 encoder.write_type_id(Self::type_id());
             }
             #[inline]
-            fn encode_value(&self, encoder: &mut Encoder) {
-                let bytes = self.to_vec();
-                encoder.write_dynamic_size(bytes.len());
-                encoder.write_slice(&bytes);
-            }
+            fn encode_value(&$self, $encoder: &mut Encoder) $encode_value
         }
 
         impl Decode for $t {
@@ -1,4 +1,4 @@
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Group, Ident, Literal, TokenStream, TokenTree};
 use quote::{format_ident, quote};
 use syn::parse::Parser;
 use syn::spanned::Spanned;
@@ -24,6 +24,7 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
     let bp_impl = &bp.implementation;
     let bp_ident = &bp_strut.ident;
     let bp_items = &bp_impl.items;
+    let bp_impl_items = strip_macro_attrs(bp_items)?;
     let bp_name = bp_ident.to_string();
     trace!("Blueprint name: {}", bp_name);
 
@@ -54,7 +55,7 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
             pub struct #bp_ident #bp_fields #bp_semi_token
 
             impl #bp_ident {
-                #(#bp_items)*
+                #(#bp_impl_items)*
             }
 
             impl ::scrypto::component::ComponentState<#value_ident> for #bp_ident {
@@ -86,7 +87,9 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
     #[cfg(not(feature = "no-abi-gen"))]
     let output_abi = {
         let abi_ident = format_ident!("{}_abi", bp_ident);
-        let abi_functions = generate_abi(bp_ident, bp_items)?;
+        let abi_functions = generate_abi(bp_ident, bp_fields, bp_items)?;
+        let abi_events = parse_type_list_attr(&bp_strut.attrs, "events")?;
+        let abi_errors = parse_type_list_attr(&bp_strut.attrs, "errors")?;
         quote! {
             #[no_mangle]
             pub extern "C" fn #abi_ident(input: *mut u8) -> *mut u8 {
@@ -98,9 +101,13 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
 
                 let fns: Vec<Fn> = vec![ #(#abi_functions),* ];
                 let structure: Type = #module_ident::#bp_ident::describe();
+                let events: Vec<Type> = vec![ #(<#abi_events>::describe()),* ];
+                let errors: Vec<Type> = vec![ #(<#abi_errors>::describe()),* ];
                 let output = BlueprintAbi {
                     structure,
                     fns,
+                    events,
+                    errors,
                 };
 
                 ::scrypto::buffer::scrypto_encode_to_buffer(&output)
@@ -287,8 +294,12 @@ fn generate_dispatcher(
 
 // Parses function items of an `Impl` and returns ABI of functions.
 #[allow(dead_code)]
-fn generate_abi(bp_ident: &Ident, items: &[ImplItem]) -> Result<Vec<Expr>> {
+fn generate_abi(bp_ident: &Ident, bp_fields: &Fields, items: &[ImplItem]) -> Result<Vec<Expr>> {
     let mut fns = Vec::<Expr>::new();
+    let field_names: Vec<String> = bp_fields
+        .iter()
+        .filter_map(|f| f.ident.as_ref().map(|i| i.to_string()))
+        .collect();
 
     for item in items {
         trace!("Processing item: {}", quote! { #item });
@@ -339,6 +350,28 @@ fn generate_abi(bp_ident: &Ident, items: &[ImplItem]) -> Result<Vec<Expr>> {
                         }
                     };
                     let export_name = format!("{}_{}", bp_ident, m.sig.ident);
+                    let default_types: Vec<Type> = default_input_types(m)?
+                        .iter()
+                        .map(|ty| replace_self_with(ty, &bp_ident.to_string()))
+                        .collect();
+                    let input_defaults = quote! {
+                        vec![ #(::scrypto::buffer::scrypto_encode(&<#default_types>::default())),* ]
+                    };
+                    let auth = match auth_rule(m)? {
+                        Some(rule) => {
+                            if mutability.is_none() {
+                                return Err(Error::new(
+                                    m.sig.span(),
+                                    "Only methods can have an `#[auth]`",
+                                ));
+                            }
+                            let rule = replace_fields_with_paths(rule, &field_names);
+                            quote! {
+                                Option::Some(::scrypto::buffer::scrypto_encode(&::scrypto::rule!(#rule)))
+                            }
+                        }
+                        None => quote! { Option::None },
+                    };
 
                     if mutability.is_none() {
                         fns.push(parse_quote! {
@@ -348,6 +381,8 @@ fn generate_abi(bp_ident: &Ident, items: &[ImplItem]) -> Result<Vec<Expr>> {
                                 input: #input,
                                 output: #output,
                                 export_name: #export_name.to_string(),
+                                input_defaults: #input_defaults,
+                                auth: #auth,
                             }
                         });
                     } else {
@@ -358,6 +393,8 @@ fn generate_abi(bp_ident: &Ident, items: &[ImplItem]) -> Result<Vec<Expr>> {
                                 input: #input,
                                 output: #output,
                                 export_name: #export_name.to_string(),
+                                input_defaults: #input_defaults,
+                                auth: #auth,
                             }
                         });
                     }
@@ -375,6 +412,103 @@ fn generate_abi(bp_ident: &Ident, items: &[ImplItem]) -> Result<Vec<Expr>> {
     Ok(fns)
 }
 
+// Returns the types of the trailing method inputs marked with `#[default]`.
+fn default_input_types(method: &ImplItemMethod) -> Result<Vec<Type>> {
+    let mut types = Vec::new();
+
+    for input in &method.sig.inputs {
+        if let FnArg::Typed(ref t) = input {
+            if t.attrs.iter().any(|attr| attr.path.is_ident("default")) {
+                types.push(t.ty.as_ref().clone());
+            } else if !types.is_empty() {
+                return Err(Error::new(
+                    t.span(),
+                    "Only trailing inputs can have a `#[default]`",
+                ));
+            }
+        }
+    }
+
+    Ok(types)
+}
+
+// Returns the access rule tokens of the `#[auth(..)]` attribute of a method, if any.
+fn auth_rule(method: &ImplItemMethod) -> Result<Option<TokenStream>> {
+    let mut rules = method
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("auth"));
+    let rule = match rules.next() {
+        Some(attr) => attr.parse_args::<TokenStream>()?,
+        None => return Ok(None),
+    };
+    if let Some(attr) = rules.next() {
+        return Err(Error::new(attr.span(), "Only one `#[auth]` is allowed"));
+    }
+    Ok(Some(rule))
+}
+
+// Replaces the blueprint field names in an access rule with schema paths, so that the rule
+// refers to the component state when checked by the engine.
+fn replace_fields_with_paths(rule: TokenStream, field_names: &[String]) -> TokenStream {
+    rule.into_iter()
+        .map(|tt| match tt {
+            TokenTree::Ident(ref ident) if field_names.contains(&ident.to_string()) => {
+                let mut literal = Literal::string(&ident.to_string());
+                literal.set_span(ident.span());
+                TokenTree::Literal(literal)
+            }
+            TokenTree::Group(ref group) => {
+                let mut replaced = Group::new(
+                    group.delimiter(),
+                    replace_fields_with_paths(group.stream(), field_names),
+                );
+                replaced.set_span(group.span());
+                TokenTree::Group(replaced)
+            }
+            other => other,
+        })
+        .collect()
+}
+
+// Removes the `#[default]` and `#[auth]` attributes, which are only understood by this macro.
+fn strip_macro_attrs(items: &[ImplItem]) -> Result<Vec<ImplItem>> {
+    let mut stripped = Vec::new();
+
+    for item in items {
+        let mut item = item.clone();
+        if let ImplItem::Method(ref mut m) = item {
+            default_input_types(m)?;
+            auth_rule(m)?;
+            m.attrs.retain(|attr| !attr.path.is_ident("auth"));
+            for input in m.sig.inputs.iter_mut() {
+                if let FnArg::Typed(ref mut t) = input {
+                    t.attrs.retain(|attr| !attr.path.is_ident("default"));
+                }
+            }
+        }
+        stripped.push(item);
+    }
+
+    Ok(stripped)
+}
+
+// Parses `#[<name>(A, B, ..)]` attributes on the blueprint struct and returns the listed types.
+#[allow(dead_code)]
+fn parse_type_list_attr(attrs: &[Attribute], name: &str) -> Result<Vec<Type>> {
+    let mut types = Vec::new();
+
+    for attr in attrs {
+        if attr.path.is_ident(name) {
+            let list =
+                attr.parse_args_with(punctuated::Punctuated::<Type, Token![,]>::parse_terminated)?;
+            types.extend(list);
+        }
+    }
+
+    Ok(types)
+}
+
 // Parses function items of an `Impl` and returns ABI of functions.
 fn generate_stubs(
     value_ident: &Ident,
@@ -486,6 +620,10 @@ fn generate_stubs(
                 self.component.add_access_check(access_rules);
                 self
             }
+            fn restrict_resources(&mut self, resource_addresses: ::sbor::rust::collections::BTreeSet<::scrypto::resource::ResourceAddress>) -> &mut Self {
+                self.component.restrict_resources(resource_addresses);
+                self
+            }
             fn globalize(self) -> ComponentAddress {
                 self.component.globalize()
             }
@@ -636,6 +774,8 @@ mod tests {
                             input: Test_x_Input::describe(),
                             output: <u32>::describe(),
                             export_name: "Test_x".to_string(),
+                            input_defaults: vec![],
+                            auth: Option::None,
                         },
                         ::scrypto::abi::Fn {
                             ident: "y".to_owned(),
@@ -643,12 +783,18 @@ mod tests {
                             input: Test_y_Input::describe(),
                             output: <u32>::describe(),
                             export_name: "Test_y".to_string(),
+                            input_defaults: vec![],
+                            auth: Option::None,
                         }
                     ];
                     let structure: Type = Test_impl::Test::describe();
+                    let events: Vec<Type> = vec![];
+                    let errors: Vec<Type> = vec![];
                     let output = BlueprintAbi {
                         structure,
                         fns,
+                        events,
+                        errors,
                     };
                     ::scrypto::buffer::scrypto_encode_to_buffer(&output)
                 }
@@ -678,6 +824,10 @@ mod tests {
                         self.component.add_access_check(access_rules);
                         self
                     }
+                    fn restrict_resources(&mut self, resource_addresses: ::sbor::rust::collections::BTreeSet<::scrypto::resource::ResourceAddress>) -> &mut Self {
+                        self.component.restrict_resources(resource_addresses);
+                        self
+                    }
                     fn globalize(self) -> ComponentAddress {
                         self.component.globalize()
                     }
@@ -736,9 +886,13 @@ mod tests {
                     use ::sbor::rust::vec::Vec;
                     let fns: Vec<Fn> = vec![];
                     let structure: Type = Test_impl::Test::describe();
+                    let events: Vec<Type> = vec![];
+                    let errors: Vec<Type> = vec![];
                     let output = BlueprintAbi {
                         structure,
                         fns,
+                        events,
+                        errors,
                     };
                     ::scrypto::buffer::scrypto_encode_to_buffer(&output)
                 }
@@ -768,6 +922,10 @@ mod tests {
                         self.component.add_access_check(access_rules);
                         self
                     }
+                    fn restrict_resources(&mut self, resource_addresses: ::sbor::rust::collections::BTreeSet<::scrypto::resource::ResourceAddress>) -> &mut Self {
+                        self.component.restrict_resources(resource_addresses);
+                        self
+                    }
                     fn globalize(self) -> ComponentAddress {
                         self.component.globalize()
                     }
@@ -136,6 +136,9 @@ fn get_native_type(ty: &des::Type) -> Result<(Type, Vec<Item>)> {
         des::Type::U64 => parse_quote! { u64 },
         des::Type::U128 => parse_quote! { u128 },
         des::Type::String => parse_quote! { String },
+        des::Type::Char => parse_quote! { char },
+        des::Type::I256 => parse_quote! { ::scrypto::math::I256 },
+        des::Type::U256 => parse_quote! { ::scrypto::math::U256 },
         // struct & enum
         des::Type::Struct { name, fields } => {
             let ident = format_ident!("{}", name);
@@ -177,6 +180,12 @@ fn get_native_type(ty: &des::Type) -> Result<(Type, Vec<Item>)> {
                         pub struct #ident;
                     });
                 }
+                des::Fields::Tagged { .. } => {
+                    return Err(Error::new(
+                        Span::call_site(),
+                        format!("Struct with tagged fields: {}", name),
+                    ));
+                }
             }
 
             parse_quote! { #ident }
@@ -184,6 +193,7 @@ fn get_native_type(ty: &des::Type) -> Result<(Type, Vec<Item>)> {
         des::Type::Enum { name, variants } => {
             let ident = format_ident!("{}", name);
             let mut native_variants = Vec::<Variant>::new();
+            let mut tagged = false;
 
             for variant in variants {
                 let v_ident = format_ident!("{}", variant.name);
@@ -215,6 +225,20 @@ fn get_native_type(ty: &des::Type) -> Result<(Type, Vec<Item>)> {
                             #v_ident ( #(#types),* )
                         });
                     }
+                    des::Fields::Tagged { tagged: fields } => {
+                        tagged = true;
+                        let mut tags: Vec<u8> = vec![];
+                        let mut types: Vec<Type> = vec![];
+                        for (t, v) in fields {
+                            tags.push(*t);
+                            let (new_type, new_structs) = get_native_type(v)?;
+                            types.push(new_type);
+                            structs.extend(new_structs);
+                        }
+                        native_variants.push(parse_quote! {
+                            #v_ident ( #(#[sbor(tag = #tags)] #types),* )
+                        });
+                    }
                     des::Fields::Unit => {
                         native_variants.push(parse_quote! {
                             #v_ident
@@ -223,8 +247,14 @@ fn get_native_type(ty: &des::Type) -> Result<(Type, Vec<Item>)> {
                 };
             }
 
+            let tagged_attr = if tagged {
+                quote! { #[sbor(tagged)] }
+            } else {
+                quote! {}
+            };
             structs.push(parse_quote! {
                 #[derive(Debug, ::sbor::TypeId, ::sbor::Encode, ::sbor::Decode, ::sbor::Describe)]
+                #tagged_attr
                 pub enum #ident {
                     #( #native_variants ),*
                 }
@@ -16,6 +16,19 @@ use proc_macro::TokenStream;
 /// This macro will derive the dispatcher method responsible for handling invocation
 /// according to Scrypto ABI.
 ///
+/// Trailing inputs of a public function or method can be marked with `#[default]`,
+/// in which case callers may omit them and `Default::default()` is passed instead.
+///
+/// A method can declare the access rule its callers must satisfy with `#[auth(..)]`,
+/// using the `rule!` grammar, e.g. `#[auth(require(admin_badge))]`. Names of the
+/// blueprint's fields refer to their values in the component state. The rule is
+/// exported in the ABI and enforced by the engine before the method runs.
+///
+/// The struct can list the event and error types of the blueprint with `#[events(..)]`
+/// and `#[errors(..)]`, e.g. `#[events(Deposited, Withdrawn)]`. The listed types must
+/// implement `Describe`; their schemas are exported in the `events` and `errors` of the
+/// blueprint ABI.
+///
 /// # Example
 /// ```ignore
 /// use scrypto::prelude::*;
@@ -0,0 +1,51 @@
+use scrypto::prelude::*;
+
+fn badge(id: u32) -> NonFungibleAddress {
+    NonFungibleAddress::new(RADIX_TOKEN, NonFungibleId::from_u32(id))
+}
+
+#[test]
+fn test_builder_matches_rule_macro() {
+    let voters = vec![badge(1), badge(2), badge(3)];
+
+    assert_eq!(
+        AccessRuleBuilder::new()
+            .or(require_n_of(2u8, voters.clone()))
+            .build(),
+        rule!(require_n_of(2u8, voters.clone()))
+    );
+    assert_eq!(
+        AccessRuleBuilder::new()
+            .or(require(badge(1)))
+            .or(require(badge(2)))
+            .or(require(badge(3)))
+            .build(),
+        rule!(require(badge(1)) || require(badge(2)) || require(badge(3)))
+    );
+    assert_eq!(
+        AccessRuleBuilder::new()
+            .and(require(badge(1)))
+            .and(require_amount(dec!("10"), RADIX_TOKEN))
+            .build(),
+        rule!(require(badge(1)) && require_amount(dec!("10"), RADIX_TOKEN))
+    );
+}
+
+#[test]
+fn test_builder_from_runtime_list() {
+    let voters = vec![badge(1), badge(2)];
+
+    let rule = voters
+        .iter()
+        .fold(AccessRuleBuilder::new(), |builder, voter| {
+            builder.or(require(voter.clone()))
+        })
+        .build();
+
+    assert_eq!(rule, rule!(require(badge(1)) || require(badge(2))));
+}
+
+#[test]
+fn test_empty_builder_denies_all() {
+    assert_eq!(AccessRuleBuilder::new().build(), AccessRule::DenyAll);
+}
@@ -34,6 +34,49 @@ blueprint! {
     }
 }
 
+#[derive(TypeId, Encode, Decode, Describe)]
+pub struct Deposited {
+    amount: Decimal,
+}
+
+#[derive(TypeId, Encode, Decode, Describe)]
+pub enum WithdrawError {
+    InsufficientBalance,
+}
+
+blueprint! {
+    #[events(Deposited)]
+    #[errors(WithdrawError)]
+    struct WithEvents {}
+
+    impl WithEvents {
+        pub fn new() -> ComponentAddress {
+            Self {}.instantiate().globalize()
+        }
+    }
+}
+
+blueprint! {
+    struct WithDefaults {}
+
+    impl WithDefaults {
+        pub fn transfer(amount: Decimal, #[default] memo: String, #[default] fee: u32) {
+            let _ = (amount, memo, fee);
+        }
+    }
+}
+
+blueprint! {
+    struct WithAuth {
+        admin_badge: ResourceAddress,
+    }
+
+    impl WithAuth {
+        #[auth(require(admin_badge))]
+        pub fn withdraw(&mut self) {}
+    }
+}
+
 fn assert_json_eq<T: Serialize>(actual: T, expected: Value) {
     assert_eq!(to_value(&actual).unwrap(), expected);
 }
@@ -76,7 +119,9 @@ fn test_simple_abi() {
                         "type_id": 129,
                         "generics": []
                     },
-                    "export_name": "Simple_new"
+                    "export_name": "Simple_new",
+                    "input_defaults": [],
+                    "auth": null
                 },
 
                 {
@@ -93,7 +138,9 @@ fn test_simple_abi() {
                     "output": {
                         "type": "U32"
                     },
-                    "export_name": "Simple_get_state"
+                    "export_name": "Simple_get_state",
+                    "input_defaults": [],
+                    "auth": null
                 },
                 {
                     "ident": "set_state",
@@ -116,7 +163,9 @@ fn test_simple_abi() {
                     "output": {
                         "type": "Unit"
                     },
-                    "export_name": "Simple_set_state"
+                    "export_name": "Simple_set_state",
+                    "input_defaults": [],
+                    "auth": null
                 },
                 {
                     "ident": "custom_types",
@@ -176,9 +225,81 @@ fn test_simple_abi() {
                             }
                         ]
                     },
-                    "export_name": "Simple_custom_types"
+                    "export_name": "Simple_custom_types",
+                    "input_defaults": [],
+                    "auth": null
                 }
-            ]
+            ],
+            "events": [],
+            "errors": []
         }),
     );
 }
+
+#[test]
+fn test_abi_with_events_and_errors() {
+    let ptr = WithEvents_abi(core::ptr::null_mut::<u8>());
+    let abi: BlueprintAbi = scrypto_consume(ptr, |slice| scrypto_decode(slice).unwrap());
+
+    assert_json_eq(
+        abi.events,
+        json!([
+            {
+                "type": "Struct",
+                "name": "Deposited",
+                "fields": {
+                    "type": "Named",
+                    "named": [
+                        [
+                            "amount",
+                            {
+                                "type": "Custom",
+                                "type_id": 161,
+                                "generics": []
+                            }
+                        ]
+                    ]
+                }
+            }
+        ]),
+    );
+    assert_json_eq(
+        abi.errors,
+        json!([
+            {
+                "type": "Enum",
+                "name": "WithdrawError",
+                "variants": [
+                    {
+                        "name": "InsufficientBalance",
+                        "fields": {
+                            "type": "Unit"
+                        }
+                    }
+                ]
+            }
+        ]),
+    );
+}
+
+#[test]
+fn test_abi_with_defaulted_inputs() {
+    let ptr = WithDefaults_abi(core::ptr::null_mut::<u8>());
+    let abi: BlueprintAbi = scrypto_consume(ptr, |slice| scrypto_decode(slice).unwrap());
+
+    assert_eq!(
+        abi.fns[0].input_defaults,
+        vec![scrypto_encode(&String::new()), scrypto_encode(&0u32)]
+    );
+}
+
+#[test]
+fn test_abi_with_declared_auth() {
+    let ptr = WithAuth_abi(core::ptr::null_mut::<u8>());
+    let abi: BlueprintAbi = scrypto_consume(ptr, |slice| scrypto_decode(slice).unwrap());
+
+    assert_eq!(
+        abi.fns[0].auth,
+        Some(scrypto_encode(&rule!(require("admin_badge"))))
+    );
+}
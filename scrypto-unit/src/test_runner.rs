@@ -10,7 +10,7 @@ use radix_engine::ledger::*;
 use radix_engine::model::{export_abi, export_abi_by_component, extract_abi};
 use radix_engine::state_manager::StagedSubstateStoreManager;
 use radix_engine::transaction::{
-    ExecutionConfig, FeeReserveConfig, PreviewError, PreviewExecutor, PreviewResult,
+    ExecutionConfig, FeeReserveConfig, PreviewError, PreviewExecutor, PreviewResult, ReadOnlyCall,
     TransactionExecutor, TransactionReceipt, TransactionResult,
 };
 use radix_engine::types::*;
@@ -35,6 +35,7 @@ pub struct TestRunner<'s, S: ReadableSubstateStore + WriteableSubstateStore> {
     next_private_key: u64,
     next_transaction_nonce: u64,
     trace: bool,
+    trace_auth_zones: bool,
 }
 
 impl<'s, S: ReadableSubstateStore + WriteableSubstateStore> TestRunner<'s, S> {
@@ -47,9 +48,15 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore> TestRunner<'s, S> {
             next_private_key: 1, // 0 is invalid
             next_transaction_nonce: 0,
             trace,
+            trace_auth_zones: false,
         }
     }
 
+    /// Records the auth zone state at each method invocation in the receipts of subsequent transactions.
+    pub fn set_trace_auth_zones(&mut self, trace_auth_zones: bool) {
+        self.trace_auth_zones = trace_auth_zones;
+    }
+
     pub fn next_transaction_nonce(&self) -> u64 {
         self.next_transaction_nonce
     }
@@ -247,6 +254,23 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore> TestRunner<'s, S> {
         self.execute_manifest(manifest, signer_public_keys)
     }
 
+    /// Returns the cost units consumed by a manifest, without committing its changes.
+    ///
+    /// Panics if the transaction doesn't succeed.
+    pub fn measure_cost(
+        &mut self,
+        manifest: TransactionManifest,
+        signer_public_keys: Vec<PublicKey>,
+    ) -> u32 {
+        let node_id = self.create_child_node(0);
+        let receipt = self
+            .execute_batch_on_node(node_id, vec![(manifest, signer_public_keys)])
+            .pop()
+            .unwrap();
+        receipt.expect_commit_success();
+        receipt.execution.fee_summary.cost_unit_consumed
+    }
+
     pub fn execute_transaction<T: ExecutableTransaction>(
         &mut self,
         transaction: &T,
@@ -264,6 +288,35 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore> TestRunner<'s, S> {
         .execute(transaction, fee_reserve_config, execution_config)
     }
 
+    pub fn minimum_fee<T: ExecutableTransaction>(
+        &mut self,
+        transaction: &T,
+        fee_reserve_config: &FeeReserveConfig,
+        execution_config: &ExecutionConfig,
+    ) -> Result<Decimal, RuntimeError> {
+        let node_id = self.create_child_node(0);
+        let substate_store = &mut self.execution_stores.get_output_store(node_id);
+
+        TransactionExecutor::new(
+            substate_store,
+            &mut self.wasm_engine,
+            &mut self.wasm_instrumenter,
+        )
+        .minimum_fee(transaction, fee_reserve_config, execution_config)
+    }
+
+    pub fn query(&mut self, calls: Vec<ReadOnlyCall>) -> Result<Vec<ScryptoValue>, RuntimeError> {
+        let node_id = self.create_child_node(0);
+        let substate_store = &mut self.execution_stores.get_output_store(node_id);
+
+        TransactionExecutor::new(
+            substate_store,
+            &mut self.wasm_engine,
+            &mut self.wasm_instrumenter,
+        )
+        .query(calls, &ExecutionConfig::standard())
+    }
+
     pub fn execute_preview(
         &mut self,
         preview_intent: PreviewIntent,
@@ -315,12 +368,17 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore> TestRunner<'s, S> {
             .execute_and_commit(
                 &transaction,
                 &FeeReserveConfig {
-                    cost_unit_price: DEFAULT_COST_UNIT_PRICE.parse().unwrap(),
                     system_loan: DEFAULT_SYSTEM_LOAN,
                 },
                 &ExecutionConfig {
                     max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+                    max_kv_value_bytes: DEFAULT_MAX_KV_VALUE_BYTES,
                     trace: self.trace,
+                    profile_wasm_memory: false,
+                    trace_auth_zones: self.trace_auth_zones,
+                    client_context: None,
+                    on_commit: None,
+                    fee_table: FeeTable::new(),
                 },
             );
             receipts.push(receipt);
@@ -420,6 +478,8 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore> TestRunner<'s, S> {
                 HashMap::new(),
                 access_rules,
                 Some(MintParams::Fungible { amount: 5.into() }),
+                None,
+                None,
             )
             .call_method(
                 account,
@@ -463,6 +523,8 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore> TestRunner<'s, S> {
                 HashMap::new(),
                 access_rules,
                 Some(MintParams::Fungible { amount: 5.into() }),
+                None,
+                None,
             )
             .call_method(
                 account,
@@ -501,6 +563,8 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore> TestRunner<'s, S> {
                 HashMap::new(),
                 access_rules,
                 Some(MintParams::Fungible { amount: 5.into() }),
+                None,
+                None,
             )
             .call_method(
                 account,
@@ -545,6 +609,8 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore> TestRunner<'s, S> {
                 HashMap::new(),
                 access_rules,
                 Some(MintParams::NonFungible { entries }),
+                None,
+                None,
             )
             .call_method(
                 account,
@@ -576,6 +642,8 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore> TestRunner<'s, S> {
                 HashMap::new(),
                 access_rules,
                 Some(MintParams::Fungible { amount }),
+                None,
+                None,
             )
             .call_method(
                 account,
@@ -660,6 +728,41 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore> TestRunner<'s, S> {
         scrypto_decode(&current_epoch.raw).unwrap()
     }
 
+    pub fn set_time(&mut self, unix_seconds: u64) {
+        self.kernel_call(
+            vec![NonFungibleAddress::new(
+                SYSTEM_TOKEN,
+                NonFungibleId::from_u32(0),
+            )],
+            |kernel| {
+                kernel
+                    .invoke_method(
+                        Receiver::Ref(RENodeId::System),
+                        FnIdentifier::Native(NativeFnIdentifier::System(
+                            SystemFnIdentifier::SetTime,
+                        )),
+                        ScryptoValue::from_typed(&SystemSetTimeInput { time: unix_seconds }),
+                    )
+                    .unwrap()
+            },
+        );
+    }
+
+    pub fn get_current_time(&mut self) -> u64 {
+        let current_time: ScryptoValue = self.kernel_call(vec![], |kernel| {
+            kernel
+                .invoke_method(
+                    Receiver::Ref(RENodeId::System),
+                    FnIdentifier::Native(NativeFnIdentifier::System(
+                        SystemFnIdentifier::GetCurrentTime,
+                    )),
+                    ScryptoValue::from_typed(&SystemGetCurrentTimeInput {}),
+                )
+                .unwrap()
+        });
+        scrypto_decode(&current_time.raw).unwrap()
+    }
+
     /// Performs a kernel call through a kernel with `is_system = true`.
     fn kernel_call<F>(&mut self, initial_proofs: Vec<NonFungibleAddress>, fun: F) -> ScryptoValue
     where
@@ -682,6 +785,7 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore> TestRunner<'s, S> {
             initial_proofs,
             &blobs,
             DEFAULT_MAX_CALL_DEPTH,
+            DEFAULT_MAX_KV_VALUE_BYTES,
             &mut track,
             &mut self.wasm_engine,
             &mut self.wasm_instrumenter,
@@ -751,7 +855,11 @@ pub fn test_abi_any_in_void_out(
                 },
                 output: Type::Unit,
                 export_name: format!("{}_{}", blueprint_name, function_name),
+                input_defaults: vec![],
+                auth: None,
             }],
+            events: vec![],
+            errors: vec![],
         },
     );
     blueprint_abis
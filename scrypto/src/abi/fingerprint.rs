@@ -0,0 +1,77 @@
+use sbor::rust::vec::Vec;
+
+use crate::abi::*;
+use crate::buffer::scrypto_encode;
+use crate::crypto::{hash, Hash};
+
+/// Computes a stable fingerprint of a blueprint interface, e.g. for caching generated bindings.
+pub trait AbiFingerprint {
+    fn fingerprint(&self) -> Hash;
+}
+
+impl AbiFingerprint for BlueprintAbi {
+    /// Hashes the structure, functions, events and errors of the blueprint.
+    ///
+    /// Functions are sorted by identifier first, so the declaration order doesn't matter.
+    fn fingerprint(&self) -> Hash {
+        let mut fns: Vec<Fn> = self.fns.clone();
+        fns.sort_by(|a, b| a.ident.cmp(&b.ident));
+        hash(scrypto_encode(&(
+            self.structure.clone(),
+            fns,
+            self.events.clone(),
+            self.errors.clone(),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sbor::describe::Fields;
+    use sbor::rust::borrow::ToOwned;
+    use sbor::rust::vec;
+    use sbor::Type;
+
+    fn function(ident: &str, input: Type) -> Fn {
+        Fn {
+            ident: ident.to_owned(),
+            mutability: Some(SelfMutability::Immutable),
+            input: Type::Struct {
+                name: ident.to_owned(),
+                fields: Fields::Named {
+                    named: vec![("arg0".to_owned(), input)],
+                },
+            },
+            output: Type::Unit,
+            export_name: ident.to_owned(),
+            input_defaults: vec![],
+            auth: None,
+        }
+    }
+
+    fn blueprint_abi(fns: Vec<Fn>) -> BlueprintAbi {
+        BlueprintAbi {
+            structure: Type::Unit,
+            fns,
+            events: vec![],
+            errors: vec![],
+        }
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_declaration_order() {
+        let a = blueprint_abi(vec![function("get", Type::U32), function("set", Type::U64)]);
+        let b = blueprint_abi(vec![function("set", Type::U64), function("get", Type::U32)]);
+
+        assert_eq!(a.fingerprint(), b.fingerprint());
+    }
+
+    #[test]
+    fn test_fingerprint_changes_with_signature() {
+        let a = blueprint_abi(vec![function("get", Type::U32), function("set", Type::U64)]);
+        let b = blueprint_abi(vec![function("get", Type::U32), function("set", Type::U32)]);
+
+        assert_ne!(a.fingerprint(), b.fingerprint());
+    }
+}
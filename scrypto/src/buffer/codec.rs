@@ -5,7 +5,16 @@ use crate::buffer::*;
 
 /// Encodes a data structure into byte array.
 pub fn scrypto_encode<T: Encode + ?Sized>(v: &T) -> Vec<u8> {
-    encode_with_static_info(v)
+    let mut buf = Vec::with_capacity(512);
+    scrypto_encode_into(v, &mut buf);
+    buf
+}
+
+/// Encodes a data structure into the given buffer, replacing its content.
+///
+/// Reusing one buffer avoids allocating when encoding repeatedly.
+pub fn scrypto_encode_into<T: Encode + ?Sized>(v: &T, buf: &mut Vec<u8>) {
+    encode_with_static_info_into(v, buf)
 }
 
 /// Decodes an instance of `T` from a slice.
@@ -27,6 +36,10 @@ pub fn scrypto_decode_from_buffer<T: Decode + ?Sized>(ptr: *mut u8) -> Result<T,
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::constants::RADIX_TOKEN;
+    use crate::crypto::*;
+    use crate::math::*;
+    use crate::resource::*;
     use sbor::rust::string::String;
 
     #[test]
@@ -35,4 +48,50 @@ mod tests {
         let decoded: String = scrypto_decode_from_buffer(encoded).unwrap();
         assert_eq!(decoded, "abc");
     }
+
+    #[derive(TypeId, Encode)]
+    struct Order {
+        id: u64,
+        price: Decimal,
+        quantity: PreciseDecimal,
+        resource: ResourceAddress,
+        signer: Option<EcdsaSecp256k1PublicKey>,
+        signatures: Vec<EddsaEd25519Signature>,
+        memo: String,
+        nested: (Hash, NonFungibleId),
+    }
+
+    #[test]
+    fn test_encoded_len_of_scrypto_types() {
+        let order = Order {
+            id: 1,
+            price: Decimal::from("1.5"),
+            quantity: PreciseDecimal::from(3),
+            resource: RADIX_TOKEN,
+            signer: Some(EcdsaSecp256k1PublicKey(
+                [2u8; EcdsaSecp256k1PublicKey::LENGTH],
+            )),
+            signatures: sbor::rust::vec![EddsaEd25519Signature([0u8; EddsaEd25519Signature::LENGTH]); 2],
+            memo: String::from("hello"),
+            nested: (Hash([1u8; Hash::LENGTH]), NonFungibleId::from_u64(7)),
+        };
+        assert_eq!(encoded_len(&order), scrypto_encode(&order).len());
+    }
+
+    #[test]
+    fn test_encode_into_reuses_buffer() {
+        let value = sbor::rust::vec![String::from("abc"); 100];
+        let mut buf = Vec::new();
+        scrypto_encode_into(&value, &mut buf);
+        assert_eq!(buf, scrypto_encode(&value));
+
+        let ptr = buf.as_ptr();
+        let capacity = buf.capacity();
+        for _ in 0..1000 {
+            scrypto_encode_into(&value, &mut buf);
+        }
+        assert_eq!(buf.as_ptr(), ptr);
+        assert_eq!(buf.capacity(), capacity);
+        assert_eq!(buf, scrypto_encode(&value));
+    }
 }
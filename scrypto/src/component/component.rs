@@ -1,3 +1,4 @@
+use sbor::rust::collections::BTreeSet;
 use sbor::rust::fmt;
 use sbor::rust::str::FromStr;
 use sbor::rust::string::String;
@@ -12,13 +13,18 @@ use crate::core::*;
 use crate::engine::types::{RENodeId, SubstateId};
 use crate::engine::{api::*, call_engine};
 use crate::misc::*;
-use crate::resource::AccessRules;
+use crate::resource::{AccessRules, ResourceAddress};
 
 #[derive(Debug, TypeId, Encode, Decode)]
 pub struct ComponentAddAccessCheckInput {
     pub access_rules: AccessRules,
 }
 
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct ComponentRestrictResourcesInput {
+    pub resource_addresses: BTreeSet<ResourceAddress>,
+}
+
 /// Represents the state of a component.
 pub trait ComponentState<C: LocalComponent>: Encode + Decode {
     /// Instantiates a component from this data structure.
@@ -29,6 +35,7 @@ pub trait LocalComponent {
     fn package_address(&self) -> PackageAddress;
     fn blueprint_name(&self) -> String;
     fn add_access_check(&mut self, access_rules: AccessRules) -> &mut Self;
+    fn restrict_resources(&mut self, resource_addresses: BTreeSet<ResourceAddress>) -> &mut Self;
     fn globalize(self) -> ComponentAddress;
 }
 
@@ -71,6 +78,26 @@ impl Component {
         self
     }
 
+    /// Restricts the resources this component may deposit into its vaults.
+    ///
+    /// Once restricted, depositing any other resource fails the transaction. Calling this
+    /// again narrows the accepted resources down further.
+    pub fn restrict_resources(
+        &mut self,
+        resource_addresses: BTreeSet<ResourceAddress>,
+    ) -> &mut Self {
+        let input = RadixEngineInput::InvokeMethod(
+            Receiver::Ref(RENodeId::Component(self.0)),
+            FnIdentifier::Native(NativeFnIdentifier::Component(
+                ComponentFnIdentifier::RestrictResources,
+            )),
+            scrypto_encode(&ComponentRestrictResourcesInput { resource_addresses }),
+        );
+        let _: () = call_engine(input);
+
+        self
+    }
+
     pub fn globalize(self) -> ComponentAddress {
         let input = RadixEngineInput::RENodeGlobalize(RENodeId::Component(self.0));
         let _: () = call_engine(input);
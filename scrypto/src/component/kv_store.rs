@@ -51,6 +51,59 @@ impl<K: Encode + Decode, V: 'static + Encode + Decode + TypeId> KeyValueStore<K,
         value.map(|value| DataRefMut::new(substate_id, value))
     }
 
+    /// Applies the given closure to the value associated with the given key, and writes the
+    /// updated value back.
+    ///
+    /// Returns `false`, without calling the closure, if the key is absent.
+    pub fn update<F: FnOnce(&mut V)>(&self, key: &K, f: F) -> bool {
+        let substate_id = SubstateId::KeyValueStoreEntry(self.id, scrypto_encode(key));
+        let input = RadixEngineInput::SubstateRead(substate_id.clone());
+        let value: Option<V> = call_engine(input);
+        match value {
+            Some(mut value) => {
+                f(&mut value);
+                let input = RadixEngineInput::SubstateWrite(substate_id, scrypto_encode(&value));
+                let _: () = call_engine(input);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns up to `limit` entries whose keys lie between `start` and `end`, both inclusive.
+    ///
+    /// Entries are ordered by the byte order of the encoded keys, which doesn't necessarily
+    /// match the natural order of `K`. Each entry returned is charged as a read.
+    pub fn entries_in_range(&self, start: &K, end: &K, limit: u32) -> Vec<(K, V)> {
+        let input = RadixEngineInput::IterateKeyValueStore(
+            self.id,
+            scrypto_encode(start),
+            scrypto_encode(end),
+            limit,
+        );
+        let entries: Vec<(Vec<u8>, Vec<u8>)> = call_engine(input);
+        entries
+            .into_iter()
+            .map(|(key, value)| {
+                (
+                    scrypto_decode(&key).expect("Failed to decode key value store key"),
+                    scrypto_decode(&value).expect("Failed to decode key value store value"),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the number of entries in this map.
+    pub fn len(&self) -> u64 {
+        let input = RadixEngineInput::GetKeyValueStoreLength(self.id);
+        call_engine(input)
+    }
+
+    /// Returns whether this map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Inserts a new key-value pair into this map.
     pub fn insert(&self, key: K, value: V) {
         let substate_id = SubstateId::KeyValueStoreEntry(self.id, scrypto_encode(&key));
@@ -139,7 +192,7 @@ impl<K: Encode + Decode, V: 'static + Encode + Decode + TypeId> Decode for KeyVa
     }
 
     fn decode_value(decoder: &mut Decoder) -> Result<Self, DecodeError> {
-        let len = decoder.read_dynamic_size()?;
+        let len = decoder.read_len(Self::type_id())?;
         let slice = decoder.read_bytes(len)?;
         Self::try_from(slice)
             .map_err(|_| DecodeError::CustomError("Failed to decode KeyValueStore".to_string()))
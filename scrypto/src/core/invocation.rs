@@ -43,6 +43,7 @@ pub enum NativeFnIdentifier {
 )]
 pub enum ComponentFnIdentifier {
     AddAccessCheck,
+    RestrictResources,
 }
 
 #[derive(
@@ -52,6 +53,11 @@ pub enum SystemFnIdentifier {
     GetTransactionHash,
     GetCurrentEpoch,
     SetEpoch,
+    VerifyEcdsaSecp256k1Signature,
+    VerifyEddsaEd25519Signature,
+    GetComponentMethods,
+    GetCurrentTime,
+    SetTime,
 }
 
 #[derive(
@@ -83,6 +89,8 @@ pub enum ResourceManagerFnIdentifier {
     NonFungibleExists,
     CreateBucket,
     CreateVault,
+    SetTransferHook,
+    CreateWithAddressSeed,
 }
 
 #[derive(
@@ -17,5 +17,8 @@ pub use level::Level;
 pub use logger::Logger;
 pub use network::{NetworkDefinition, ParseNetworkError};
 pub use runtime::{
-    Runtime, SystemGetCurrentEpochInput, SystemGetTransactionHashInput, SystemSetEpochInput,
+    Runtime, SubCallBudgetExceeded, SystemGetComponentMethodsInput, SystemGetCurrentEpochInput,
+    SystemGetCurrentTimeInput, SystemGetTransactionHashInput, SystemSetEpochInput,
+    SystemSetTimeInput, SystemVerifyEcdsaSecp256k1SignatureInput,
+    SystemVerifyEddsaEd25519SignatureInput,
 };
@@ -3,12 +3,20 @@ use sbor::rust::string::*;
 use sbor::rust::vec::Vec;
 use sbor::*;
 
-use crate::buffer::scrypto_encode;
+use crate::abi::MethodSignature;
+use crate::buffer::{scrypto_decode, scrypto_encode};
 use crate::component::*;
 use crate::core::*;
 use crate::crypto::*;
 use crate::engine::types::{RENodeId, SubstateId};
-use crate::engine::{api::*, call_engine};
+use crate::engine::{api::*, call_engine, call_engine_raw};
+use crate::values::ScryptoValue;
+
+/// Returned when a method called with a budget runs out of cost units.
+#[derive(Debug, Clone, PartialEq, Eq, TypeId, Encode, Decode)]
+pub struct SubCallBudgetExceeded {
+    pub max_cost_units: u32,
+}
 
 #[derive(Debug, TypeId, Encode, Decode)]
 pub struct SystemGetCurrentEpochInput {}
@@ -18,9 +26,36 @@ pub struct SystemSetEpochInput {
     pub epoch: u64,
 }
 
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct SystemGetCurrentTimeInput {}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct SystemSetTimeInput {
+    pub time: u64,
+}
+
 #[derive(Debug, TypeId, Encode, Decode)]
 pub struct SystemGetTransactionHashInput {}
 
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct SystemVerifyEcdsaSecp256k1SignatureInput {
+    pub public_key: EcdsaSecp256k1PublicKey,
+    pub message_hash: Hash,
+    pub signature: EcdsaSecp256k1Signature,
+}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct SystemVerifyEddsaEd25519SignatureInput {
+    pub public_key: EddsaEd25519PublicKey,
+    pub message_hash: Hash,
+    pub signature: EddsaEd25519Signature,
+}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct SystemGetComponentMethodsInput {
+    pub component_address: ComponentAddress,
+}
+
 /// The transaction runtime.
 #[derive(Debug)]
 pub struct Runtime {}
@@ -88,6 +123,72 @@ impl Runtime {
         call_engine(input)
     }
 
+    /// Invokes a function on a blueprint, returning the undecoded output.
+    pub fn call_function_raw<S: AsRef<str>>(
+        package_address: PackageAddress,
+        blueprint_name: S,
+        function: S,
+        args: Vec<u8>,
+    ) -> ScryptoValue {
+        let input = RadixEngineInput::InvokeFunction(
+            FnIdentifier::Scrypto {
+                package_address,
+                blueprint_name: blueprint_name.as_ref().to_owned(),
+                ident: function.as_ref().to_string(),
+            },
+            args,
+        );
+        ScryptoValue::from_slice(&call_engine_raw(input)).unwrap()
+    }
+
+    /// Invokes a method on a component, returning the undecoded output.
+    pub fn call_method_raw<S: AsRef<str>>(
+        component_address: ComponentAddress,
+        method: S,
+        args: Vec<u8>,
+    ) -> ScryptoValue {
+        let input = RadixEngineInput::SubstateRead(SubstateId::ComponentInfo(component_address));
+        let (package_address, blueprint_name): (PackageAddress, String) = call_engine(input);
+
+        let input = RadixEngineInput::InvokeMethod(
+            Receiver::Ref(RENodeId::Component(component_address)),
+            FnIdentifier::Scrypto {
+                package_address,
+                blueprint_name,
+                ident: method.as_ref().to_string(),
+            },
+            args,
+        );
+        ScryptoValue::from_slice(&call_engine_raw(input)).unwrap()
+    }
+
+    /// Invokes a method on a component, allowing it to consume at most `max_cost_units`.
+    ///
+    /// If the budget runs out, the state changes of the call are reverted and an error is returned
+    /// instead of failing the transaction. The cost units consumed are still charged.
+    pub fn call_method_with_budget<S: AsRef<str>, T: Decode>(
+        component_address: ComponentAddress,
+        method: S,
+        args: Vec<u8>,
+        max_cost_units: u32,
+    ) -> Result<T, SubCallBudgetExceeded> {
+        let input = RadixEngineInput::SubstateRead(SubstateId::ComponentInfo(component_address));
+        let (package_address, blueprint_name): (PackageAddress, String) = call_engine(input);
+
+        let input = RadixEngineInput::InvokeMethodWithBudget(
+            Receiver::Ref(RENodeId::Component(component_address)),
+            FnIdentifier::Scrypto {
+                package_address,
+                blueprint_name,
+                ident: method.as_ref().to_string(),
+            },
+            args,
+            max_cost_units,
+        );
+        let output: Result<Vec<u8>, SubCallBudgetExceeded> = call_engine(input);
+        output.map(|raw| scrypto_decode(&raw).unwrap())
+    }
+
     /// Returns the transaction hash.
     pub fn transaction_hash() -> Hash {
         let input = RadixEngineInput::InvokeMethod(
@@ -111,4 +212,69 @@ impl Runtime {
         );
         call_engine(input)
     }
+
+    /// Returns the current ledger time, in seconds since the Unix epoch.
+    pub fn current_time() -> u64 {
+        let input = RadixEngineInput::InvokeMethod(
+            Receiver::Ref(RENodeId::System),
+            FnIdentifier::Native(NativeFnIdentifier::System(
+                SystemFnIdentifier::GetCurrentTime,
+            )),
+            scrypto_encode(&SystemGetCurrentTimeInput {}),
+        );
+        call_engine(input)
+    }
+
+    /// Verifies an ECDSA secp256k1 signature over the given message hash.
+    pub fn verify_ecdsa_signature(
+        public_key: EcdsaSecp256k1PublicKey,
+        message_hash: Hash,
+        signature: EcdsaSecp256k1Signature,
+    ) -> bool {
+        let input = RadixEngineInput::InvokeMethod(
+            Receiver::Ref(RENodeId::System),
+            FnIdentifier::Native(NativeFnIdentifier::System(
+                SystemFnIdentifier::VerifyEcdsaSecp256k1Signature,
+            )),
+            scrypto_encode(&SystemVerifyEcdsaSecp256k1SignatureInput {
+                public_key,
+                message_hash,
+                signature,
+            }),
+        );
+        call_engine(input)
+    }
+
+    /// Verifies an EdDSA Ed25519 signature over the given message hash.
+    pub fn verify_ed25519_signature(
+        public_key: EddsaEd25519PublicKey,
+        message_hash: Hash,
+        signature: EddsaEd25519Signature,
+    ) -> bool {
+        let input = RadixEngineInput::InvokeMethod(
+            Receiver::Ref(RENodeId::System),
+            FnIdentifier::Native(NativeFnIdentifier::System(
+                SystemFnIdentifier::VerifyEddsaEd25519Signature,
+            )),
+            scrypto_encode(&SystemVerifyEddsaEd25519SignatureInput {
+                public_key,
+                message_hash,
+                signature,
+            }),
+        );
+        call_engine(input)
+    }
+
+    /// Returns the signatures of the methods exposed by a component, as declared in the
+    /// ABI of its blueprint.
+    pub fn component_methods(component_address: ComponentAddress) -> Vec<MethodSignature> {
+        let input = RadixEngineInput::InvokeMethod(
+            Receiver::Ref(RENodeId::System),
+            FnIdentifier::Native(NativeFnIdentifier::System(
+                SystemFnIdentifier::GetComponentMethods,
+            )),
+            scrypto_encode(&SystemGetComponentMethodsInput { component_address }),
+        );
+        call_engine(input)
+    }
 }
@@ -102,7 +102,8 @@ impl EcdsaSecp256k1PublicKey {
 scrypto_type!(
     EcdsaSecp256k1PublicKey,
     ScryptoType::EcdsaSecp256k1PublicKey,
-    Vec::new()
+    Vec::new(),
+    EcdsaSecp256k1PublicKey::LENGTH
 );
 
 impl TryFrom<&[u8]> for EcdsaSecp256k1Signature {
@@ -128,7 +129,8 @@ impl EcdsaSecp256k1Signature {
 scrypto_type!(
     EcdsaSecp256k1Signature,
     ScryptoType::EcdsaSecp256k1Signature,
-    Vec::new()
+    Vec::new(),
+    EcdsaSecp256k1Signature::LENGTH
 );
 
 //======
@@ -100,7 +100,8 @@ impl EddsaEd25519PublicKey {
 scrypto_type!(
     EddsaEd25519PublicKey,
     ScryptoType::EddsaEd25519PublicKey,
-    Vec::new()
+    Vec::new(),
+    EddsaEd25519PublicKey::LENGTH
 );
 
 impl TryFrom<&[u8]> for EddsaEd25519Signature {
@@ -124,7 +125,8 @@ impl EddsaEd25519Signature {
 scrypto_type!(
     EddsaEd25519Signature,
     ScryptoType::EddsaEd25519Signature,
-    Vec::new()
+    Vec::new(),
+    EddsaEd25519Signature::LENGTH
 );
 
 //======
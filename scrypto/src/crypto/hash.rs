@@ -1,6 +1,7 @@
 use sbor::rust::borrow::ToOwned;
 use sbor::rust::convert::TryFrom;
 use sbor::rust::fmt;
+use sbor::rust::ptr;
 use sbor::rust::str::FromStr;
 use sbor::rust::string::String;
 use sbor::rust::vec::Vec;
@@ -10,6 +11,9 @@ use crate::abi::*;
 use crate::misc::*;
 
 /// Represents a 32-byte hash digest.
+///
+/// Note that `==` compares hashes in variable time; use `ct_eq` when comparing against
+/// attacker-influenced input.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Hash(pub [u8; Self::LENGTH]);
 
@@ -29,6 +33,18 @@ impl Hash {
         result.copy_from_slice(&self.0[16..32]);
         result
     }
+
+    /// Compares with another hash in constant time, i.e. without stopping at the first
+    /// differing byte.
+    pub fn ct_eq(&self, other: &Hash) -> bool {
+        let diff = self
+            .0
+            .iter()
+            .zip(other.0.iter())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b));
+        // Keeps the compiler from turning the fold into an early-exit comparison
+        unsafe { ptr::read_volatile(&diff) == 0 }
+    }
 }
 
 impl AsRef<[u8]> for Hash {
@@ -45,6 +61,26 @@ pub fn hash<T: AsRef<[u8]>>(data: T) -> Hash {
     crate::crypto::sha256(data)
 }
 
+/// Domain of the transaction intent hash.
+pub const TRANSACTION_INTENT_HASH_DOMAIN: &[u8] = b"transaction_intent";
+/// Domain of the signed transaction intent hash.
+pub const SIGNED_TRANSACTION_INTENT_HASH_DOMAIN: &[u8] = b"signed_transaction_intent";
+/// Domain of the notarized transaction hash.
+pub const NOTARIZED_TRANSACTION_HASH_DOMAIN: &[u8] = b"notarized_transaction";
+
+/// Computes the hash digest of a message within a domain, so that the same message never
+/// hashes the same in different domains.
+///
+/// The domain is prefixed with its length, as a little-endian `u32`, so that no domain and
+/// message can be confused with another split of the same bytes.
+pub fn hash_with_domain(domain: &[u8], data: &[u8]) -> Hash {
+    let mut buf = Vec::with_capacity(4 + domain.len() + data.len());
+    buf.extend((domain.len() as u32).to_le_bytes());
+    buf.extend(domain);
+    buf.extend(data);
+    hash(buf)
+}
+
 //========
 // error
 //========
@@ -87,12 +123,19 @@ impl Hash {
     }
 }
 
-scrypto_type!(Hash, ScryptoType::Hash, Vec::new());
+scrypto_type!(Hash, ScryptoType::Hash, Vec::new(), Hash::LENGTH);
 
 //======
 // text
 //======
 
+impl Hash {
+    /// Parses a hash from its hex representation.
+    pub fn from_hex(s: &str) -> Result<Self, ParseHashError> {
+        Self::from_str(s)
+    }
+}
+
 impl FromStr for Hash {
     type Err = ParseHashError;
 
@@ -125,4 +168,45 @@ mod tests {
         let h = Hash::from_str(s).unwrap();
         assert_eq!(h.to_string(), s);
     }
+
+    #[test]
+    fn test_from_hex() {
+        let s = "b177968c9c68877dc8d33e25759183c556379daa45a4d78a2b91c70133c873ca";
+        assert_eq!(Hash::from_hex(s), Hash::from_str(s));
+        assert_eq!(
+            Hash::from_hex("not hex"),
+            Err(ParseHashError::InvalidHex("not hex".to_owned()))
+        );
+        assert_eq!(
+            Hash::from_hex("b177"),
+            Err(ParseHashError::InvalidLength(2))
+        );
+    }
+
+    #[test]
+    fn test_hash_with_domain() {
+        let data = b"payload";
+        let intent_hash = hash_with_domain(TRANSACTION_INTENT_HASH_DOMAIN, data);
+        assert_eq!(
+            intent_hash,
+            hash_with_domain(TRANSACTION_INTENT_HASH_DOMAIN, data)
+        );
+        assert_ne!(
+            intent_hash,
+            hash_with_domain(SIGNED_TRANSACTION_INTENT_HASH_DOMAIN, data)
+        );
+        assert_ne!(intent_hash, hash(data));
+        assert_ne!(hash_with_domain(b"ab", b"c"), hash_with_domain(b"a", b"bc"));
+    }
+
+    #[test]
+    fn test_ct_eq() {
+        let h1 = hash("a");
+        let h2 = hash("b");
+        let mut h3 = h1;
+        h3.0[31] ^= 1;
+        assert!(h1.ct_eq(&hash("a")));
+        assert!(!h1.ct_eq(&h2));
+        assert!(!h1.ct_eq(&h3));
+    }
 }
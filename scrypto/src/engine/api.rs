@@ -32,6 +32,7 @@ macro_rules! native_functions {
 pub enum RadixEngineInput {
     InvokeFunction(FnIdentifier, Vec<u8>),
     InvokeMethod(Receiver, FnIdentifier, Vec<u8>),
+    InvokeMethodWithBudget(Receiver, FnIdentifier, Vec<u8>, u32),
     RENodeCreate(ScryptoRENode),
     RENodeGlobalize(RENodeId),
     SubstateRead(SubstateId),
@@ -40,4 +41,6 @@ pub enum RadixEngineInput {
     EmitLog(Level, String),
     GenerateUuid(),
     CheckAccessRule(AccessRule, Vec<ProofId>),
+    IterateKeyValueStore(KeyValueStoreId, Vec<u8>, Vec<u8>, u32),
+    GetKeyValueStoreLength(KeyValueStoreId),
 }
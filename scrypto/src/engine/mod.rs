@@ -3,6 +3,7 @@ pub mod api;
 /// Types and functions shared by both Scrypto and Radix Engine.
 pub mod types;
 
+use sbor::rust::vec::Vec;
 use sbor::Decode;
 
 use crate::engine::api::RadixEngineInput;
@@ -22,6 +23,27 @@ pub fn call_engine<V: Decode>(input: RadixEngineInput) -> V {
 
 /// Utility function for making a radix engine call.
 #[cfg(not(target_arch = "wasm32"))]
-pub fn call_engine<V: Decode>(_input: RadixEngineInput) -> V {
-    todo!()
+pub fn call_engine<V: Decode>(input: RadixEngineInput) -> V {
+    use crate::buffer::scrypto_decode;
+
+    scrypto_decode(&call_engine_raw(input)).unwrap()
+}
+
+/// Utility function for making a radix engine call, returning the undecoded output.
+#[cfg(target_arch = "wasm32")]
+pub fn call_engine_raw(input: RadixEngineInput) -> Vec<u8> {
+    use crate::buffer::*;
+    use crate::engine::api::radix_engine;
+
+    unsafe {
+        let input_ptr = scrypto_encode_to_buffer(&input);
+        let output_ptr = radix_engine(input_ptr);
+        scrypto_consume(output_ptr, |slice| slice.to_vec())
+    }
+}
+
+/// Utility function for making a radix engine call, returning the undecoded output.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn call_engine_raw(_input: RadixEngineInput) -> Vec<u8> {
+    unimplemented!("Radix Engine calls are only available when compiled to wasm32")
 }
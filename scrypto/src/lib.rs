@@ -18,6 +18,9 @@ compile_error!("Feature `std` and `alloc` can't be enabled at the same time.");
 
 /// Scrypto blueprint ABI.
 pub mod abi {
+    mod fingerprint;
+
+    pub use fingerprint::AbiFingerprint;
     pub use scrypto_abi::*;
 }
 /// Scrypto address.
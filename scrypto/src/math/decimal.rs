@@ -1,4 +1,5 @@
 use core::ops::*;
+use num_bigint::BigInt;
 use num_traits::{One, Pow, ToPrimitive, Zero};
 use paste::paste;
 use sbor::rust::convert::{TryFrom, TryInto};
@@ -66,6 +67,18 @@ impl Decimal {
         Self::ONE
     }
 
+    /// Creates a decimal of `mantissa / 10^scale`, e.g. `123.45` from `(12345, 2)`.
+    ///
+    /// Returns an error if `scale` is greater than `Decimal::SCALE`.
+    pub fn from_integer_with_scale(mantissa: i128, scale: u32) -> Result<Self, ParseDecimalError> {
+        if scale > Self::SCALE {
+            return Err(ParseDecimalError::UnsupportedDecimalPlace);
+        }
+        Ok(Self(
+            I256::from(mantissa) * I256::from(10u8).pow(Self::SCALE - scale),
+        ))
+    }
+
     /// Whether this decimal is zero.
     pub fn is_zero(&self) -> bool {
         self.0 == I256::zero()
@@ -81,11 +94,46 @@ impl Decimal {
         self.0 < I256::zero()
     }
 
+    /// Converts to a `PreciseDecimal`, which is always lossless.
+    pub fn to_precise_decimal(&self) -> PreciseDecimal {
+        PreciseDecimal(
+            I512::from(self.0) * I512::from(10i8).pow(PreciseDecimal::SCALE - Self::SCALE),
+        )
+    }
+
     /// Returns the absolute value.
     pub fn abs(&self) -> Decimal {
         Decimal(self.0.abs())
     }
 
+    /// Adds `other`, clamping the result at `MIN` or `MAX` instead of overflowing.
+    pub fn saturating_add(self, other: Self) -> Self {
+        self.checked_add(other).unwrap_or(if other.is_negative() {
+            Self::MIN
+        } else {
+            Self::MAX
+        })
+    }
+
+    /// Subtracts `other`, clamping the result at zero when `other` is greater than `self`,
+    /// and at `MAX` instead of overflowing.
+    pub fn saturating_sub(self, other: Self) -> Self {
+        if other > self {
+            return Self::ZERO;
+        }
+        self.checked_sub(other).unwrap_or(Self::MAX)
+    }
+
+    /// Multiplies by `other`, clamping the result at `MIN` or `MAX` instead of overflowing.
+    pub fn saturating_mul(self, other: Self) -> Self {
+        self.checked_mul(other)
+            .unwrap_or(if self.is_negative() == other.is_negative() {
+                Self::MAX
+            } else {
+                Self::MIN
+            })
+    }
+
     /// Returns the largest integer that is equal to or less than this number.
     pub fn floor(&self) -> Self {
         self.round(0, RoundingMode::TowardsNegativeInfinity)
@@ -169,7 +217,33 @@ impl Decimal {
                     }
                 }
             }
+            RoundingMode::TowardsNearestAndHalfToEven => {
+                let remainder = (self.0 % divisor).abs();
+                if remainder == I256::zero() {
+                    return self.clone();
+                }
+                let truncated = self.0 / divisor;
+                let twice_remainder = remainder * I256::from(2i8);
+                let round_away = twice_remainder > divisor
+                    || (twice_remainder == divisor && truncated % I256::from(2i8) != I256::zero());
+                if !round_away {
+                    Self(truncated * divisor)
+                } else if self.is_negative() {
+                    Self((truncated - I256::one()) * divisor)
+                } else {
+                    Self((truncated + I256::one()) * divisor)
+                }
+            }
+        }
+    }
+
+    /// Returns the square root, rounded towards zero, or `None` if this decimal is negative.
+    pub fn sqrt(&self) -> Option<Self> {
+        if self.is_negative() {
+            return None;
         }
+        let root = (BigInt::from(self.0) * BigInt::from(Self::ONE.0)).sqrt();
+        Some(Self(I256::try_from(root).expect("Overflow")))
     }
 
     /// Calculates power usingexponentiation by squaring".
@@ -194,6 +268,39 @@ impl Decimal {
             );
         }
     }
+
+    /// Multiplies this decimal by the given basis points (1 bps = 0.01%).
+    ///
+    /// The product is computed in 512-bit precision and rounded to the nearest
+    /// representable value, with ties rounded away from zero.
+    pub fn mul_bps(&self, bps: u32) -> Self {
+        self.mul_div_rounded(I512::from(bps), I512::from(10000u32))
+    }
+
+    /// Multiplies this decimal by the given percentage, e.g. `dec!("2.5")` for 2.5%.
+    ///
+    /// The product is computed in 512-bit precision and rounded to the nearest
+    /// representable value, with ties rounded away from zero.
+    pub fn mul_percent(&self, percent: Decimal) -> Self {
+        self.mul_div_rounded(
+            I512::from(percent.0),
+            I512::from(Self::ONE.0) * I512::from(100u32),
+        )
+    }
+
+    fn mul_div_rounded(&self, numerator: I512, denominator: I512) -> Self {
+        let product = I512::from(self.0) * numerator;
+        let mut quotient = product / denominator;
+        let remainder = product % denominator;
+        if (remainder * I512::from(2u32)).abs() >= denominator.abs() {
+            if product.is_negative() != denominator.is_negative() {
+                quotient -= I512::one();
+            } else {
+                quotient += I512::one();
+            }
+        }
+        Decimal(quotient.try_into().expect("Overflow"))
+    }
 }
 
 macro_rules! from_int {
@@ -300,6 +407,35 @@ where
     }
 }
 
+impl CheckedAdd for Decimal {
+    fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Decimal)
+    }
+}
+
+impl CheckedSub for Decimal {
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Decimal)
+    }
+}
+
+impl CheckedMul for Decimal {
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        let c = BigInt::from(self.0) * BigInt::from(other.0) / BigInt::from(Self::ONE.0);
+        I256::try_from(c).ok().map(Decimal)
+    }
+}
+
+impl CheckedDiv for Decimal {
+    fn checked_div(self, other: Self) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        let c = BigInt::from(self.0) * BigInt::from(Self::ONE.0) / BigInt::from(other.0);
+        I256::try_from(c).ok().map(Decimal)
+    }
+}
+
 impl Neg for Decimal {
     type Output = Decimal;
 
@@ -373,7 +509,7 @@ impl Decimal {
     }
 }
 
-scrypto_type!(Decimal, ScryptoType::Decimal, Vec::new());
+scrypto_type!(Decimal, ScryptoType::Decimal, Vec::new(), Decimal::BITS / 8);
 
 //======
 // text
@@ -669,6 +805,28 @@ mod tests {
         let _ = (-Decimal::MAX) * dec!("-1.000000000000000001");
     }
 
+    #[test]
+    fn test_mul_percent_decimal() {
+        let amount = dec!("123456789012345678901234567.123456789012345678");
+        assert_eq!(
+            amount.mul_percent(dec!("2.5")).to_string(),
+            "3086419725308641972530864.178086419725308642"
+        );
+        assert_eq!(amount.mul_bps(250), amount.mul_percent(dec!("2.5")));
+        assert_eq!((-amount).mul_bps(250), -amount.mul_percent(dec!("2.5")));
+    }
+
+    #[test]
+    fn test_mul_bps_decimal() {
+        assert_eq!(Decimal::MAX.mul_bps(10000), Decimal::MAX);
+        assert_eq!(
+            dec!("0.000000000000000001").mul_bps(5000).to_string(),
+            "0.000000000000000001"
+        );
+        assert_eq!(dec!("0.000000000000000001").mul_bps(4999), Decimal::zero());
+        assert_eq!(dec!("100").mul_bps(1).to_string(), "0.01");
+    }
+
     #[test]
     #[should_panic]
     fn test_div_by_zero_decimal() {
@@ -986,6 +1144,24 @@ mod tests {
         assert_eq!(dec!("-5.5").round(0, mode).to_string(), "-6");
     }
 
+    #[test]
+    fn test_round_towards_nearest_and_half_to_even_decimal() {
+        let mode = RoundingMode::TowardsNearestAndHalfToEven;
+        assert_eq!(dec!("5.5").round(0, mode).to_string(), "6");
+        assert_eq!(dec!("2.5").round(0, mode).to_string(), "2");
+        assert_eq!(dec!("2.51").round(0, mode).to_string(), "3");
+        assert_eq!(dec!("1.6").round(0, mode).to_string(), "2");
+        assert_eq!(dec!("1.1").round(0, mode).to_string(), "1");
+        assert_eq!(dec!("1.0").round(0, mode).to_string(), "1");
+        assert_eq!(dec!("-1.0").round(0, mode).to_string(), "-1");
+        assert_eq!(dec!("-1.1").round(0, mode).to_string(), "-1");
+        assert_eq!(dec!("-1.6").round(0, mode).to_string(), "-2");
+        assert_eq!(dec!("-2.5").round(0, mode).to_string(), "-2");
+        assert_eq!(dec!("-5.5").round(0, mode).to_string(), "-6");
+        assert_eq!(dec!("0.125").round(2, mode).to_string(), "0.12");
+        assert_eq!(dec!("0.135").round(2, mode).to_string(), "0.14");
+    }
+
     #[test]
     fn test_various_decimal_places_decimal() {
         let mode = RoundingMode::TowardsNearestAndHalfAwayFromZero;
@@ -1064,4 +1240,108 @@ mod tests {
         let dec = Decimal::from_str("non_decimal_value");
         assert_eq!(dec, Err(ParseDecimalError::InvalidChar('n')));
     }
+
+    #[test]
+    fn test_from_integer_with_scale_decimal() {
+        assert_eq!(
+            Decimal::from_integer_with_scale(12345, 2),
+            Ok(dec!("123.45"))
+        );
+        assert_eq!(Decimal::from_integer_with_scale(-7, 0), Ok(dec!("-7")));
+        assert_eq!(
+            Decimal::from_integer_with_scale(1, 18),
+            Ok(dec!("0.000000000000000001"))
+        );
+        assert_eq!(
+            Decimal::from_integer_with_scale(1, 19),
+            Err(ParseDecimalError::UnsupportedDecimalPlace)
+        );
+    }
+
+    #[test]
+    fn test_checked_ops_decimal() {
+        assert_eq!(dec!("1.5").checked_add(dec!("2")), Some(dec!("3.5")));
+        assert_eq!(dec!("1.5").checked_sub(dec!("2")), Some(dec!("-0.5")));
+        assert_eq!(dec!("1.5").checked_mul(dec!("2")), Some(dec!("3")));
+        assert_eq!(dec!("3").checked_div(dec!("2")), Some(dec!("1.5")));
+    }
+
+    #[test]
+    fn test_checked_ops_overflow_decimal() {
+        assert_eq!(Decimal::MAX.checked_add(Decimal::ONE), None);
+        assert_eq!(Decimal::MIN.checked_sub(Decimal::ONE), None);
+        assert_eq!(Decimal::MAX.checked_mul(dec!("2")), None);
+        assert_eq!(Decimal::MAX.checked_div(dec!("0.5")), None);
+        assert_eq!(Decimal::MAX.checked_mul(Decimal::ONE), Some(Decimal::MAX));
+    }
+
+    #[test]
+    fn test_checked_div_by_zero_decimal() {
+        assert_eq!(Decimal::ONE.checked_div(Decimal::ZERO), None);
+    }
+
+    #[test]
+    fn test_saturating_ops_decimal() {
+        assert_eq!(dec!("1.5").saturating_add(dec!("2")), dec!("3.5"));
+        assert_eq!(dec!("2").saturating_sub(dec!("1.5")), dec!("0.5"));
+        assert_eq!(dec!("1.5").saturating_mul(dec!("2")), dec!("3"));
+    }
+
+    #[test]
+    fn test_saturating_sub_clamps_at_zero_decimal() {
+        assert_eq!(dec!("1.5").saturating_sub(dec!("2")), Decimal::ZERO);
+        assert_eq!(dec!("-1").saturating_sub(dec!("1")), Decimal::ZERO);
+        assert_eq!(dec!("2").saturating_sub(dec!("2")), Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_saturating_ops_clamp_at_bounds_decimal() {
+        assert_eq!(Decimal::MAX.saturating_add(Decimal::ONE), Decimal::MAX);
+        assert_eq!(Decimal::MIN.saturating_add(-Decimal::ONE), Decimal::MIN);
+        assert_eq!(Decimal::MAX.saturating_sub(-Decimal::ONE), Decimal::MAX);
+        assert_eq!(Decimal::MAX.saturating_mul(dec!("2")), Decimal::MAX);
+        assert_eq!(Decimal::MAX.saturating_mul(dec!("-2")), Decimal::MIN);
+    }
+
+    #[test]
+    fn test_sqrt_decimal() {
+        assert_eq!(dec!("2").sqrt(), Some(dec!("1.414213562373095048")));
+        assert_eq!(dec!("4").sqrt(), Some(dec!("2")));
+        assert_eq!(dec!("0.01").sqrt(), Some(dec!("0.1")));
+        assert_eq!(Decimal::ZERO.sqrt(), Some(Decimal::ZERO));
+        assert!(Decimal::MAX.sqrt().is_some());
+    }
+
+    #[test]
+    fn test_sqrt_negative_decimal() {
+        assert_eq!(dec!("-1").sqrt(), None);
+    }
+
+    #[test]
+    fn test_powi_negative_exp_decimal() {
+        assert_eq!(dec!("2").powi(-1), dec!("0.5"));
+        assert_eq!(dec!("2").powi(-2), dec!("0.25"));
+    }
+
+    #[test]
+    fn test_min_max_decimal() {
+        assert_eq!(dec!("1.5").min(dec!("-2")), dec!("-2"));
+        assert_eq!(dec!("1.5").max(dec!("-2")), dec!("1.5"));
+        assert_eq!(dec!("3").min(dec!("3")), dec!("3"));
+        assert_eq!(dec!("3").max(dec!("3")), dec!("3"));
+    }
+
+    #[test]
+    fn test_clamp_decimal() {
+        assert_eq!(dec!("5").clamp(dec!("1"), dec!("3")), dec!("3"));
+        assert_eq!(dec!("-5").clamp(dec!("1"), dec!("3")), dec!("1"));
+        assert_eq!(dec!("2").clamp(dec!("1"), dec!("3")), dec!("2"));
+        assert_eq!(dec!("2").clamp(dec!("2"), dec!("2")), dec!("2"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_clamp_invalid_bounds_decimal() {
+        let _ = dec!("2").clamp(dec!("3"), dec!("1"));
+    }
 }
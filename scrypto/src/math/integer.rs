@@ -331,6 +331,8 @@ sbor_codec!(U16, TYPE_U16, U16);
 sbor_codec!(U32, TYPE_U32, U32);
 sbor_codec!(U64, TYPE_U64, U64);
 sbor_codec!(U128, TYPE_U128, U128);
+sbor_codec!(I256, TYPE_I256, I256);
+sbor_codec!(U256, TYPE_U256, U256);
 
 fn fmt<
     T: fmt::Display
@@ -1525,3 +1525,38 @@ fn test_format_121_u512() {
     let u512 = U512::from("121");
     assert_eq!(format!("{}", u512), "121");
 }
+
+#[cfg(test)]
+mod sbor_tests {
+    use super::*;
+    use crate::buffer::{scrypto_decode, scrypto_encode};
+    use sbor::any::{decode_any, Value};
+
+    #[test]
+    fn test_encode_decode_zero_i256() {
+        let bytes = scrypto_encode(&I256::zero());
+        assert_eq!(bytes, [vec![TYPE_I256], vec![0u8; 32]].concat());
+        assert_eq!(scrypto_decode::<I256>(&bytes).unwrap(), I256::zero());
+    }
+
+    #[test]
+    fn test_encode_decode_max_u256() {
+        let bytes = scrypto_encode(&U256::MAX);
+        assert_eq!(bytes, [vec![TYPE_U256], vec![0xffu8; 32]].concat());
+        assert_eq!(scrypto_decode::<U256>(&bytes).unwrap(), U256::MAX);
+    }
+
+    #[test]
+    fn test_encode_decode_negative_i256() {
+        let i256 = I256::from("-12345678901234567890123456789012345678901234567890");
+        let bytes = scrypto_encode(&i256);
+        assert_eq!(&bytes[1..], &i256.to_le_bytes());
+        assert_eq!(scrypto_decode::<I256>(&bytes).unwrap(), i256);
+        assert_eq!(
+            decode_any(&bytes).unwrap(),
+            Value::I256 {
+                value: i256.to_le_bytes()
+            }
+        );
+    }
+}
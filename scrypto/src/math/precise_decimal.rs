@@ -1,4 +1,5 @@
 use core::ops::*;
+use num_bigint::BigInt;
 use num_traits::{One, Pow, ToPrimitive, Zero};
 use sbor::rust::convert::{TryFrom, TryInto};
 use sbor::rust::fmt;
@@ -83,11 +84,53 @@ impl PreciseDecimal {
         self.0 < I512::zero()
     }
 
+    /// Converts to a `Decimal`, rounding the extra decimal places with the given mode.
+    ///
+    /// Returns an error if the integer part does not fit into a `Decimal`.
+    pub fn to_decimal(&self, mode: RoundingMode) -> Result<Decimal, ArithmeticError> {
+        let divisor = BigInt::from(10u8).pow(Self::SCALE - Decimal::SCALE);
+        if I256::try_from(BigInt::from(self.0) / &divisor).is_err() {
+            return Err(ArithmeticError::Overflow);
+        }
+        let rounded = BigInt::from(self.round(Decimal::SCALE, mode).0) / divisor;
+        I256::try_from(rounded)
+            .map(Decimal)
+            .map_err(|_| ArithmeticError::Overflow)
+    }
+
     /// Returns the absolute value.
     pub fn abs(&self) -> PreciseDecimal {
         PreciseDecimal(self.0.abs())
     }
 
+    /// Adds `other`, clamping the result at `MIN` or `MAX` instead of overflowing.
+    pub fn saturating_add(self, other: Self) -> Self {
+        self.checked_add(other).unwrap_or(if other.is_negative() {
+            Self::MIN
+        } else {
+            Self::MAX
+        })
+    }
+
+    /// Subtracts `other`, clamping the result at zero when `other` is greater than `self`,
+    /// and at `MAX` instead of overflowing.
+    pub fn saturating_sub(self, other: Self) -> Self {
+        if other > self {
+            return Self::ZERO;
+        }
+        self.checked_sub(other).unwrap_or(Self::MAX)
+    }
+
+    /// Multiplies by `other`, clamping the result at `MIN` or `MAX` instead of overflowing.
+    pub fn saturating_mul(self, other: Self) -> Self {
+        self.checked_mul(other)
+            .unwrap_or(if self.is_negative() == other.is_negative() {
+                Self::MAX
+            } else {
+                Self::MIN
+            })
+    }
+
     /// Returns the largest integer that is equal to or less than this number.
     pub fn floor(&self) -> Self {
         self.round(0, RoundingMode::TowardsNegativeInfinity)
@@ -171,6 +214,23 @@ impl PreciseDecimal {
                     }
                 }
             }
+            RoundingMode::TowardsNearestAndHalfToEven => {
+                let remainder = (self.0 % divisor).abs();
+                if remainder == I512::zero() {
+                    return self.clone();
+                }
+                let truncated = self.0 / divisor;
+                let twice_remainder = remainder * I512::from(2i8);
+                let round_away = twice_remainder > divisor
+                    || (twice_remainder == divisor && truncated % I512::from(2i8) != I512::zero());
+                if !round_away {
+                    Self(truncated * divisor)
+                } else if self.is_negative() {
+                    Self((truncated - I512::one()) * divisor)
+                } else {
+                    Self((truncated + I512::one()) * divisor)
+                }
+            }
         }
     }
 
@@ -306,6 +366,35 @@ where
     }
 }
 
+impl CheckedAdd for PreciseDecimal {
+    fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(PreciseDecimal)
+    }
+}
+
+impl CheckedSub for PreciseDecimal {
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(PreciseDecimal)
+    }
+}
+
+impl CheckedMul for PreciseDecimal {
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        let c = BigInt::from(self.0) * BigInt::from(other.0) / BigInt::from(Self::ONE.0);
+        I512::try_from(c).ok().map(PreciseDecimal)
+    }
+}
+
+impl CheckedDiv for PreciseDecimal {
+    fn checked_div(self, other: Self) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        let c = BigInt::from(self.0) * BigInt::from(Self::ONE.0) / BigInt::from(other.0);
+        I512::try_from(c).ok().map(PreciseDecimal)
+    }
+}
+
 impl Neg for PreciseDecimal {
     type Output = PreciseDecimal;
 
@@ -379,7 +468,12 @@ impl PreciseDecimal {
     }
 }
 
-scrypto_type!(PreciseDecimal, ScryptoType::PreciseDecimal, Vec::new());
+scrypto_type!(
+    PreciseDecimal,
+    ScryptoType::PreciseDecimal,
+    Vec::new(),
+    PreciseDecimal::BITS / 8
+);
 
 //======
 // text
@@ -523,9 +617,26 @@ impl fmt::Display for ParsePreciseDecimalError {
     }
 }
 
+/// Represents an error in decimal arithmetic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArithmeticError {
+    Overflow,
+}
+
+#[cfg(not(feature = "alloc"))]
+impl std::error::Error for ArithmeticError {}
+
+#[cfg(not(feature = "alloc"))]
+impl fmt::Display for ArithmeticError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Rescales the value to `PreciseDecimal::SCALE` decimal places, so `1` converts to `1`.
 impl From<Decimal> for PreciseDecimal {
     fn from(val: Decimal) -> Self {
-        Self(val.0.into())
+        val.to_precise_decimal()
     }
 }
 
@@ -534,15 +645,16 @@ pub trait Truncate<T> {
     fn truncate(self) -> Self::Output;
 }
 
+/// Rescales the value to `Decimal::SCALE` decimal places, dropping the extra digits.
+///
+/// Panics if the integer part does not fit into a `Decimal`; see `to_decimal` for a fallible
+/// conversion.
 impl Truncate<Decimal> for PreciseDecimal {
     type Output = Decimal;
 
     fn truncate(self) -> Self::Output {
-        Decimal(
-            (self.0 / I512::from(10i8).pow(PreciseDecimal::SCALE - PreciseDecimal::SCALE))
-                .try_into()
-                .expect("Overflow"),
-        )
+        self.to_decimal(RoundingMode::TowardsZero)
+            .expect("Overflow")
     }
 }
 
@@ -580,6 +692,7 @@ try_from_integer!(U512, I512);
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::dec;
     use crate::math::precise_decimal::RoundingMode;
     use crate::pdec;
     use sbor::rust::vec;
@@ -1026,6 +1139,24 @@ mod tests {
         assert_eq!(pdec!("-5.5").round(0, mode).to_string(), "-6");
     }
 
+    #[test]
+    fn test_round_towards_nearest_and_half_to_even_precise_decimal() {
+        let mode = RoundingMode::TowardsNearestAndHalfToEven;
+        assert_eq!(pdec!("5.5").round(0, mode).to_string(), "6");
+        assert_eq!(pdec!("2.5").round(0, mode).to_string(), "2");
+        assert_eq!(pdec!("2.51").round(0, mode).to_string(), "3");
+        assert_eq!(pdec!("1.6").round(0, mode).to_string(), "2");
+        assert_eq!(pdec!("1.1").round(0, mode).to_string(), "1");
+        assert_eq!(pdec!("1.0").round(0, mode).to_string(), "1");
+        assert_eq!(pdec!("-1.0").round(0, mode).to_string(), "-1");
+        assert_eq!(pdec!("-1.1").round(0, mode).to_string(), "-1");
+        assert_eq!(pdec!("-1.6").round(0, mode).to_string(), "-2");
+        assert_eq!(pdec!("-2.5").round(0, mode).to_string(), "-2");
+        assert_eq!(pdec!("-5.5").round(0, mode).to_string(), "-6");
+        assert_eq!(pdec!("0.125").round(2, mode).to_string(), "0.12");
+        assert_eq!(pdec!("0.135").round(2, mode).to_string(), "0.14");
+    }
+
     #[test]
     fn test_various_decimal_places_precise_decimal() {
         let mode = RoundingMode::TowardsNearestAndHalfAwayFromZero;
@@ -1116,4 +1247,133 @@ mod tests {
         let pdec = PreciseDecimal::from_str("non_decimal_value");
         assert_eq!(pdec, Err(ParsePreciseDecimalError::InvalidChar('n')));
     }
+
+    #[test]
+    fn test_checked_ops_precise_decimal() {
+        assert_eq!(pdec!("1.5").checked_add(pdec!("2")), Some(pdec!("3.5")));
+        assert_eq!(pdec!("1.5").checked_sub(pdec!("2")), Some(pdec!("-0.5")));
+        assert_eq!(pdec!("1.5").checked_mul(pdec!("2")), Some(pdec!("3")));
+        assert_eq!(pdec!("3").checked_div(pdec!("2")), Some(pdec!("1.5")));
+    }
+
+    #[test]
+    fn test_checked_ops_overflow_precise_decimal() {
+        assert_eq!(PreciseDecimal::MAX.checked_add(PreciseDecimal::ONE), None);
+        assert_eq!(PreciseDecimal::MIN.checked_sub(PreciseDecimal::ONE), None);
+        assert_eq!(PreciseDecimal::MAX.checked_mul(pdec!("2")), None);
+        assert_eq!(PreciseDecimal::ONE.checked_div(PreciseDecimal::ZERO), None);
+    }
+
+    #[test]
+    fn test_saturating_ops_precise_decimal() {
+        assert_eq!(pdec!("1.5").saturating_add(pdec!("2")), pdec!("3.5"));
+        assert_eq!(pdec!("2").saturating_sub(pdec!("1.5")), pdec!("0.5"));
+        assert_eq!(pdec!("1.5").saturating_mul(pdec!("2")), pdec!("3"));
+    }
+
+    #[test]
+    fn test_saturating_sub_clamps_at_zero_precise_decimal() {
+        assert_eq!(
+            pdec!("1.5").saturating_sub(pdec!("2")),
+            PreciseDecimal::ZERO
+        );
+        assert_eq!(pdec!("-1").saturating_sub(pdec!("1")), PreciseDecimal::ZERO);
+        assert_eq!(pdec!("2").saturating_sub(pdec!("2")), PreciseDecimal::ZERO);
+    }
+
+    #[test]
+    fn test_saturating_ops_clamp_at_bounds_precise_decimal() {
+        assert_eq!(
+            PreciseDecimal::MAX.saturating_add(PreciseDecimal::ONE),
+            PreciseDecimal::MAX
+        );
+        assert_eq!(
+            PreciseDecimal::MIN.saturating_add(-PreciseDecimal::ONE),
+            PreciseDecimal::MIN
+        );
+        assert_eq!(
+            PreciseDecimal::MAX.saturating_sub(-PreciseDecimal::ONE),
+            PreciseDecimal::MAX
+        );
+        assert_eq!(
+            PreciseDecimal::MAX.saturating_mul(pdec!("2")),
+            PreciseDecimal::MAX
+        );
+        assert_eq!(
+            PreciseDecimal::MAX.saturating_mul(pdec!("-2")),
+            PreciseDecimal::MIN
+        );
+    }
+
+    #[test]
+    fn test_to_decimal_precise_decimal() {
+        let a = pdec!("1.123456789012345678901");
+        assert_eq!(
+            a.to_decimal(RoundingMode::TowardsZero),
+            Ok(dec!("1.123456789012345678"))
+        );
+        assert_eq!(
+            a.to_decimal(RoundingMode::AwayFromZero),
+            Ok(dec!("1.123456789012345679"))
+        );
+        assert_eq!(
+            (-a).to_decimal(RoundingMode::TowardsNegativeInfinity),
+            Ok(dec!("-1.123456789012345679"))
+        );
+        assert_eq!(
+            pdec!("42").to_decimal(RoundingMode::TowardsZero),
+            Ok(dec!("42"))
+        );
+    }
+
+    #[test]
+    fn test_to_decimal_overflow_precise_decimal() {
+        let too_large = Decimal::MAX.to_precise_decimal() + pdec!("1");
+        assert_eq!(
+            too_large.to_decimal(RoundingMode::TowardsZero),
+            Err(ArithmeticError::Overflow)
+        );
+        assert_eq!(
+            PreciseDecimal::MAX.to_decimal(RoundingMode::TowardsZero),
+            Err(ArithmeticError::Overflow)
+        );
+        assert_eq!(
+            Decimal::MAX
+                .to_precise_decimal()
+                .to_decimal(RoundingMode::TowardsZero),
+            Ok(Decimal::MAX)
+        );
+    }
+
+    #[test]
+    fn test_from_decimal_precise_decimal() {
+        assert_eq!(dec!("1.5").to_precise_decimal(), pdec!("1.5"));
+        assert_eq!(
+            PreciseDecimal::from(dec!("-0.000000000000000001")),
+            pdec!("-0.000000000000000001")
+        );
+        assert_eq!(pdec!("2.999").truncate(), dec!("2.999"));
+    }
+
+    #[test]
+    fn test_min_max_precise_decimal() {
+        assert_eq!(pdec!("1.5").min(pdec!("-2")), pdec!("-2"));
+        assert_eq!(pdec!("1.5").max(pdec!("-2")), pdec!("1.5"));
+        assert_eq!(pdec!("3").min(pdec!("3")), pdec!("3"));
+        assert_eq!(pdec!("3").max(pdec!("3")), pdec!("3"));
+    }
+
+    #[test]
+    fn test_clamp_precise_decimal() {
+        assert_eq!(pdec!("5").clamp(pdec!("1"), pdec!("3")), pdec!("3"));
+        assert_eq!(pdec!("-5").clamp(pdec!("1"), pdec!("3")), pdec!("1"));
+        assert_eq!(pdec!("2").clamp(pdec!("1"), pdec!("3")), pdec!("2"));
+        assert_eq!(pdec!("2").clamp(pdec!("2"), pdec!("2")), pdec!("2"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_clamp_invalid_bounds_precise_decimal() {
+        let _ = pdec!("2").clamp(pdec!("3"), pdec!("1"));
+    }
 }
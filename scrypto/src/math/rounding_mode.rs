@@ -13,4 +13,6 @@ pub enum RoundingMode {
     TowardsNearestAndHalfTowardsZero,
     /// Rounds to the nearest and when a number is halfway between two others, it's rounded away zero, e.g. `3.5 -> 4`, `-3.5 -> -4`.
     TowardsNearestAndHalfAwayFromZero,
+    /// Rounds to the nearest and when a number is halfway between two others, it's rounded towards the even one, e.g. `2.5 -> 2`, `3.5 -> 4`, `-2.5 -> -2`.
+    TowardsNearestAndHalfToEven,
 }
@@ -0,0 +1,47 @@
+use crate::resource::*;
+
+/// Composes an `AccessRule` at runtime, e.g. from a list of signers only known after instantiation.
+///
+/// Chaining `or`/`and` yields the same rule as the equivalent `rule!` expression.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AccessRuleBuilder {
+    node: Option<AccessRuleNode>,
+}
+
+impl AccessRuleBuilder {
+    pub fn new() -> Self {
+        Self { node: None }
+    }
+
+    /// Requires either the rule built so far or the given one.
+    pub fn or<N: Into<AccessRuleNode>>(mut self, other: N) -> Self {
+        self.node = Some(match self.node {
+            Some(node) => node.or(other.into()),
+            None => other.into(),
+        });
+        self
+    }
+
+    /// Requires both the rule built so far and the given one.
+    pub fn and<N: Into<AccessRuleNode>>(mut self, other: N) -> Self {
+        self.node = Some(match self.node {
+            Some(node) => node.and(other.into()),
+            None => other.into(),
+        });
+        self
+    }
+
+    /// Builds the access rule; a builder with no requirements denies all.
+    pub fn build(self) -> AccessRule {
+        match self.node {
+            Some(node) => AccessRule::Protected(node),
+            None => AccessRule::DenyAll,
+        }
+    }
+}
+
+impl From<ProofRule> for AccessRuleNode {
+    fn from(proof_rule: ProofRule) -> Self {
+        AccessRuleNode::ProofRule(proof_rule)
+    }
+}
@@ -1,3 +1,4 @@
+mod access_rule_builder;
 mod access_rules;
 mod auth_zone;
 mod bucket;
@@ -13,15 +14,17 @@ mod resource_manager;
 mod resource_type;
 mod schema_path;
 mod system;
+mod transfer_hook;
 mod vault;
 
+pub use access_rule_builder::AccessRuleBuilder;
 pub use access_rules::AccessRules;
 pub use auth_zone::*;
 pub use bucket::*;
 pub use mint_params::MintParams;
 pub use non_fungible::NonFungible;
 pub use non_fungible_address::{NonFungibleAddress, ParseNonFungibleAddressError};
-pub use non_fungible_data::NonFungibleData;
+pub use non_fungible_data::{NonFungibleData, NonFungibleDataSchema};
 pub use non_fungible_id::{NonFungibleId, ParseNonFungibleIdError};
 pub use proof::*;
 pub use proof_rule::{
@@ -36,4 +39,5 @@ pub use resource_manager::*;
 pub use resource_type::ResourceType;
 pub use schema_path::SchemaPath;
 pub use system::{init_resource_system, resource_system, ResourceSystem};
+pub use transfer_hook::{TransferHook, TransferHookInput, TransferHookOutcome, TransferKind};
 pub use vault::*;
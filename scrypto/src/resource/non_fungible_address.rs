@@ -147,7 +147,9 @@ impl fmt::Debug for NonFungibleAddress {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::crypto::EcdsaSecp256k1PublicKey;
     use crate::sbor::rust::string::ToString;
+    use crate::sbor::rust::vec;
 
     #[test]
     pub fn non_fungible_address_from_and_to_string_succeeds() {
@@ -169,4 +171,47 @@ mod tests {
         // Assert
         assert_eq!(converted_non_fungible_address, Ok(non_fungible_address));
     }
+
+    #[test]
+    pub fn non_fungible_address_to_and_from_bytes_succeeds() {
+        // Arrange
+        let non_fungible_address =
+            NonFungibleAddress::new(ECDSA_TOKEN, NonFungibleId::from_bytes(vec![1u8, 2, 3]));
+
+        // Act
+        let bytes = non_fungible_address.to_vec();
+        let converted_non_fungible_address = NonFungibleAddress::try_from(bytes.as_slice());
+
+        // Assert
+        assert_eq!(converted_non_fungible_address, Ok(non_fungible_address));
+        assert_eq!(
+            converted_non_fungible_address.unwrap().to_vec(),
+            bytes,
+            "Round trip should be stable"
+        );
+    }
+
+    #[test]
+    pub fn non_fungible_address_from_public_key_should_expose_its_parts() {
+        // Arrange
+        let public_key = EcdsaSecp256k1PublicKey([7u8; EcdsaSecp256k1PublicKey::LENGTH]);
+
+        // Act
+        let non_fungible_address = NonFungibleAddress::from_public_key(&public_key);
+
+        // Assert
+        assert_eq!(non_fungible_address.resource_address(), ECDSA_TOKEN);
+        assert_eq!(
+            non_fungible_address.non_fungible_id(),
+            NonFungibleId::from_bytes(public_key.to_vec())
+        );
+    }
+
+    #[test]
+    pub fn non_fungible_address_from_too_short_bytes_fails() {
+        assert_eq!(
+            NonFungibleAddress::try_from([0u8; 26].as_slice()),
+            Err(ParseNonFungibleAddressError::InvalidLength(26))
+        );
+    }
 }
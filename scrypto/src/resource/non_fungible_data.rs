@@ -1,6 +1,23 @@
 use sbor::rust::vec::Vec;
 use sbor::{describe::*, *};
 
+/// The schemas of the immutable and mutable data parts of a non-fungible.
+#[derive(Debug, Clone, PartialEq, Eq, TypeId, Encode, Decode)]
+pub struct NonFungibleDataSchema {
+    pub immutable_data: Type,
+    pub mutable_data: Type,
+}
+
+impl NonFungibleDataSchema {
+    /// Returns the schema of the given non-fungible data type.
+    pub fn of<T: NonFungibleData>() -> Self {
+        Self {
+            immutable_data: T::immutable_data_schema(),
+            mutable_data: T::mutable_data_schema(),
+        }
+    }
+}
+
 /// Represents the data structure of a non-fungible.
 pub trait NonFungibleData {
     /// Decodes `Self` from the serialized immutable and mutable parts.
@@ -18,11 +18,14 @@ pub struct FungibleResourceBuilder {
     divisibility: u8,
     metadata: HashMap<String, String>,
     authorization: HashMap<ResourceMethodAuthKey, (AccessRule, Mutability)>,
+    supply_cap: Option<Decimal>,
 }
 
 pub struct NonFungibleResourceBuilder {
     metadata: HashMap<String, String>,
     authorization: HashMap<ResourceMethodAuthKey, (AccessRule, Mutability)>,
+    supply_cap: Option<Decimal>,
+    data_schema: Option<NonFungibleDataSchema>,
 }
 
 impl ResourceBuilder {
@@ -43,6 +46,7 @@ impl FungibleResourceBuilder {
             divisibility: DIVISIBILITY_MAXIMUM,
             metadata: HashMap::new(),
             authorization: HashMap::new(),
+            supply_cap: None,
         }
     }
 
@@ -104,6 +108,24 @@ impl FungibleResourceBuilder {
         self
     }
 
+    pub fn updateable_transfer_hook(
+        &mut self,
+        method_auth: AccessRule,
+        mutability: Mutability,
+    ) -> &mut Self {
+        self.authorization
+            .insert(UpdateTransferHook, (method_auth, mutability));
+        self
+    }
+
+    /// Caps the total supply, including the initial supply, at the given amount.
+    ///
+    /// The cap can't be changed once the resource is created.
+    pub fn supply_cap<T: Into<Decimal>>(&mut self, amount: T) -> &mut Self {
+        self.supply_cap = Some(amount.into());
+        self
+    }
+
     /// Creates resource with the given initial supply.
     ///
     /// # Example
@@ -134,6 +156,8 @@ impl FungibleResourceBuilder {
             self.metadata.clone(),
             authorization,
             mint_params,
+            self.supply_cap,
+            None,
         )
     }
 }
@@ -143,6 +167,8 @@ impl NonFungibleResourceBuilder {
         Self {
             metadata: HashMap::new(),
             authorization: HashMap::new(),
+            supply_cap: None,
+            data_schema: None,
         }
     }
 
@@ -195,6 +221,16 @@ impl NonFungibleResourceBuilder {
         self
     }
 
+    pub fn updateable_transfer_hook(
+        &mut self,
+        method_auth: AccessRule,
+        mutability: Mutability,
+    ) -> &mut Self {
+        self.authorization
+            .insert(UpdateTransferHook, (method_auth, mutability));
+        self
+    }
+
     pub fn updateable_non_fungible_data(
         &mut self,
         method_auth: AccessRule,
@@ -205,6 +241,21 @@ impl NonFungibleResourceBuilder {
         self
     }
 
+    /// Caps the total number of non-fungibles, including the initial supply.
+    ///
+    /// The cap can't be changed once the resource is created.
+    pub fn supply_cap(&mut self, count: u64) -> &mut Self {
+        self.supply_cap = Some(count.into());
+        self
+    }
+
+    /// Requires the data of every non-fungible to be of the given type, which is checked
+    /// whenever non-fungibles are minted or their data updated.
+    pub fn data_schema<T: NonFungibleData>(&mut self) -> &mut Self {
+        self.data_schema = Some(NonFungibleDataSchema::of::<T>());
+        self
+    }
+
     /// Creates resource with the given initial supply.
     ///
     /// # Example
@@ -242,6 +293,8 @@ impl NonFungibleResourceBuilder {
             self.metadata.clone(),
             authorization,
             mint_params,
+            self.supply_cap,
+            self.data_schema.clone(),
         )
     }
 }
@@ -8,8 +8,10 @@ use sbor::*;
 use crate::abi::*;
 use crate::address::{AddressError, EntityType, BECH32_DECODER, BECH32_ENCODER};
 use crate::buffer::scrypto_encode;
+use crate::component::PackageAddress;
 use crate::core::NativeFnIdentifier;
 use crate::core::{FnIdentifier, Receiver, ResourceManagerFnIdentifier};
+use crate::crypto::{hash, Hash};
 use crate::engine::types::RENodeId;
 use crate::engine::{api::*, call_engine};
 use crate::math::*;
@@ -27,6 +29,7 @@ pub enum ResourceMethodAuthKey {
     Deposit,
     UpdateMetadata,
     UpdateNonFungibleData,
+    UpdateTransferHook,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, TypeId, Encode, Decode, Describe)]
@@ -41,6 +44,16 @@ pub struct ResourceManagerCreateInput {
     pub metadata: HashMap<String, String>,
     pub access_rules: HashMap<ResourceMethodAuthKey, (AccessRule, Mutability)>,
     pub mint_params: Option<MintParams>,
+    /// The maximum total supply, which can't be changed afterwards.
+    pub supply_cap: Option<Decimal>,
+    /// The schema which the data of every non-fungible has to match.
+    pub non_fungible_data_schema: Option<NonFungibleDataSchema>,
+}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct ResourceManagerCreateWithAddressSeedInput {
+    pub address_seed: Hash,
+    pub create: ResourceManagerCreateInput,
 }
 
 #[derive(Debug, TypeId, Encode, Decode)]
@@ -85,6 +98,11 @@ pub struct ResourceManagerUpdateNonFungibleDataInput {
     pub data: Vec<u8>,
 }
 
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct ResourceManagerSetTransferHookInput {
+    pub transfer_hook: Option<TransferHook>,
+}
+
 #[derive(Debug, TypeId, Encode, Decode)]
 pub struct ResourceManagerNonFungibleExistsInput {
     pub id: NonFungibleId,
@@ -101,13 +119,54 @@ pub enum ResourceAddress {
     Normal([u8; 26]),
 }
 
-impl ResourceAddress {}
+impl ResourceAddress {
+    /// Derives the address of a resource created with the given address seed by a blueprint
+    /// of the given package.
+    pub fn from_address_seed(creator: PackageAddress, address_seed: Hash) -> Self {
+        let mut data = b"resource_address_seed".to_vec();
+        data.extend(creator.to_vec());
+        data.extend(address_seed.to_vec());
+        Self::Normal(hash(data).lower_26_bytes())
+    }
+}
 
 /// Represents a resource manager.
 #[derive(Debug)]
 pub struct ResourceManager(pub(crate) ResourceAddress);
 
 impl ResourceManager {
+    /// Creates a new resource at the address derived from the given seed and the package of
+    /// the calling blueprint, see `ResourceAddress::from_address_seed`.
+    ///
+    /// A bucket is returned iif an initial supply is provided.
+    pub fn create_with_address_seed(
+        address_seed: Hash,
+        resource_type: ResourceType,
+        metadata: HashMap<String, String>,
+        access_rules: HashMap<ResourceMethodAuthKey, (AccessRule, Mutability)>,
+        mint_params: Option<MintParams>,
+        supply_cap: Option<Decimal>,
+        non_fungible_data_schema: Option<NonFungibleDataSchema>,
+    ) -> (ResourceAddress, Option<Bucket>) {
+        let input = RadixEngineInput::InvokeFunction(
+            FnIdentifier::Native(NativeFnIdentifier::ResourceManager(
+                ResourceManagerFnIdentifier::CreateWithAddressSeed,
+            )),
+            scrypto_encode(&ResourceManagerCreateWithAddressSeedInput {
+                address_seed,
+                create: ResourceManagerCreateInput {
+                    resource_type,
+                    metadata,
+                    access_rules,
+                    mint_params,
+                    supply_cap,
+                    non_fungible_data_schema,
+                },
+            }),
+        );
+        call_engine(input)
+    }
+
     pub fn set_mintable(&mut self, access_rule: AccessRule) -> () {
         let input = RadixEngineInput::InvokeMethod(
             Receiver::Ref(RENodeId::ResourceManager(self.0)),
@@ -192,6 +251,20 @@ impl ResourceManager {
         call_engine(input)
     }
 
+    pub fn set_updateable_transfer_hook(&self, access_rule: AccessRule) -> () {
+        let input = RadixEngineInput::InvokeMethod(
+            Receiver::Ref(RENodeId::ResourceManager(self.0)),
+            FnIdentifier::Native(NativeFnIdentifier::ResourceManager(
+                ResourceManagerFnIdentifier::UpdateAuth,
+            )),
+            scrypto_encode(&ResourceManagerUpdateAuthInput {
+                method: ResourceMethodAuthKey::UpdateTransferHook,
+                access_rule,
+            }),
+        );
+        call_engine(input)
+    }
+
     pub fn lock_mintable(&mut self) -> () {
         let input = RadixEngineInput::InvokeMethod(
             Receiver::Ref(RENodeId::ResourceManager(self.0)),
@@ -270,6 +343,19 @@ impl ResourceManager {
         call_engine(input)
     }
 
+    pub fn lock_updateable_transfer_hook(&mut self) -> () {
+        let input = RadixEngineInput::InvokeMethod(
+            Receiver::Ref(RENodeId::ResourceManager(self.0)),
+            FnIdentifier::Native(NativeFnIdentifier::ResourceManager(
+                ResourceManagerFnIdentifier::LockAuth,
+            )),
+            scrypto_encode(&ResourceManagerLockAuthInput {
+                method: ResourceMethodAuthKey::UpdateTransferHook,
+            }),
+        );
+        call_engine(input)
+    }
+
     fn mint_internal(&mut self, mint_params: MintParams) -> Bucket {
         let input = RadixEngineInput::InvokeMethod(
             Receiver::Ref(RENodeId::ResourceManager(self.0)),
@@ -323,6 +409,12 @@ impl ResourceManager {
                     metadata
                 }
             }
+            pub fn set_transfer_hook(&mut self, transfer_hook: Option<TransferHook>) -> () {
+                ResourceManagerFnIdentifier::SetTransferHook,
+                ResourceManagerSetTransferHookInput {
+                    transfer_hook
+                }
+            }
             pub fn non_fungible_exists(&self, id: &NonFungibleId) -> bool {
                 ResourceManagerFnIdentifier::NonFungibleExists,
                 ResourceManagerNonFungibleExistsInput {
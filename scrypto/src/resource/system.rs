@@ -4,6 +4,7 @@ use sbor::rust::string::String;
 use crate::buffer::scrypto_encode;
 use crate::core::{FnIdentifier, NativeFnIdentifier, ResourceManagerFnIdentifier};
 use crate::engine::{api::*, call_engine};
+use crate::math::Decimal;
 use crate::resource::*;
 
 /// Represents the Radix Engine resource subsystem.
@@ -46,6 +47,8 @@ impl ResourceSystem {
         metadata: HashMap<String, String>,
         access_rules: HashMap<ResourceMethodAuthKey, (AccessRule, Mutability)>,
         mint_params: Option<MintParams>,
+        supply_cap: Option<Decimal>,
+        non_fungible_data_schema: Option<NonFungibleDataSchema>,
     ) -> (ResourceAddress, Option<Bucket>) {
         let input = RadixEngineInput::InvokeFunction(
             FnIdentifier::Native(NativeFnIdentifier::ResourceManager(
@@ -56,6 +59,8 @@ impl ResourceSystem {
                 metadata,
                 access_rules,
                 mint_params,
+                supply_cap,
+                non_fungible_data_schema,
             }),
         );
         call_engine(input)
@@ -0,0 +1,57 @@
+use sbor::rust::borrow::ToOwned;
+use sbor::rust::string::String;
+use sbor::*;
+
+use crate::component::ComponentAddress;
+use crate::math::*;
+use crate::resource::*;
+
+/// A component method which the engine invokes on every vault deposit and withdrawal
+/// of a resource.
+///
+/// The method receives a single `TransferHookInput` and returns a `TransferHookOutcome`.
+#[derive(Debug, Clone, PartialEq, Eq, TypeId, Encode, Decode, Describe)]
+pub struct TransferHook {
+    pub component_address: ComponentAddress,
+    pub method_name: String,
+}
+
+impl TransferHook {
+    pub fn new<S: AsRef<str>>(component_address: ComponentAddress, method_name: S) -> Self {
+        Self {
+            component_address,
+            method_name: method_name.as_ref().to_owned(),
+        }
+    }
+}
+
+/// The direction of a transfer, from the point of view of the vault.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TypeId, Encode, Decode, Describe)]
+pub enum TransferKind {
+    Deposit,
+    Withdraw,
+}
+
+/// Describes the transfer a hook is asked to approve.
+#[derive(Debug, Clone, PartialEq, Eq, TypeId, Encode, Decode, Describe)]
+pub struct TransferHookInput {
+    pub kind: TransferKind,
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+    /// The component moving resources in or out of its vault, if the vault is
+    /// operated by a component.
+    pub component_address: Option<ComponentAddress>,
+}
+
+/// The verdict of a transfer hook.
+#[derive(Debug, Clone, PartialEq, Eq, TypeId, Encode, Decode, Describe)]
+pub enum TransferHookOutcome {
+    /// Let the transfer through unchanged.
+    Accept,
+    /// Fail the transaction.
+    Reject,
+    /// Withdraw the given amount instead, which must not exceed the requested amount.
+    ///
+    /// Only supported on withdrawal of fungible resources; adjusting a deposit fails it.
+    Adjust(Decimal),
+}
@@ -11,7 +11,7 @@ use sbor::type_id::*;
 use sbor::{any::*, *};
 
 use crate::abi::*;
-use crate::address::AddressError;
+use crate::address::{AddressError, Bech32Encoder};
 use crate::buffer::*;
 use crate::component::*;
 use crate::core::*;
@@ -21,12 +21,30 @@ use crate::math::*;
 use crate::misc::copy_u8_array;
 use crate::resource::*;
 
+/// The kind of difference found at a path by `ScryptoValue::diff`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+/// Represents an error when validating the nodes carried by a Scrypto value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScryptoValueValidationError {
+    VaultNotAllowed(VaultId),
+    KeyValueStoreNotAllowed(KeyValueStoreId),
+    ComponentNotAllowed(ComponentAddress),
+}
+
 pub enum ScryptoValueReplaceError {
     ProofIdNotFound(ProofId),
     BucketIdNotFound(BucketId),
 }
 
 /// A Scrypto value is a SBOR value of which the custom types are the ones defined by `ScryptoType`.
+///
+/// Note that `==` compares the `raw` bytes too; use `semantic_eq` to ignore the encoding.
 #[derive(Clone, PartialEq, Eq)]
 pub struct ScryptoValue {
     pub raw: Vec<u8>,
@@ -56,6 +74,23 @@ impl ScryptoValue {
         Self::from_value(value)
     }
 
+    /// Decodes the `len` bytes at `ptr` of a WASM memory, without copying them first.
+    pub fn from_wasm_memory(
+        ptr: usize,
+        len: usize,
+        memory_data: &[u8],
+    ) -> Result<Self, DecodeError> {
+        let slice = ptr
+            .checked_add(len)
+            .and_then(|end| memory_data.get(ptr..end))
+            .ok_or(DecodeError::Underflow {
+                required: len,
+                remaining: memory_data.len().saturating_sub(ptr),
+                while_decoding: None,
+            })?;
+        Self::from_slice(slice)
+    }
+
     pub fn from_value(value: Value) -> Result<Self, DecodeError> {
         let mut checker = ScryptoCustomValueChecker::new();
         traverse_any(&mut MutableSborPath::new(), &value, &mut checker)
@@ -102,6 +137,58 @@ impl ScryptoValue {
         })
     }
 
+    /// Returns the value at the given path, if any.
+    pub fn get_at_path<'a>(&'a self, path: &'a SborPath) -> Option<&'a Value> {
+        path.get_from_value(&self.dom)
+    }
+
+    /// Decodes the value at the given path into `T`.
+    pub fn get_typed_at_path<T: Decode>(&self, path: &SborPath) -> Result<T, DecodeError> {
+        let value = self
+            .get_at_path(path)
+            .ok_or_else(|| DecodeError::CustomError(format!("No value at {:?}", path)))?;
+        scrypto_decode(&encode_any(value))
+    }
+
+    /// Returns the leaf-level differences between `self` and `other`, walking both trees in lockstep.
+    /// Map entries are matched by key and set elements by membership, rather than by position.
+    pub fn diff(&self, other: &ScryptoValue) -> Vec<(SborPath, DiffKind)> {
+        let mut diffs = Vec::new();
+        diff_values(
+            &mut MutableSborPath::new(),
+            &self.dom,
+            &other.dom,
+            &mut diffs,
+        );
+        diffs
+    }
+
+    /// Compares the decoded value trees, ignoring `raw` and the order of map entries and set
+    /// elements, which an encoding may list in any order.
+    pub fn semantic_eq(&self, other: &Self) -> bool {
+        self.diff(other).is_empty()
+    }
+
+    /// Checks that the value doesn't carry any owned vault, key value store or component.
+    ///
+    /// The smallest offending id is reported, so the error doesn't depend on set iteration order.
+    pub fn validate_no_stored_nodes(&self) -> Result<(), ScryptoValueValidationError> {
+        if let Some(vault_id) = self.vault_ids.iter().min() {
+            return Err(ScryptoValueValidationError::VaultNotAllowed(*vault_id));
+        }
+        if let Some(kv_store_id) = self.kv_store_ids.iter().min() {
+            return Err(ScryptoValueValidationError::KeyValueStoreNotAllowed(
+                *kv_store_id,
+            ));
+        }
+        if let Some(component_address) = self.owned_component_addresses.iter().min() {
+            return Err(ScryptoValueValidationError::ComponentNotAllowed(
+                *component_address,
+            ));
+        }
+        Ok(())
+    }
+
     pub fn node_ids(&self) -> HashSet<RENodeId> {
         let mut node_ids = HashSet::new();
         for vault_id in &self.vault_ids {
@@ -206,6 +293,189 @@ impl ScryptoValue {
     }
 }
 
+fn diff_values(
+    path: &mut MutableSborPath,
+    before: &Value,
+    after: &Value,
+    diffs: &mut Vec<(SborPath, DiffKind)>,
+) {
+    match (before, after) {
+        (Value::Struct { fields: a }, Value::Struct { fields: b })
+        | (Value::Tuple { elements: a }, Value::Tuple { elements: b }) => {
+            diff_elements(path, a, b, diffs);
+        }
+        (
+            Value::Enum {
+                name: name_a,
+                fields: a,
+            },
+            Value::Enum {
+                name: name_b,
+                fields: b,
+            },
+        ) if name_a == name_b => {
+            diff_elements(path, a, b, diffs);
+        }
+        (Value::Option { value: a }, Value::Option { value: b }) => {
+            path.push(0);
+            match (a.borrow(), b.borrow()) {
+                (Some(x), Some(y)) => diff_values(path, x, y, diffs),
+                (Some(_), None) => diffs.push((path.clone().into(), DiffKind::Removed)),
+                (None, Some(_)) => diffs.push((path.clone().into(), DiffKind::Added)),
+                (None, None) => {}
+            }
+            path.pop();
+        }
+        (Value::Result { value: a }, Value::Result { value: b }) => {
+            match (a.borrow(), b.borrow()) {
+                (Ok(x), Ok(y)) | (Err(x), Err(y)) => {
+                    path.push(0);
+                    diff_values(path, x, y, diffs);
+                    path.pop();
+                }
+                _ => diffs.push((path.clone().into(), DiffKind::Changed)),
+            }
+        }
+        (
+            Value::Array {
+                element_type_id: type_a,
+                elements: a,
+            },
+            Value::Array {
+                element_type_id: type_b,
+                elements: b,
+            },
+        )
+        | (
+            Value::List {
+                element_type_id: type_a,
+                elements: a,
+            },
+            Value::List {
+                element_type_id: type_b,
+                elements: b,
+            },
+        ) if type_a == type_b => {
+            diff_elements(path, a, b, diffs);
+        }
+        (
+            Value::Set {
+                element_type_id: type_a,
+                elements: a,
+            },
+            Value::Set {
+                element_type_id: type_b,
+                elements: b,
+            },
+        ) if type_a == type_b => {
+            diff_set_elements(path, a, b, diffs);
+        }
+        (
+            Value::Map {
+                key_type_id: key_a,
+                value_type_id: value_a,
+                elements: a,
+            },
+            Value::Map {
+                key_type_id: key_b,
+                value_type_id: value_b,
+                elements: b,
+            },
+        ) if key_a == key_b && value_a == value_b => {
+            diff_map_elements(path, a, b, diffs);
+        }
+        _ => {
+            if before != after {
+                diffs.push((path.clone().into(), DiffKind::Changed));
+            }
+        }
+    }
+}
+
+fn diff_elements(
+    path: &mut MutableSborPath,
+    before: &[Value],
+    after: &[Value],
+    diffs: &mut Vec<(SborPath, DiffKind)>,
+) {
+    for i in 0..before.len().max(after.len()) {
+        path.push(i);
+        match (before.get(i), after.get(i)) {
+            (Some(a), Some(b)) => diff_values(path, a, b, diffs),
+            (Some(_), None) => diffs.push((path.clone().into(), DiffKind::Removed)),
+            (None, Some(_)) => diffs.push((path.clone().into(), DiffKind::Added)),
+            (None, None) => {}
+        }
+        path.pop();
+    }
+}
+
+/// Matches set elements by membership. Removed elements are reported at their index in `before`
+/// and added elements at their index in `after`.
+fn diff_set_elements(
+    path: &mut MutableSborPath,
+    before: &[Value],
+    after: &[Value],
+    diffs: &mut Vec<(SborPath, DiffKind)>,
+) {
+    for (i, element) in before.iter().enumerate() {
+        if !after.contains(element) {
+            path.push(i);
+            diffs.push((path.clone().into(), DiffKind::Removed));
+            path.pop();
+        }
+    }
+    for (i, element) in after.iter().enumerate() {
+        if !before.contains(element) {
+            path.push(i);
+            diffs.push((path.clone().into(), DiffKind::Added));
+            path.pop();
+        }
+    }
+}
+
+fn find_map_value<'a>(entries: &'a [Value], key: &Value) -> Option<&'a Value> {
+    entries
+        .chunks(2)
+        .position(|entry| &entry[0] == key)
+        .and_then(|i| entries.get(2 * i + 1))
+}
+
+/// Matches map entries by key. Removed entries are reported at their indices in `before`, while
+/// added entries and changes to the values of kept entries are reported at their indices in `after`.
+fn diff_map_elements(
+    path: &mut MutableSborPath,
+    before: &[Value],
+    after: &[Value],
+    diffs: &mut Vec<(SborPath, DiffKind)>,
+) {
+    for (i, entry) in before.chunks(2).enumerate() {
+        if find_map_value(after, &entry[0]).is_none() {
+            for j in 2 * i..2 * i + entry.len() {
+                path.push(j);
+                diffs.push((path.clone().into(), DiffKind::Removed));
+                path.pop();
+            }
+        }
+    }
+    for (i, entry) in after.chunks(2).enumerate() {
+        match (find_map_value(before, &entry[0]), entry.get(1)) {
+            (Some(a), Some(b)) => {
+                path.push(2 * i + 1);
+                diff_values(path, a, b, diffs);
+                path.pop();
+            }
+            _ => {
+                for j in 2 * i..2 * i + entry.len() {
+                    path.push(j);
+                    diffs.push((path.clone().into(), DiffKind::Added));
+                    path.pop();
+                }
+            }
+        }
+    }
+}
+
 impl fmt::Debug for ScryptoValue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.to_string())
@@ -252,6 +522,8 @@ pub struct ScryptoCustomValueChecker {
     pub components: HashSet<Component>,
     pub ref_components: HashSet<ComponentAddress>,
     pub resource_addresses: HashSet<ResourceAddress>,
+    pub max_buckets: Option<usize>,
+    pub max_proofs: Option<usize>,
 }
 
 /// Represents an error when validating a Scrypto-specific value.
@@ -277,6 +549,8 @@ pub enum ScryptoCustomValueCheckError {
     InvalidExpression(ParseExpressionError),
     InvalidBlob(ParseBlobError),
     DuplicateIds,
+    TooManyBuckets,
+    TooManyProofs,
 }
 
 impl ScryptoCustomValueChecker {
@@ -290,6 +564,17 @@ impl ScryptoCustomValueChecker {
             components: HashSet::new(),
             ref_components: HashSet::new(),
             resource_addresses: HashSet::new(),
+            max_buckets: None,
+            max_proofs: None,
+        }
+    }
+
+    /// Creates a checker which fails as soon as more than the given number of buckets or proofs are found.
+    pub fn new_with_limits(max_buckets: usize, max_proofs: usize) -> Self {
+        Self {
+            max_buckets: Some(max_buckets),
+            max_proofs: Some(max_proofs),
+            ..Self::new()
         }
     }
 }
@@ -366,6 +651,9 @@ impl CustomValueVisitor for ScryptoCustomValueChecker {
             ScryptoType::Bucket => {
                 let bucket =
                     Bucket::try_from(data).map_err(ScryptoCustomValueCheckError::InvalidBucket)?;
+                if matches!(self.max_buckets, Some(max) if self.buckets.len() >= max) {
+                    return Err(ScryptoCustomValueCheckError::TooManyBuckets);
+                }
                 if self.buckets.insert(bucket, path.clone().into()).is_some() {
                     return Err(ScryptoCustomValueCheckError::DuplicateIds);
                 }
@@ -373,6 +661,9 @@ impl CustomValueVisitor for ScryptoCustomValueChecker {
             ScryptoType::Proof => {
                 let proof =
                     Proof::try_from(data).map_err(ScryptoCustomValueCheckError::InvalidProof)?;
+                if matches!(self.max_proofs, Some(max) if self.proofs.len() >= max) {
+                    return Err(ScryptoCustomValueCheckError::TooManyProofs);
+                }
                 if self.proofs.insert(proof, path.clone().into()).is_some() {
                     return Err(ScryptoCustomValueCheckError::DuplicateIds);
                 }
@@ -418,6 +709,25 @@ impl ScryptoValueFormatter {
         value: &Value,
         bucket_ids: &HashMap<BucketId, String>,
         proof_ids: &HashMap<ProofId, String>,
+    ) -> String {
+        Self::format_value_internal(value, bucket_ids, proof_ids, None)
+    }
+
+    /// Formats a value, rendering package, component and resource addresses in Bech32.
+    pub fn format_value_with_bech32(
+        value: &Value,
+        bucket_ids: &HashMap<BucketId, String>,
+        proof_ids: &HashMap<ProofId, String>,
+        encoder: &Bech32Encoder,
+    ) -> String {
+        Self::format_value_internal(value, bucket_ids, proof_ids, Some(encoder))
+    }
+
+    fn format_value_internal(
+        value: &Value,
+        bucket_ids: &HashMap<BucketId, String>,
+        proof_ids: &HashMap<ProofId, String>,
+        encoder: Option<&Bech32Encoder>,
     ) -> String {
         match value {
             // primitive types
@@ -434,11 +744,14 @@ impl ScryptoValueFormatter {
             Value::U64 { value } => format!("{}u64", value),
             Value::U128 { value } => format!("{}u128", value),
             Value::String { value } => format!("\"{}\"", value),
+            Value::Char { value } => format!("{:?}", value),
+            Value::I256 { value } => format!("{}i256", I256::from_le_bytes(*value)),
+            Value::U256 { value } => format!("{}u256", U256::from_le_bytes(*value)),
             // struct & enum
             Value::Struct { fields } => {
                 format!(
                     "Struct({})",
-                    Self::format_elements(fields, bucket_ids, proof_ids)
+                    Self::format_elements_internal(fields, bucket_ids, proof_ids, encoder)
                 )
             }
             Value::Enum { name, fields } => {
@@ -446,12 +759,15 @@ impl ScryptoValueFormatter {
                     "Enum(\"{}\"{}{})",
                     name,
                     if fields.is_empty() { "" } else { ", " },
-                    Self::format_elements(fields, bucket_ids, proof_ids)
+                    Self::format_elements_internal(fields, bucket_ids, proof_ids, encoder)
                 )
             }
             // rust types
             Value::Option { value } => match value.borrow() {
-                Some(x) => format!("Some({})", Self::format_value(x, bucket_ids, proof_ids)),
+                Some(x) => format!(
+                    "Some({})",
+                    Self::format_value_internal(x, bucket_ids, proof_ids, encoder)
+                ),
                 None => "None".to_string(),
             },
             Value::Array {
@@ -460,15 +776,21 @@ impl ScryptoValueFormatter {
             } => format!(
                 "Array<{}>({})",
                 Self::format_type_id(*element_type_id),
-                Self::format_elements(elements, bucket_ids, proof_ids)
+                Self::format_elements_internal(elements, bucket_ids, proof_ids, encoder)
             ),
             Value::Tuple { elements } => format!(
                 "Tuple({})",
-                Self::format_elements(elements, bucket_ids, proof_ids)
+                Self::format_elements_internal(elements, bucket_ids, proof_ids, encoder)
             ),
             Value::Result { value } => match value.borrow() {
-                Ok(x) => format!("Ok({})", Self::format_value(x, bucket_ids, proof_ids)),
-                Err(x) => format!("Err({})", Self::format_value(x, bucket_ids, proof_ids)),
+                Ok(x) => format!(
+                    "Ok({})",
+                    Self::format_value_internal(x, bucket_ids, proof_ids, encoder)
+                ),
+                Err(x) => format!(
+                    "Err({})",
+                    Self::format_value_internal(x, bucket_ids, proof_ids, encoder)
+                ),
             },
             // collections
             Value::List {
@@ -478,7 +800,7 @@ impl ScryptoValueFormatter {
                 format!(
                     "Vec<{}>({})",
                     Self::format_type_id(*element_type_id),
-                    Self::format_elements(elements, bucket_ids, proof_ids)
+                    Self::format_elements_internal(elements, bucket_ids, proof_ids, encoder)
                 )
             }
             Value::Set {
@@ -487,7 +809,7 @@ impl ScryptoValueFormatter {
             } => format!(
                 "Set<{}>({})",
                 Self::format_type_id(*element_type_id),
-                Self::format_elements(elements, bucket_ids, proof_ids)
+                Self::format_elements_internal(elements, bucket_ids, proof_ids, encoder)
             ),
             Value::Map {
                 key_type_id,
@@ -497,11 +819,11 @@ impl ScryptoValueFormatter {
                 "Map<{}, {}>({})",
                 Self::format_type_id(*key_type_id),
                 Self::format_type_id(*value_type_id),
-                Self::format_elements(elements, bucket_ids, proof_ids)
+                Self::format_elements_internal(elements, bucket_ids, proof_ids, encoder)
             ),
             // custom types
             Value::Custom { type_id, bytes } => {
-                Self::from_custom_value(*type_id, bytes, bucket_ids, proof_ids)
+                Self::from_custom_value_internal(*type_id, bytes, bucket_ids, proof_ids, encoder)
             }
         }
     }
@@ -526,6 +848,9 @@ impl ScryptoValueFormatter {
             TYPE_U64 => "U64",
             TYPE_U128 => "U128",
             TYPE_STRING => "String",
+            TYPE_CHAR => "Char",
+            TYPE_I256 => "I256",
+            TYPE_U256 => "U256",
             // struct & enum
             TYPE_STRUCT => "Struct",
             TYPE_ENUM => "Enum",
@@ -548,21 +873,41 @@ impl ScryptoValueFormatter {
         values: &[Value],
         bucket_ids: &HashMap<BucketId, String>,
         proof_ids: &HashMap<ProofId, String>,
+    ) -> String {
+        Self::format_elements_internal(values, bucket_ids, proof_ids, None)
+    }
+
+    fn format_elements_internal(
+        values: &[Value],
+        bucket_ids: &HashMap<BucketId, String>,
+        proof_ids: &HashMap<ProofId, String>,
+        encoder: Option<&Bech32Encoder>,
     ) -> String {
         let mut buf = String::new();
         for (i, x) in values.iter().enumerate() {
             if i != 0 {
                 buf.push_str(", ");
             }
-            buf.push_str(Self::format_value(x, bucket_ids, proof_ids).as_str());
+            buf.push_str(Self::format_value_internal(x, bucket_ids, proof_ids, encoder).as_str());
         }
         buf
     }
+
     pub fn from_custom_value(
         type_id: u8,
         data: &[u8],
         bucket_ids: &HashMap<BucketId, String>,
         proof_ids: &HashMap<ProofId, String>,
+    ) -> String {
+        Self::from_custom_value_internal(type_id, data, bucket_ids, proof_ids, None)
+    }
+
+    fn from_custom_value_internal(
+        type_id: u8,
+        data: &[u8],
+        bucket_ids: &HashMap<BucketId, String>,
+        proof_ids: &HashMap<ProofId, String>,
+        encoder: Option<&Bech32Encoder>,
     ) -> String {
         match ScryptoType::from_id(type_id).unwrap() {
             ScryptoType::Decimal => format!("Decimal(\"{}\")", Decimal::try_from(data).unwrap()),
@@ -573,16 +918,24 @@ impl ScryptoValueFormatter {
                 )
             }
             ScryptoType::PackageAddress => {
-                format!(
-                    "PackageAddress(\"{}\")",
-                    PackageAddress::try_from(data).unwrap()
-                )
+                let package_address = PackageAddress::try_from(data).unwrap();
+                match encoder {
+                    Some(encoder) => format!(
+                        "PackageAddress(\"{}\")",
+                        encoder.encode_package_address(&package_address)
+                    ),
+                    None => format!("PackageAddress(\"{}\")", package_address),
+                }
             }
             ScryptoType::ComponentAddress => {
-                format!(
-                    "ComponentAddress(\"{}\")",
-                    ComponentAddress::try_from(data).unwrap()
-                )
+                let component_address = ComponentAddress::try_from(data).unwrap();
+                match encoder {
+                    Some(encoder) => format!(
+                        "ComponentAddress(\"{}\")",
+                        encoder.encode_component_address(&component_address)
+                    ),
+                    None => format!("ComponentAddress(\"{}\")", component_address),
+                }
             }
             ScryptoType::Component => {
                 format!("Component(\"{}\")", Component::try_from(data).unwrap())
@@ -641,10 +994,16 @@ impl ScryptoValueFormatter {
                 "NonFungibleAddress(\"{}\")",
                 NonFungibleAddress::try_from(data).unwrap()
             ),
-            ScryptoType::ResourceAddress => format!(
-                "ResourceAddress(\"{}\")",
-                ResourceAddress::try_from(data).unwrap()
-            ),
+            ScryptoType::ResourceAddress => {
+                let resource_address = ResourceAddress::try_from(data).unwrap();
+                match encoder {
+                    Some(encoder) => format!(
+                        "ResourceAddress(\"{}\")",
+                        encoder.encode_resource_address(&resource_address)
+                    ),
+                    None => format!("ResourceAddress(\"{}\")", resource_address),
+                }
+            }
             ScryptoType::Expression => {
                 format!("Expression(\"{}\")", Expression::try_from(data).unwrap())
             }
@@ -658,8 +1017,31 @@ impl ScryptoValueFormatter {
 #[cfg(test)]
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::rust::collections::BTreeMap;
+    use super::rust::collections::BTreeSet;
     use super::rust::vec;
     use super::*;
+    use crate::constants::RADIX_TOKEN;
+
+    #[test]
+    fn from_wasm_memory_should_match_from_slice() {
+        let bytes = scrypto_encode(&vec![7u8; 100_000]);
+        let mut memory = vec![0u8; 16];
+        memory.extend(&bytes);
+
+        assert_eq!(
+            ScryptoValue::from_wasm_memory(16, bytes.len(), &memory),
+            ScryptoValue::from_slice(&bytes)
+        );
+        assert_eq!(
+            ScryptoValue::from_wasm_memory(16, bytes.len() + 1, &memory),
+            Err(DecodeError::Underflow {
+                required: bytes.len() + 1,
+                remaining: bytes.len(),
+                while_decoding: None
+            })
+        );
+    }
 
     #[test]
     fn should_reject_duplicate_ids() {
@@ -670,4 +1052,277 @@ mod tests {
         let error = ScryptoValue::from_slice(&buckets).expect_err("Should be an error");
         assert_eq!(error, DecodeError::CustomError("DuplicateIds".to_string()));
     }
+
+    #[derive(TypeId, Encode, Decode)]
+    struct Position {
+        resource_address: ResourceAddress,
+        amount: Decimal,
+    }
+
+    #[derive(TypeId, Encode, Decode)]
+    struct Pool {
+        name: String,
+        position: Position,
+    }
+
+    #[test]
+    fn should_limit_number_of_buckets() {
+        let value = decode_any(&scrypto_encode(&vec![Bucket(1), Bucket(2), Bucket(3)])).unwrap();
+
+        let mut checker = ScryptoCustomValueChecker::new_with_limits(3, 0);
+        assert_eq!(
+            traverse_any(&mut MutableSborPath::new(), &value, &mut checker),
+            Ok(())
+        );
+
+        let mut checker = ScryptoCustomValueChecker::new_with_limits(2, 0);
+        assert_eq!(
+            traverse_any(&mut MutableSborPath::new(), &value, &mut checker),
+            Err(ScryptoCustomValueCheckError::TooManyBuckets)
+        );
+    }
+
+    #[test]
+    fn should_limit_number_of_proofs() {
+        let value = decode_any(&scrypto_encode(&vec![Proof(1), Proof(2)])).unwrap();
+
+        let mut checker = ScryptoCustomValueChecker::new_with_limits(0, 2);
+        assert_eq!(
+            traverse_any(&mut MutableSborPath::new(), &value, &mut checker),
+            Ok(())
+        );
+
+        let mut checker = ScryptoCustomValueChecker::new_with_limits(0, 1);
+        assert_eq!(
+            traverse_any(&mut MutableSborPath::new(), &value, &mut checker),
+            Err(ScryptoCustomValueCheckError::TooManyProofs)
+        );
+    }
+
+    #[test]
+    fn should_get_typed_values_at_path() {
+        let resource_address = ResourceAddress::Normal([3u8; 26]);
+        let value = ScryptoValue::from_typed(&Pool {
+            name: "pool".to_owned(),
+            position: Position {
+                resource_address,
+                amount: Decimal::from(5),
+            },
+        });
+
+        assert_eq!(
+            value.get_typed_at_path::<Decimal>(&SborPath::new(vec![1, 1])),
+            Ok(Decimal::from(5))
+        );
+        assert_eq!(
+            value.get_typed_at_path::<ResourceAddress>(&SborPath::new(vec![1, 0])),
+            Ok(resource_address)
+        );
+        assert!(value.get_at_path(&SborPath::new(vec![1, 2])).is_none());
+        assert!(value
+            .get_typed_at_path::<Decimal>(&SborPath::new(vec![0]))
+            .is_err());
+    }
+
+    #[derive(TypeId, Encode, Decode)]
+    enum Status {
+        Open { fee: u32 },
+        Closed,
+    }
+
+    #[derive(TypeId, Encode, Decode)]
+    struct Market {
+        name: String,
+        status: Status,
+        prices: BTreeMap<String, u32>,
+    }
+
+    #[test]
+    fn should_diff_changed_field() {
+        let before = ScryptoValue::from_typed(&Market {
+            name: "XRD/USD".to_owned(),
+            status: Status::Open { fee: 1 },
+            prices: BTreeMap::new(),
+        });
+        let after = ScryptoValue::from_typed(&Market {
+            name: "XRD/USD".to_owned(),
+            status: Status::Open { fee: 2 },
+            prices: BTreeMap::new(),
+        });
+
+        assert_eq!(
+            before.diff(&after),
+            vec![(SborPath::new(vec![1, 0]), DiffKind::Changed)]
+        );
+        assert!(before.diff(&before).is_empty());
+    }
+
+    #[test]
+    fn should_compare_semantically_ignoring_raw() {
+        let mut prices = BTreeMap::new();
+        prices.insert("XRD".to_owned(), 1u32);
+        prices.insert("YYY".to_owned(), 2u32);
+        let market = ScryptoValue::from_typed(&Market {
+            name: "XRD/USD".to_owned(),
+            status: Status::Closed,
+            prices: prices.clone(),
+        });
+        assert!(market.semantic_eq(&ScryptoValue::from_value(market.dom.clone()).unwrap()));
+
+        // a different but equivalent encoding, which lists the map entries in reverse order
+        let mut reordered = market.dom.clone();
+        if let Value::Map { elements, .. } = SborPath::new(vec![2])
+            .get_from_value_mut(&mut reordered)
+            .unwrap()
+        {
+            elements.rotate_left(2);
+        }
+        let reordered = ScryptoValue::from_slice(&encode_any(&reordered)).unwrap();
+        assert!(market != reordered);
+        assert!(market.semantic_eq(&reordered));
+
+        prices.insert("YYY".to_owned(), 3u32);
+        let other = ScryptoValue::from_typed(&Market {
+            name: "XRD/USD".to_owned(),
+            status: Status::Closed,
+            prices,
+        });
+        assert!(!market.semantic_eq(&other));
+    }
+
+    #[test]
+    fn should_diff_added_map_entry() {
+        let mut prices = BTreeMap::new();
+        prices.insert("XRD".to_owned(), 1u32);
+        let before = ScryptoValue::from_typed(&Market {
+            name: "XRD/USD".to_owned(),
+            status: Status::Closed,
+            prices: prices.clone(),
+        });
+        prices.insert("YYY".to_owned(), 2u32);
+        let after = ScryptoValue::from_typed(&Market {
+            name: "XRD/USD".to_owned(),
+            status: Status::Closed,
+            prices,
+        });
+
+        assert_eq!(
+            before.diff(&after),
+            vec![
+                (SborPath::new(vec![2, 2]), DiffKind::Added),
+                (SborPath::new(vec![2, 3]), DiffKind::Added)
+            ]
+        );
+        assert_eq!(
+            after.diff(&before),
+            vec![
+                (SborPath::new(vec![2, 2]), DiffKind::Removed),
+                (SborPath::new(vec![2, 3]), DiffKind::Removed)
+            ]
+        );
+    }
+
+    #[test]
+    fn should_diff_map_entry_inserted_at_front_by_key() {
+        let mut prices = BTreeMap::new();
+        prices.insert("XRD".to_owned(), 1u32);
+        prices.insert("YYY".to_owned(), 2u32);
+        let before = ScryptoValue::from_typed(&Market {
+            name: "XRD/USD".to_owned(),
+            status: Status::Closed,
+            prices: prices.clone(),
+        });
+        prices.insert("AAA".to_owned(), 3u32);
+        prices.insert("YYY".to_owned(), 4u32);
+        let after = ScryptoValue::from_typed(&Market {
+            name: "XRD/USD".to_owned(),
+            status: Status::Closed,
+            prices,
+        });
+
+        assert_eq!(
+            before.diff(&after),
+            vec![
+                (SborPath::new(vec![2, 0]), DiffKind::Added),
+                (SborPath::new(vec![2, 1]), DiffKind::Added),
+                (SborPath::new(vec![2, 5]), DiffKind::Changed)
+            ]
+        );
+    }
+
+    #[test]
+    fn should_diff_set_by_membership() {
+        let before = ScryptoValue::from_typed(&BTreeSet::from(["b".to_owned(), "c".to_owned()]));
+        let after = ScryptoValue::from_typed(&BTreeSet::from(["a".to_owned(), "b".to_owned()]));
+
+        assert_eq!(
+            before.diff(&after),
+            vec![
+                (SborPath::new(vec![1]), DiffKind::Removed),
+                (SborPath::new(vec![0]), DiffKind::Added)
+            ]
+        );
+    }
+
+    #[test]
+    fn should_diff_variant_switch_as_changed() {
+        let before = ScryptoValue::from_typed(&Market {
+            name: "XRD/USD".to_owned(),
+            status: Status::Open { fee: 1 },
+            prices: BTreeMap::new(),
+        });
+        let after = ScryptoValue::from_typed(&Market {
+            name: "XRD/USD".to_owned(),
+            status: Status::Closed,
+            prices: BTreeMap::new(),
+        });
+
+        assert_eq!(
+            before.diff(&after),
+            vec![(SborPath::new(vec![1]), DiffKind::Changed)]
+        );
+    }
+
+    #[test]
+    fn should_format_addresses_with_bech32() {
+        let value = ScryptoValue::from_typed(&RADIX_TOKEN);
+        let encoder = Bech32Encoder::for_simulator();
+
+        let formatted = ScryptoValueFormatter::format_value_with_bech32(
+            &value.dom,
+            &HashMap::new(),
+            &HashMap::new(),
+            &encoder,
+        );
+
+        assert_eq!(
+            formatted,
+            format!(
+                "ResourceAddress(\"{}\")",
+                encoder.encode_resource_address(&RADIX_TOKEN)
+            )
+        );
+        assert!(formatted.starts_with("ResourceAddress(\"resource_sim1"));
+    }
+
+    #[test]
+    fn should_accept_value_without_stored_nodes() {
+        let value = ScryptoValue::from_typed(&Position {
+            resource_address: RADIX_TOKEN,
+            amount: Decimal::from(5),
+        });
+
+        assert_eq!(value.validate_no_stored_nodes(), Ok(()));
+    }
+
+    #[test]
+    fn should_reject_value_with_vault() {
+        let vault_id = (Hash([1u8; 32]), 5u32);
+        let value = ScryptoValue::from_typed(&vec![Vault(vault_id)]);
+
+        assert_eq!(
+            value.validate_no_stored_nodes(),
+            Err(ScryptoValueValidationError::VaultNotAllowed(vault_id))
+        );
+    }
 }
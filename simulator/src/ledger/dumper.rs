@@ -1,5 +1,6 @@
 #![allow(unused_must_use)]
 use colored::*;
+use radix_engine::engine::Substate;
 use radix_engine::ledger::*;
 use radix_engine::model::*;
 use radix_engine::types::*;
@@ -13,6 +14,7 @@ pub enum DisplayError {
     PackageNotFound,
     ComponentNotFound,
     ResourceManagerNotFound,
+    SubstateNotFound,
     AddressError(AddressError),
 }
 
@@ -209,7 +211,10 @@ fn dump_resources<T: ReadableSubstateStore, O: std::io::Write>(
 }
 
 /// Dump a resource into console.
-pub fn dump_resource_manager<T: ReadableSubstateStore, O: std::io::Write>(
+pub fn dump_resource_manager<
+    T: ReadableSubstateStore + QueryableSubstateStore,
+    O: std::io::Write,
+>(
     resource_address: ResourceAddress,
     substate_store: &T,
     output: &mut O,
@@ -247,8 +252,211 @@ pub fn dump_resource_manager<T: ReadableSubstateStore, O: std::io::Write>(
                 "Total Supply".green().bold(),
                 r.total_supply()
             );
+            if matches!(r.resource_type(), ResourceType::NonFungible) {
+                let non_fungibles: Vec<(NonFungibleId, NonFungible)> = substate_store
+                    .scan_prefix(&non_fungible_prefix(resource_address))
+                    .into_iter()
+                    .filter_map(|(substate_id, output_value)| {
+                        let non_fungible: NonFungibleWrapper = output_value.substate.into();
+                        match (substate_id, non_fungible.0) {
+                            (SubstateId::NonFungible(_, id), Some(non_fungible)) => {
+                                Some((id, non_fungible))
+                            }
+                            _ => None,
+                        }
+                    })
+                    .collect();
+                writeln!(
+                    output,
+                    "{}: {}",
+                    "Non Fungibles".green().bold(),
+                    non_fungibles.len()
+                );
+                for (last, (id, non_fungible)) in non_fungibles.iter().identify_last() {
+                    let immutable_data =
+                        ScryptoValue::from_slice(&non_fungible.immutable_data()).unwrap();
+                    let mutable_data =
+                        ScryptoValue::from_slice(&non_fungible.mutable_data()).unwrap();
+                    writeln!(
+                        output,
+                        "{} NonFungible {{ id: {}, immutable_data: {}, mutable_data: {} }}",
+                        list_item_prefix(last),
+                        ScryptoValue::from_typed(id),
+                        immutable_data,
+                        mutable_data
+                    );
+                }
+            }
             Ok(())
         }
         None => Err(DisplayError::ResourceManagerNotFound),
     }
 }
+
+/// Dump a raw substate into console.
+pub fn dump_substate<T: ReadableSubstateStore, O: std::io::Write>(
+    substate_id: &SubstateId,
+    substate_store: &T,
+    output: &mut O,
+) -> Result<(), DisplayError> {
+    let output_value = substate_store
+        .get_substate(substate_id)
+        .ok_or(DisplayError::SubstateNotFound)?;
+
+    writeln!(
+        output,
+        "{}: {:?}",
+        "Substate ID".green().bold(),
+        substate_id
+    );
+    writeln!(
+        output,
+        "{}: {}",
+        "Version".green().bold(),
+        output_value.version
+    );
+    match output_value.substate {
+        Substate::System(system) => {
+            writeln!(output, "{}: System", "Type".green().bold());
+            writeln!(
+                output,
+                "{}: {}",
+                "Value".green().bold(),
+                ScryptoValue::from_typed(&system)
+            );
+        }
+        Substate::Resource(resource_manager) => {
+            writeln!(output, "{}: Resource", "Type".green().bold());
+            writeln!(
+                output,
+                "{}: {}",
+                "Value".green().bold(),
+                ScryptoValue::from_typed(&resource_manager)
+            );
+        }
+        Substate::ComponentInfo(component_info) => {
+            writeln!(output, "{}: ComponentInfo", "Type".green().bold());
+            writeln!(
+                output,
+                "{}: {}",
+                "Value".green().bold(),
+                ScryptoValue::from_typed(&component_info)
+            );
+        }
+        Substate::ComponentState(component_state) => {
+            writeln!(output, "{}: ComponentState", "Type".green().bold());
+            writeln!(
+                output,
+                "{}: {}",
+                "State".green().bold(),
+                format_raw_value(component_state.state())
+            );
+        }
+        Substate::Package(package) => {
+            writeln!(output, "{}: Package", "Type".green().bold());
+            writeln!(
+                output,
+                "{}: {} bytes",
+                "Code size".green().bold(),
+                package.code().len()
+            );
+        }
+        Substate::Vault(vault) => {
+            writeln!(output, "{}: Vault", "Type".green().bold());
+            writeln!(
+                output,
+                "{}: {}",
+                "Value".green().bold(),
+                ScryptoValue::from_typed(&vault)
+            );
+        }
+        Substate::NonFungible(NonFungibleWrapper(non_fungible)) => {
+            writeln!(output, "{}: NonFungible", "Type".green().bold());
+            match non_fungible {
+                Some(non_fungible) => {
+                    writeln!(
+                        output,
+                        "{}: {}",
+                        "Immutable data".green().bold(),
+                        format_raw_value(&non_fungible.immutable_data())
+                    );
+                    writeln!(
+                        output,
+                        "{}: {}",
+                        "Mutable data".green().bold(),
+                        format_raw_value(&non_fungible.mutable_data())
+                    );
+                }
+                None => {
+                    writeln!(output, "{}: None", "Value".green().bold());
+                }
+            }
+        }
+        Substate::KeyValueStoreEntry(KeyValueStoreEntryWrapper(value)) => {
+            writeln!(output, "{}: KeyValueStoreEntry", "Type".green().bold());
+            writeln!(
+                output,
+                "{}: {}",
+                "Value".green().bold(),
+                value
+                    .map(|value| format_raw_value(&value))
+                    .unwrap_or("None".to_owned())
+            );
+        }
+        Substate::KeyValueStoreSpace(KeyValueStoreSpace { len }) => {
+            writeln!(output, "{}: KeyValueStoreSpace", "Type".green().bold());
+            writeln!(output, "{}: {}", "Length".green().bold(), len);
+        }
+    }
+    Ok(())
+}
+
+/// Formats an encoded value, falling back to hex if it isn't a valid Scrypto value.
+fn format_raw_value(slice: &[u8]) -> String {
+    match ScryptoValue::from_slice(slice) {
+        Ok(value) => value.to_string(),
+        Err(_) => hex::encode(slice),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dump_component_substates() {
+        let substate_store = TypedInMemorySubstateStore::with_bootstrap();
+
+        let mut output = Vec::new();
+        dump_substate(
+            &SubstateId::ComponentInfo(SYS_FAUCET_COMPONENT),
+            &substate_store,
+            &mut output,
+        )
+        .unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("ComponentInfo"));
+        assert!(output.contains("SysFaucet"));
+
+        let mut output = Vec::new();
+        dump_substate(
+            &SubstateId::ComponentState(SYS_FAUCET_COMPONENT),
+            &substate_store,
+            &mut output,
+        )
+        .unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("ComponentState"));
+        assert!(output.contains("Vault("));
+    }
+
+    #[test]
+    fn test_dump_missing_substate() {
+        let substate_store = TypedInMemorySubstateStore::with_bootstrap();
+        let substate_id = SubstateId::Vault((Hash([1u8; 32]), 0));
+
+        let result = dump_substate(&substate_id, &substate_store, &mut Vec::new());
+
+        assert!(matches!(result, Err(DisplayError::SubstateNotFound)));
+    }
+}
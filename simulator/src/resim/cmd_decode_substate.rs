@@ -0,0 +1,26 @@
+use clap::Parser;
+use radix_engine::types::*;
+use radix_engine_stores::rocks_db::RadixEngineDB;
+
+use crate::ledger::*;
+use crate::resim::*;
+
+/// Decode and show a raw substate in the ledger state
+#[derive(Parser, Debug)]
+pub struct DecodeSubstate {
+    /// The hex-encoded substate ID
+    substate_id: String,
+}
+
+impl DecodeSubstate {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
+
+        let substate_id: SubstateId = hex::decode(&self.substate_id)
+            .ok()
+            .and_then(|bytes| scrypto_decode(&bytes).ok())
+            .ok_or(Error::InvalidId(self.substate_id.clone()))?;
+
+        dump_substate(&substate_id, &ledger, out).map_err(Error::LedgerDumpError)
+    }
+}
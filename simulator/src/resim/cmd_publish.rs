@@ -1,7 +1,9 @@
 use clap::Parser;
 use colored::*;
 use radix_engine::engine::Substate;
-use radix_engine::ledger::{OutputValue, ReadableSubstateStore, WriteableSubstateStore};
+use radix_engine::ledger::{
+    OutputValue, ReadableSubstateStore, WriteableSubstateStore, OUTPUT_VALUE_FORMAT_VERSION,
+};
 use radix_engine::types::*;
 use std::ffi::OsStr;
 use std::fs;
@@ -59,6 +61,7 @@ impl Publish {
 
             let validated_package = Package::new(code, abi).map_err(Error::InvalidPackage)?;
             let output_value = OutputValue {
+                format_version: OUTPUT_VALUE_FORMAT_VERSION,
                 substate: Substate::Package(validated_package),
                 version: previous_version.unwrap_or(0),
             };
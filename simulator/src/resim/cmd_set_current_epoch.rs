@@ -37,6 +37,7 @@ impl SetCurrentEpoch {
             vec![AuthModule::validator_role_nf_address()],
             &blobs,
             DEFAULT_MAX_CALL_DEPTH,
+            DEFAULT_MAX_KV_VALUE_BYTES,
             &mut track,
             &mut wasm_engine,
             &mut wasm_instrumenter,
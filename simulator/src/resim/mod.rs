@@ -1,5 +1,6 @@
 mod cmd_call_function;
 mod cmd_call_method;
+mod cmd_decode_substate;
 mod cmd_export_abi;
 mod cmd_generate_key_pair;
 mod cmd_mint;
@@ -22,6 +23,7 @@ mod error;
 
 pub use cmd_call_function::*;
 pub use cmd_call_method::*;
+pub use cmd_decode_substate::*;
 pub use cmd_export_abi::*;
 pub use cmd_generate_key_pair::*;
 pub use cmd_mint::*;
@@ -48,6 +50,7 @@ pub const ENV_DISABLE_MANIFEST_OUTPUT: &'static str = "DISABLE_MANIFEST_OUTPUT";
 
 use clap::{Parser, Subcommand};
 use radix_engine::constants::*;
+use radix_engine::fee::FeeTable;
 use radix_engine::model::*;
 use radix_engine::transaction::TransactionExecutor;
 use radix_engine::transaction::TransactionOutcome;
@@ -85,6 +88,7 @@ impl ResimCli {
 pub enum Command {
     CallFunction(CallFunction),
     CallMethod(CallMethod),
+    DecodeSubstate(DecodeSubstate),
     ExportAbi(ExportAbi),
     GenerateKeyPair(GenerateKeyPair),
     Mint(Mint),
@@ -112,6 +116,7 @@ pub fn run() -> Result<(), Error> {
     match cli.command {
         Command::CallFunction(cmd) => cmd.run(&mut out),
         Command::CallMethod(cmd) => cmd.run(&mut out),
+        Command::DecodeSubstate(cmd) => cmd.run(&mut out),
         Command::ExportAbi(cmd) => cmd.run(&mut out),
         Command::GenerateKeyPair(cmd) => cmd.run(&mut out),
         Command::Mint(cmd) => cmd.run(&mut out),
@@ -148,8 +153,7 @@ pub fn handle_manifest<O: std::io::Write>(
                     Some(n) => NetworkDefinition::from_str(&n).map_err(Error::ParseNetworkError)?,
                     None => NetworkDefinition::simulator(),
                 };
-                let manifest_str =
-                    decompile(&manifest.instructions, &network).map_err(Error::DecompileError)?;
+                let manifest_str = decompile(&manifest, &network).map_err(Error::DecompileError)?;
                 fs::write(path, manifest_str).map_err(Error::IOError)?;
                 for blob in manifest.blobs {
                     let blob_hash = hash(&blob);
@@ -184,12 +188,17 @@ pub fn handle_manifest<O: std::io::Write>(
             let receipt = executor.execute_and_commit(
                 &transaction,
                 &FeeReserveConfig {
-                    cost_unit_price: DEFAULT_COST_UNIT_PRICE.parse().unwrap(),
                     system_loan: DEFAULT_SYSTEM_LOAN,
                 },
                 &ExecutionConfig {
                     max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+                    max_kv_value_bytes: DEFAULT_MAX_KV_VALUE_BYTES,
                     trace,
+                    profile_wasm_memory: false,
+                    trace_auth_zones: false,
+                    client_context: None,
+                    on_commit: None,
+                    fee_table: FeeTable::new(),
                 },
             );
 
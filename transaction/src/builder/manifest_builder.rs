@@ -22,7 +22,8 @@ use scrypto::math::*;
 use scrypto::resource::{require, LOCKED};
 use scrypto::resource::{AccessRule, AccessRuleNode, Burn, Mint, Withdraw};
 use scrypto::resource::{
-    MintParams, Mutability, ResourceManagerCreateInput, ResourceMethodAuthKey,
+    MintParams, Mutability, NonFungibleDataSchema, ResourceManagerCreateInput,
+    ResourceMethodAuthKey,
 };
 use scrypto::resource::{NonFungibleAddress, NonFungibleId, ResourceAddress};
 use scrypto::resource::{ResourceManagerMintInput, ResourceType};
@@ -311,12 +312,16 @@ impl ManifestBuilder {
         metadata: HashMap<String, String>,
         access_rules: HashMap<ResourceMethodAuthKey, (AccessRule, Mutability)>,
         mint_params: Option<MintParams>,
+        supply_cap: Option<Decimal>,
+        non_fungible_data_schema: Option<NonFungibleDataSchema>,
     ) -> &mut Self {
         let input = ResourceManagerCreateInput {
             resource_type,
             metadata,
             access_rules,
             mint_params,
+            supply_cap,
+            non_fungible_data_schema,
         };
 
         self.add_instruction(Instruction::CallFunction {
@@ -394,6 +399,27 @@ impl ManifestBuilder {
             .0)
     }
 
+    /// Calls a function where the arguments should be an array of encoded Scrypto value.
+    ///
+    /// Unlike `call_function`, each argument is checked against the type declared by the
+    /// function ABI, so that e.g. a `u32` can't be passed where a `u64` is expected.
+    pub fn call_function_checked(
+        &mut self,
+        package_address: PackageAddress,
+        blueprint_name: &str,
+        function: &str,
+        args: Vec<u8>,
+        blueprint_abi: &abi::BlueprintAbi,
+    ) -> Result<&mut Self, BuildCallWithAbiError> {
+        let abi = blueprint_abi
+            .get_fn_abi(function)
+            .ok_or_else(|| BuildCallWithAbiError::FunctionNotFound(function.to_owned()))?;
+
+        Self::check_args(abi, &args).map_err(BuildCallWithAbiError::FailedToBuildArgs)?;
+
+        Ok(self.call_function(package_address, blueprint_name, function, args))
+    }
+
     /// Calls a scrypto method where the arguments should be an array of encoded Scrypto value.
     pub fn call_method(
         &mut self,
@@ -465,6 +491,26 @@ impl ManifestBuilder {
             .0)
     }
 
+    /// Calls a scrypto method where the arguments should be an array of encoded Scrypto value.
+    ///
+    /// Unlike `call_method`, each argument is checked against the type declared by the
+    /// method ABI, so that e.g. a `u32` can't be passed where a `u64` is expected.
+    pub fn call_method_checked(
+        &mut self,
+        component_address: ComponentAddress,
+        method: &str,
+        args: Vec<u8>,
+        blueprint_abi: &abi::BlueprintAbi,
+    ) -> Result<&mut Self, BuildCallWithAbiError> {
+        let abi = blueprint_abi
+            .get_fn_abi(method)
+            .ok_or_else(|| BuildCallWithAbiError::MethodNotFound(method.to_owned()))?;
+
+        Self::check_args(abi, &args).map_err(BuildCallWithAbiError::FailedToBuildArgs)?;
+
+        Ok(self.call_method(component_address, method, args))
+    }
+
     /// Publishes a package.
     pub fn publish_package(
         &mut self,
@@ -494,6 +540,16 @@ impl ManifestBuilder {
         }
     }
 
+    /// Builds a transaction manifest, together with the hash of its intent under the given header.
+    pub fn build_with_intent_hash(&self, header: TransactionHeader) -> (TransactionManifest, Hash) {
+        let intent = TransactionIntent {
+            header,
+            manifest: self.build(),
+        };
+        let intent_hash = intent.hash();
+        (intent.manifest, intent_hash)
+    }
+
     /// Creates a token resource with mutable supply.
     pub fn new_token_mutable(
         &mut self,
@@ -715,6 +771,19 @@ impl ManifestBuilder {
         .0
     }
 
+    /// Locks a fee from the XRD vault of an account, then withdraws resource from an account.
+    pub fn lock_fee_and_withdraw(
+        &mut self,
+        fee: Decimal,
+        fee_account: ComponentAddress,
+        amount: Decimal,
+        resource_address: ResourceAddress,
+        account: ComponentAddress,
+    ) -> &mut Self {
+        self.lock_fee(fee, fee_account)
+            .withdraw_from_account_by_amount(amount, resource_address, account)
+    }
+
     /// Withdraws resource from an account.
     pub fn withdraw_from_account(
         &mut self,
@@ -883,6 +952,48 @@ impl ManifestBuilder {
         Ok(encoded)
     }
 
+    fn check_args(abi: &abi::Fn, args: &[u8]) -> Result<(), BuildArgsError> {
+        let arg_type = &abi.input;
+        let named = match arg_type {
+            Type::Struct {
+                name: _,
+                fields: Fields::Named { named },
+            } => named,
+            _ => return Err(BuildArgsError::UnsupportedRootType(arg_type.clone())),
+        };
+        let fields = match decode_any(args).map_err(BuildArgsError::FailedToDecode)? {
+            Value::Struct { fields } => fields,
+            value => {
+                return Err(BuildArgsError::TypeMismatch {
+                    index: 0,
+                    expected: arg_type.clone(),
+                    actual: sbor::any::infer_type(&value),
+                })
+            }
+        };
+
+        let required = named.len().saturating_sub(abi.input_defaults.len());
+        for (i, (_, t)) in named.iter().enumerate() {
+            let field = match fields.get(i) {
+                Some(field) => field,
+                None if i >= required => break,
+                None => return Err(BuildArgsError::MissingArgument(i, t.clone())),
+            };
+            if !t.matches(field) {
+                return Err(BuildArgsError::TypeMismatch {
+                    index: i,
+                    expected: t.clone(),
+                    actual: sbor::any::infer_type(field),
+                });
+            }
+        }
+        if fields.len() > named.len() {
+            return Err(BuildArgsError::UnexpectedArgument(named.len()));
+        }
+
+        Ok(())
+    }
+
     fn parse_basic_ty<T>(
         &mut self,
         i: usize,
@@ -1069,3 +1180,250 @@ fn parse_resource_specifier(
         Ok(ResourceSpecifier::Amount(amount, resource_address))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signing::EcdsaSecp256k1PrivateKey;
+    use sbor::rust::cell::RefCell;
+
+    fn blueprint_abi() -> abi::BlueprintAbi {
+        abi::BlueprintAbi {
+            structure: Type::Unit,
+            fns: vec![abi::Fn {
+                ident: "set_deadline".to_owned(),
+                mutability: None,
+                input: Type::Struct {
+                    name: "Test_set_deadline_Input".to_owned(),
+                    fields: Fields::Named {
+                        named: vec![("epoch".to_owned(), Type::U64)],
+                    },
+                },
+                output: Type::Unit,
+                export_name: "Test_set_deadline".to_owned(),
+                input_defaults: vec![],
+                auth: None,
+            }],
+            events: vec![],
+            errors: vec![],
+        }
+    }
+
+    fn call_set_deadline(args: Vec<u8>) -> Result<(), BuildCallWithAbiError> {
+        ManifestBuilder::new(&NetworkDefinition::simulator())
+            .call_function_checked(
+                SYS_FAUCET_PACKAGE,
+                "Test",
+                "set_deadline",
+                args,
+                &blueprint_abi(),
+            )
+            .map(|_| ())
+    }
+
+    #[test]
+    fn test_call_function_checked() {
+        assert!(call_set_deadline(args!(5u64)).is_ok());
+    }
+
+    #[test]
+    fn test_call_function_checked_with_wrong_integer_width() {
+        assert!(matches!(
+            call_set_deadline(args!(5u32)),
+            Err(BuildCallWithAbiError::FailedToBuildArgs(
+                BuildArgsError::TypeMismatch {
+                    index: 0,
+                    expected: Type::U64,
+                    actual: Type::U32,
+                }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_call_function_checked_with_wrong_arity() {
+        assert!(matches!(
+            call_set_deadline(args!()),
+            Err(BuildCallWithAbiError::FailedToBuildArgs(
+                BuildArgsError::MissingArgument(0, Type::U64)
+            ))
+        ));
+        assert!(matches!(
+            call_set_deadline(args!(5u64, 6u64)),
+            Err(BuildCallWithAbiError::FailedToBuildArgs(
+                BuildArgsError::UnexpectedArgument(1)
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_create_proof_from_auth_zone_by_ids() {
+        let mut ids = BTreeSet::new();
+        ids.insert(NonFungibleId::from_u32(1));
+        ids.insert(NonFungibleId::from_u32(2));
+
+        let mut created_proof_id = None;
+        let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+            .create_proof_from_auth_zone_by_ids(&ids, RADIX_TOKEN, |builder, proof_id| {
+                created_proof_id = Some(proof_id);
+                builder.push_to_auth_zone(proof_id)
+            })
+            .build();
+
+        let proof_id = created_proof_id.unwrap();
+        assert_eq!(
+            manifest.instructions,
+            vec![
+                Instruction::CreateProofFromAuthZoneByIds {
+                    ids,
+                    resource_address: RADIX_TOKEN,
+                },
+                Instruction::PushToAuthZone { proof_id },
+            ]
+        );
+    }
+
+    fn call_set_deadline_with_abi(args: Vec<String>) -> Result<(), BuildCallWithAbiError> {
+        ManifestBuilder::new(&NetworkDefinition::simulator())
+            .call_function_with_abi(
+                SYS_FAUCET_PACKAGE,
+                "Test",
+                "set_deadline",
+                args,
+                None,
+                &blueprint_abi(),
+            )
+            .map(|_| ())
+    }
+
+    #[test]
+    fn test_call_function_with_abi() {
+        let mut builder = ManifestBuilder::new(&NetworkDefinition::simulator());
+        builder
+            .call_function_with_abi(
+                SYS_FAUCET_PACKAGE,
+                "Test",
+                "set_deadline",
+                vec!["5".to_owned()],
+                None,
+                &blueprint_abi(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            builder.build().instructions,
+            ManifestBuilder::new(&NetworkDefinition::simulator())
+                .call_function(SYS_FAUCET_PACKAGE, "Test", "set_deadline", args!(5u64))
+                .build()
+                .instructions
+        );
+    }
+
+    #[test]
+    fn test_call_function_with_abi_with_missing_argument() {
+        assert!(matches!(
+            call_set_deadline_with_abi(vec![]),
+            Err(BuildCallWithAbiError::FailedToBuildArgs(
+                BuildArgsError::MissingArgument(0, Type::U64)
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_call_function_with_abi_with_unparsable_argument() {
+        assert!(matches!(
+            call_set_deadline_with_abi(vec!["five".to_owned()]),
+            Err(BuildCallWithAbiError::FailedToBuildArgs(
+                BuildArgsError::FailedToParse(0, Type::U64, _)
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_lock_fee_and_withdraw() {
+        let account = ComponentAddress::Account([1u8; 26]);
+        let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+            .lock_fee_and_withdraw(
+                10.into(),
+                SYS_FAUCET_COMPONENT,
+                5.into(),
+                RADIX_TOKEN,
+                account,
+            )
+            .build();
+
+        assert_eq!(
+            manifest.instructions,
+            vec![
+                Instruction::CallMethod {
+                    method_identifier: MethodIdentifier::Scrypto {
+                        component_address: SYS_FAUCET_COMPONENT,
+                        ident: "lock_fee".to_string(),
+                    },
+                    args: args!(Decimal::from(10)),
+                },
+                Instruction::CallMethod {
+                    method_identifier: MethodIdentifier::Scrypto {
+                        component_address: account,
+                        ident: "withdraw_by_amount".to_string(),
+                    },
+                    args: args!(Decimal::from(5), RADIX_TOKEN),
+                },
+            ]
+        );
+    }
+
+    struct RecordingIntentHashManager {
+        hashes: RefCell<Vec<Hash>>,
+    }
+
+    impl IntentHashManager for RecordingIntentHashManager {
+        fn allows(&self, hash: &Hash) -> bool {
+            self.hashes.borrow_mut().push(*hash);
+            true
+        }
+    }
+
+    #[test]
+    fn test_build_with_intent_hash() {
+        let header = TransactionHeader {
+            version: 1,
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: 0,
+            end_epoch_exclusive: 100,
+            nonce: 5,
+            notary_public_key: EcdsaSecp256k1PrivateKey::from_u64(1)
+                .unwrap()
+                .public_key()
+                .into(),
+            notary_as_signatory: false,
+            cost_unit_limit: 1_000_000,
+            tip_percentage: 5,
+            cost_unit_price: Decimal::zero(),
+            message: None,
+        };
+        let (manifest, intent_hash) = ManifestBuilder::new(&NetworkDefinition::simulator())
+            .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+            .clear_auth_zone()
+            .build_with_intent_hash(header.clone());
+
+        let intent_hash_manager = RecordingIntentHashManager {
+            hashes: RefCell::new(Vec::new()),
+        };
+        let validator = NotarizedTransactionValidator::new(ValidationConfig {
+            network_id: NetworkDefinition::simulator().id,
+            current_epoch: 1,
+            max_cost_unit_limit: 10_000_000,
+            min_tip_percentage: 0,
+            min_cost_unit_price: Decimal::zero(),
+        });
+        validator
+            .validate_intent(
+                &TransactionIntent { header, manifest },
+                &intent_hash_manager,
+            )
+            .unwrap();
+
+        assert_eq!(intent_hash_manager.hashes.into_inner(), vec![intent_hash]);
+    }
+}
@@ -0,0 +1,469 @@
+use sbor::rust::collections::*;
+use sbor::rust::ops::Range;
+use sbor::rust::vec::Vec;
+use scrypto::buffer::*;
+use scrypto::core::Expression;
+use scrypto::crypto::{hash, Hash};
+use scrypto::engine::types::*;
+use scrypto::values::*;
+
+use crate::errors::*;
+use crate::model::*;
+use crate::validation::*;
+
+/// Splits a manifest into smaller manifests whose encoded size is at most `max_size`.
+///
+/// Instructions are only split at boundaries where no bucket or proof is outstanding, the auth
+/// zone is untouched and the worktop is known to be empty. The worktop is considered empty
+/// only after a call which takes the entire worktop, e.g. `deposit_batch`. Calls are assumed
+/// not to leave proofs in the auth zone.
+///
+/// Leading `lock_fee` and `lock_contingent_fee` calls are replicated into every sub-manifest,
+/// and bucket and proof IDs are re-allocated so that each sub-manifest is valid on its own.
+pub fn split_manifest(
+    manifest: TransactionManifest,
+    max_size: usize,
+) -> Result<Vec<TransactionManifest>, SplitError> {
+    let prelude_len = manifest
+        .instructions
+        .iter()
+        .take_while(|inst| is_lock_fee(inst))
+        .count();
+    let (prelude, body) = manifest.instructions.split_at(prelude_len);
+
+    // Group the instructions which must be executed within the same transaction
+    let mut tracker = BoundaryTracker::new();
+    let mut created_ids = Vec::new();
+    let mut groups = Vec::new();
+    let mut start = 0;
+    for (i, inst) in body.iter().enumerate() {
+        created_ids.push(tracker.apply(inst)?);
+        if tracker.is_boundary() {
+            groups.push(start..i + 1);
+            start = i + 1;
+        }
+    }
+    if start < body.len() {
+        groups.push(start..body.len());
+    }
+
+    // Pack the groups into sub-manifests, greedily
+    let splitter = SubManifestBuilder {
+        prelude,
+        body,
+        created_ids: &created_ids,
+        blobs: &manifest.blobs,
+    };
+    let mut manifests = Vec::new();
+    let mut current: Option<(usize, TransactionManifest)> = None;
+    for group in groups {
+        if let Some((current_start, _)) = &current {
+            let candidate = splitter.build(*current_start..group.end)?;
+            if encoded_size(&candidate) <= max_size {
+                current = Some((*current_start, candidate));
+                continue;
+            }
+            manifests.push(current.take().unwrap().1);
+        }
+
+        let single = splitter.build(group.clone())?;
+        let size = encoded_size(&single);
+        if size > max_size {
+            return Err(SplitError::InstructionGroupTooLarge {
+                start: prelude_len + group.start,
+                end: prelude_len + group.end,
+                size,
+            });
+        }
+        current = Some((group.start, single));
+    }
+    match current {
+        Some((_, last)) => manifests.push(last),
+        None => manifests.push(splitter.build(0..0)?),
+    }
+
+    Ok(manifests)
+}
+
+fn is_lock_fee(inst: &Instruction) -> bool {
+    matches!(
+        inst,
+        Instruction::CallMethod {
+            method_identifier: MethodIdentifier::Scrypto { ident, .. },
+            ..
+        } if ident == "lock_fee" || ident == "lock_contingent_fee"
+    )
+}
+
+fn encoded_size(manifest: &TransactionManifest) -> usize {
+    scrypto_encode(manifest).len()
+}
+
+fn id_allocation_error(e: IdAllocationError) -> SplitError {
+    SplitError::IdValidationError(IdValidationError::IdAllocationError(e))
+}
+
+/// The bucket or proof created by an instruction.
+#[derive(Debug, Clone, Copy)]
+enum CreatedId {
+    Bucket(BucketId),
+    Proof(ProofId),
+}
+
+/// Tracks the transaction processor state relevant to splitting.
+struct BoundaryTracker {
+    id_allocator: IdAllocator,
+    buckets: HashSet<BucketId>,
+    proofs: HashSet<ProofId>,
+    worktop_dirty: bool,
+    auth_zone_dirty: bool,
+}
+
+impl BoundaryTracker {
+    fn new() -> Self {
+        Self {
+            id_allocator: IdAllocator::new(IdSpace::Transaction),
+            buckets: HashSet::new(),
+            proofs: HashSet::new(),
+            worktop_dirty: false,
+            auth_zone_dirty: false,
+        }
+    }
+
+    fn is_boundary(&self) -> bool {
+        self.buckets.is_empty()
+            && self.proofs.is_empty()
+            && !self.worktop_dirty
+            && !self.auth_zone_dirty
+    }
+
+    fn new_bucket(&mut self) -> Result<Option<CreatedId>, SplitError> {
+        let bucket_id = self
+            .id_allocator
+            .new_bucket_id()
+            .map_err(id_allocation_error)?;
+        self.buckets.insert(bucket_id);
+        Ok(Some(CreatedId::Bucket(bucket_id)))
+    }
+
+    fn new_proof(&mut self) -> Result<Option<CreatedId>, SplitError> {
+        let proof_id = self
+            .id_allocator
+            .new_proof_id()
+            .map_err(id_allocation_error)?;
+        self.proofs.insert(proof_id);
+        Ok(Some(CreatedId::Proof(proof_id)))
+    }
+
+    fn check_bucket(&self, bucket_id: &BucketId) -> Result<(), SplitError> {
+        if self.buckets.contains(bucket_id) {
+            Ok(())
+        } else {
+            Err(SplitError::IdValidationError(
+                IdValidationError::BucketNotFound(*bucket_id),
+            ))
+        }
+    }
+
+    fn check_proof(&self, proof_id: &ProofId) -> Result<(), SplitError> {
+        if self.proofs.contains(proof_id) {
+            Ok(())
+        } else {
+            Err(SplitError::IdValidationError(
+                IdValidationError::ProofNotFound(*proof_id),
+            ))
+        }
+    }
+
+    fn apply(&mut self, inst: &Instruction) -> Result<Option<CreatedId>, SplitError> {
+        match inst {
+            Instruction::TakeFromWorktop { .. }
+            | Instruction::TakeFromWorktopByAmount { .. }
+            | Instruction::TakeFromWorktopByIds { .. } => self.new_bucket(),
+            Instruction::ReturnToWorktop { bucket_id } => {
+                self.check_bucket(bucket_id)?;
+                self.buckets.remove(bucket_id);
+                self.worktop_dirty = true;
+                Ok(None)
+            }
+            Instruction::AssertWorktopContains { .. }
+            | Instruction::AssertWorktopContainsByAmount { .. }
+            | Instruction::AssertWorktopContainsByIds { .. } => Ok(None),
+            Instruction::PopFromAuthZone => {
+                self.auth_zone_dirty = true;
+                self.new_proof()
+            }
+            Instruction::PushToAuthZone { proof_id } => {
+                self.check_proof(proof_id)?;
+                self.proofs.remove(proof_id);
+                self.auth_zone_dirty = true;
+                Ok(None)
+            }
+            Instruction::ClearAuthZone => {
+                self.auth_zone_dirty = false;
+                Ok(None)
+            }
+            Instruction::CreateProofFromAuthZone { .. }
+            | Instruction::CreateProofFromAuthZoneByAmount { .. }
+            | Instruction::CreateProofFromAuthZoneByIds { .. } => self.new_proof(),
+            Instruction::CreateProofFromBucket { bucket_id } => {
+                self.check_bucket(bucket_id)?;
+                self.new_proof()
+            }
+            Instruction::CloneProof { proof_id } => {
+                self.check_proof(proof_id)?;
+                self.new_proof()
+            }
+            Instruction::DropProof { proof_id } => {
+                self.check_proof(proof_id)?;
+                self.proofs.remove(proof_id);
+                Ok(None)
+            }
+            Instruction::DropAllProofs => {
+                self.proofs.clear();
+                Ok(None)
+            }
+            Instruction::CallFunction { args, .. } | Instruction::CallMethod { args, .. } => {
+                let value = ScryptoValue::from_slice(args).map_err(SplitError::DecodeError)?;
+                for bucket_id in value.bucket_ids.keys() {
+                    self.check_bucket(bucket_id)?;
+                    self.buckets.remove(bucket_id);
+                }
+                for proof_id in value.proof_ids.keys() {
+                    self.check_proof(proof_id)?;
+                    self.proofs.remove(proof_id);
+                }
+                // Resources returned by a call are put onto the worktop, unless the call
+                // takes the entire worktop, in which case the worktop is assumed to be drained
+                self.worktop_dirty = !value
+                    .expressions
+                    .iter()
+                    .any(|(e, _)| e.eq(&Expression::entire_worktop()));
+                if value
+                    .expressions
+                    .iter()
+                    .any(|(e, _)| e.eq(&Expression::entire_auth_zone()))
+                {
+                    self.auth_zone_dirty = true;
+                }
+                Ok(None)
+            }
+            Instruction::PublishPackage { .. } => Ok(None),
+        }
+    }
+}
+
+struct SubManifestBuilder<'a> {
+    prelude: &'a [Instruction],
+    body: &'a [Instruction],
+    created_ids: &'a [Option<CreatedId>],
+    blobs: &'a [Vec<u8>],
+}
+
+impl<'a> SubManifestBuilder<'a> {
+    /// Builds a sub-manifest from a range of the body, re-allocating bucket and proof IDs.
+    fn build(&self, range: Range<usize>) -> Result<TransactionManifest, SplitError> {
+        let mut id_allocator = IdAllocator::new(IdSpace::Transaction);
+        let mut bucket_ids = HashMap::<BucketId, BucketId>::new();
+        let mut proof_ids = HashMap::<ProofId, ProofId>::new();
+        let mut blob_hashes = HashSet::<Hash>::new();
+
+        let mut instructions = self.prelude.to_vec();
+        for i in range {
+            let inst = match self.body[i].clone() {
+                Instruction::ReturnToWorktop { bucket_id } => Instruction::ReturnToWorktop {
+                    bucket_id: Self::lookup_bucket(&bucket_ids, bucket_id)?,
+                },
+                Instruction::PushToAuthZone { proof_id } => Instruction::PushToAuthZone {
+                    proof_id: Self::lookup_proof(&proof_ids, proof_id)?,
+                },
+                Instruction::CreateProofFromBucket { bucket_id } => {
+                    Instruction::CreateProofFromBucket {
+                        bucket_id: Self::lookup_bucket(&bucket_ids, bucket_id)?,
+                    }
+                }
+                Instruction::CloneProof { proof_id } => Instruction::CloneProof {
+                    proof_id: Self::lookup_proof(&proof_ids, proof_id)?,
+                },
+                Instruction::DropProof { proof_id } => Instruction::DropProof {
+                    proof_id: Self::lookup_proof(&proof_ids, proof_id)?,
+                },
+                Instruction::CallFunction {
+                    fn_identifier,
+                    args,
+                } => Instruction::CallFunction {
+                    fn_identifier,
+                    args: Self::replace_ids(&args, &mut proof_ids, &mut bucket_ids)?,
+                },
+                Instruction::CallMethod {
+                    method_identifier,
+                    args,
+                } => Instruction::CallMethod {
+                    method_identifier,
+                    args: Self::replace_ids(&args, &mut proof_ids, &mut bucket_ids)?,
+                },
+                Instruction::PublishPackage { code, abi } => {
+                    blob_hashes.insert(code.0);
+                    blob_hashes.insert(abi.0);
+                    Instruction::PublishPackage { code, abi }
+                }
+                inst => inst,
+            };
+
+            match self.created_ids[i] {
+                Some(CreatedId::Bucket(original)) => {
+                    let bucket_id = id_allocator.new_bucket_id().map_err(id_allocation_error)?;
+                    bucket_ids.insert(original, bucket_id);
+                }
+                Some(CreatedId::Proof(original)) => {
+                    let proof_id = id_allocator.new_proof_id().map_err(id_allocation_error)?;
+                    proof_ids.insert(original, proof_id);
+                }
+                None => {}
+            }
+
+            instructions.push(inst);
+        }
+
+        Ok(TransactionManifest {
+            instructions,
+            blobs: self
+                .blobs
+                .iter()
+                .filter(|blob| blob_hashes.contains(&hash(blob)))
+                .cloned()
+                .collect(),
+        })
+    }
+
+    fn lookup_bucket(
+        bucket_ids: &HashMap<BucketId, BucketId>,
+        bucket_id: BucketId,
+    ) -> Result<BucketId, SplitError> {
+        bucket_ids
+            .get(&bucket_id)
+            .copied()
+            .ok_or(SplitError::IdValidationError(
+                IdValidationError::BucketNotFound(bucket_id),
+            ))
+    }
+
+    fn lookup_proof(
+        proof_ids: &HashMap<ProofId, ProofId>,
+        proof_id: ProofId,
+    ) -> Result<ProofId, SplitError> {
+        proof_ids
+            .get(&proof_id)
+            .copied()
+            .ok_or(SplitError::IdValidationError(
+                IdValidationError::ProofNotFound(proof_id),
+            ))
+    }
+
+    fn replace_ids(
+        args: &[u8],
+        proof_ids: &mut HashMap<ProofId, ProofId>,
+        bucket_ids: &mut HashMap<BucketId, BucketId>,
+    ) -> Result<Vec<u8>, SplitError> {
+        let mut value = ScryptoValue::from_slice(args).map_err(SplitError::DecodeError)?;
+        value
+            .replace_ids(proof_ids, bucket_ids)
+            .map_err(|e| match e {
+                ScryptoValueReplaceError::ProofIdNotFound(proof_id) => {
+                    SplitError::IdValidationError(IdValidationError::ProofNotFound(proof_id))
+                }
+                ScryptoValueReplaceError::BucketIdNotFound(bucket_id) => {
+                    SplitError::IdValidationError(IdValidationError::BucketNotFound(bucket_id))
+                }
+            })?;
+        Ok(value.raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::ManifestBuilder;
+    use scrypto::args;
+    use scrypto::component::ComponentAddress;
+    use scrypto::constants::*;
+    use scrypto::core::NetworkDefinition;
+    use scrypto::math::Decimal;
+
+    const ACCOUNT: ComponentAddress = ComponentAddress::Account([5u8; 26]);
+
+    fn airdrop(recipients: u8) -> TransactionManifest {
+        let mut builder = ManifestBuilder::new(&NetworkDefinition::simulator());
+        builder.lock_fee(Decimal::from(10), ACCOUNT);
+        for i in 0..recipients {
+            let recipient = ComponentAddress::Account([10u8 + i; 26]);
+            builder
+                .withdraw_from_account_by_amount(Decimal::from(2), RADIX_TOKEN, ACCOUNT)
+                .take_from_worktop_by_amount(Decimal::from(1), RADIX_TOKEN, |builder, bucket_id| {
+                    builder.call_method(
+                        recipient,
+                        "deposit",
+                        args!(scrypto::resource::Bucket(bucket_id)),
+                    )
+                })
+                .call_method(
+                    ACCOUNT,
+                    "deposit_batch",
+                    args!(Expression::entire_worktop()),
+                );
+        }
+        builder.build()
+    }
+
+    #[test]
+    fn should_not_split_manifest_within_limit() {
+        let manifest = airdrop(3);
+        let size = encoded_size(&manifest);
+
+        assert_eq!(split_manifest(manifest.clone(), size), Ok(vec![manifest]));
+    }
+
+    #[test]
+    fn should_split_airdrop_at_safe_boundaries() {
+        let manifest = airdrop(10);
+        let max_size = encoded_size(&airdrop(3));
+
+        let manifests = split_manifest(manifest.clone(), max_size).unwrap();
+
+        assert_eq!(manifests.len(), 4);
+        let mut total = 0;
+        for sub_manifest in &manifests {
+            assert!(encoded_size(sub_manifest) <= max_size);
+            assert!(is_lock_fee(&sub_manifest.instructions[0]));
+            assert_eq!((sub_manifest.instructions.len() - 1) % 4, 0);
+            total += sub_manifest.instructions.len() - 1;
+
+            // Bucket IDs restart in every sub-manifest
+            let mut id_allocator = IdAllocator::new(IdSpace::Transaction);
+            let first_bucket_id = id_allocator.new_bucket_id().unwrap();
+            match &sub_manifest.instructions[3] {
+                Instruction::CallMethod { args, .. } => {
+                    let value = ScryptoValue::from_slice(args).unwrap();
+                    assert!(value.bucket_ids.contains_key(&first_bucket_id));
+                }
+                _ => panic!("Expected deposit"),
+            }
+        }
+        assert_eq!(total, manifest.instructions.len() - 1);
+    }
+
+    #[test]
+    fn should_reject_group_larger_than_limit() {
+        let manifest = airdrop(2);
+        let max_size = encoded_size(&airdrop(0));
+
+        assert_eq!(
+            split_manifest(manifest, max_size),
+            Err(SplitError::InstructionGroupTooLarge {
+                start: 1,
+                end: 5,
+                size: encoded_size(&airdrop(1)),
+            })
+        );
+    }
+}
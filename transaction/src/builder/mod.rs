@@ -1,5 +1,7 @@
 mod manifest_builder;
+mod manifest_splitter;
 mod transaction_builder;
 
 pub use manifest_builder::ManifestBuilder;
+pub use manifest_splitter::split_manifest;
 pub use transaction_builder::TransactionBuilder;
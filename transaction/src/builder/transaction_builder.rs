@@ -79,6 +79,7 @@ impl TransactionBuilder {
 #[cfg(test)]
 mod tests {
     use scrypto::core::NetworkDefinition;
+    use scrypto::math::Decimal;
 
     use super::*;
     use crate::builder::*;
@@ -99,6 +100,8 @@ mod tests {
                 notary_as_signatory: true,
                 cost_unit_limit: 1_000_000,
                 tip_percentage: 5,
+                cost_unit_price: Decimal::zero(),
+                message: None,
             })
             .manifest(
                 ManifestBuilder::new(&NetworkDefinition::simulator())
@@ -12,7 +12,9 @@ pub enum HeaderValidationError {
     OutOfEpochRange,
     InvalidNetwork,
     InvalidCostUnitLimit,
+    CostUnitPriceTooLow,
     InvalidTipBps,
+    MessageTooLarge,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -42,6 +44,7 @@ pub enum CallDataValidationError {
     IdValidationError(IdValidationError),
     VaultNotAllowed(VaultId),
     KeyValueStoreNotAllowed(KeyValueStoreId),
+    ComponentNotAllowed(ComponentAddress),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -55,6 +58,23 @@ pub enum TransactionValidationError {
     CallDataValidationError(CallDataValidationError),
 }
 
+/// Represents an error when splitting a manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SplitError {
+    /// Failed to decode the arguments of a call.
+    DecodeError(DecodeError),
+
+    /// The manifest refers to buckets or proofs which don't exist.
+    IdValidationError(IdValidationError),
+
+    /// A group of instructions which can't be split doesn't fit within the size limit.
+    InstructionGroupTooLarge {
+        start: usize,
+        end: usize,
+        size: usize,
+    },
+}
+
 /// Represents an error when parsing arguments.
 #[derive(Debug, Clone)]
 pub enum BuildArgsError {
@@ -71,6 +91,19 @@ pub enum BuildArgsError {
 
     /// Failed to interpret this string as a resource specifier
     InvalidResourceSpecifier(String),
+
+    /// Failed to decode the encoded arguments.
+    FailedToDecode(DecodeError),
+
+    /// The argument is not declared by the ABI.
+    UnexpectedArgument(usize),
+
+    /// The argument's type differs from the one declared by the ABI.
+    TypeMismatch {
+        index: usize,
+        expected: Type,
+        actual: Type,
+    },
 }
 
 /// Represents an error when building a transaction.
@@ -44,7 +44,8 @@ mod tests {
     use scrypto::core::{Blob, FnIdentifier, NativeFnIdentifier, ResourceManagerFnIdentifier};
     use scrypto::math::*;
     use scrypto::resource::{
-        AccessRule, MintParams, Mutability, ResourceAddress, ResourceMethodAuthKey, ResourceType,
+        AccessRule, MintParams, Mutability, NonFungibleDataSchema, ResourceAddress,
+        ResourceMethodAuthKey, ResourceType,
     };
     use scrypto::{core::Expression, resource::NonFungibleId};
 
@@ -162,7 +163,9 @@ mod tests {
                         HashMap::<ResourceMethodAuthKey, (AccessRule, Mutability)>::new(),
                         Some(MintParams::Fungible {
                             amount: "1.0".into()
-                        })
+                        }),
+                        Option::<Decimal>::None,
+                        Option::<NonFungibleDataSchema>::None
                     ),
                 },
                 Instruction::CallMethod {
@@ -24,8 +24,11 @@ pub enum DecompileError {
     UnrecognizedNativeFunction,
 }
 
+/// Renders a manifest as transaction manifest text, one instruction per line.
+///
+/// Buckets and proofs are named sequentially; blobs are referenced by hash.
 pub fn decompile(
-    instructions: &[Instruction],
+    manifest: &TransactionManifest,
     network: &NetworkDefinition,
 ) -> Result<String, DecompileError> {
     let bech32_encoder = Bech32Encoder::new(network);
@@ -33,7 +36,7 @@ pub fn decompile(
     let mut id_validator = IdValidator::new();
     let mut buckets = HashMap::<BucketId, String>::new();
     let mut proofs = HashMap::<ProofId, String>::new();
-    for inst in instructions {
+    for inst in &manifest.instructions {
         match inst.clone() {
             Instruction::TakeFromWorktop { resource_address } => {
                 let bucket_id = id_validator
@@ -292,6 +295,20 @@ pub fn decompile(
                         buf.push(' ');
                         buf.push_str(&mint_params.to_string());
 
+                        // The trailing optional arguments are left out unless set
+                        if input.supply_cap.is_some() || input.non_fungible_data_schema.is_some() {
+                            let supply_cap = ScryptoValue::from_typed(&input.supply_cap);
+                            buf.push(' ');
+                            buf.push_str(&supply_cap.to_string());
+                        }
+
+                        if input.non_fungible_data_schema.is_some() {
+                            let non_fungible_data_schema =
+                                ScryptoValue::from_typed(&input.non_fungible_data_schema);
+                            buf.push(' ');
+                            buf.push_str(&non_fungible_data_schema.to_string());
+                        }
+
                         buf.push_str(";\n");
                     }
                     _ => return Err(DecompileError::UnrecognizedNativeFunction),
@@ -399,7 +416,7 @@ mod tests {
         ];
         let manifest = compile(manifest_str, &network, blobs).unwrap();
 
-        let manifest2 = decompile(&manifest.instructions, &network).unwrap();
+        let manifest2 = decompile(&manifest, &network).unwrap();
         assert_eq!(
             manifest2,
             r#"CALL_METHOD ComponentAddress("account_sim1q02r73u7nv47h80e30pc3q6ylsj7mgvparm3pnsm780qgsy064") "withdraw_by_amount" Decimal("5") ResourceAddress("resource_sim1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzqu57yag");
@@ -425,4 +442,21 @@ PUBLISH_PACKAGE Blob("36dae540b7889956f1f1d8d46ba23e5e44bf5723aef2a8e6b698686c02
 "#
         )
     }
+
+    #[cfg(not(feature = "alloc"))]
+    #[test]
+    fn test_decompile_round_trip() {
+        let network = NetworkDefinition::simulator();
+        let manifest_str = include_str!("../../examples/complex.rtm");
+        let blobs = vec![
+            include_bytes!("../../examples/code.blob").to_vec(),
+            include_bytes!("../../examples/abi.blob").to_vec(),
+        ];
+        let manifest = compile(manifest_str, &network, blobs.clone()).unwrap();
+
+        let decompiled = decompile(&manifest, &network).unwrap();
+        let recompiled = compile(&decompiled, &network, blobs).unwrap();
+
+        assert_eq!(recompiled.instructions, manifest.instructions);
+    }
 }
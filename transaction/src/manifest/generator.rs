@@ -25,6 +25,9 @@ use crate::manifest::ast;
 use crate::model::*;
 use crate::validation::*;
 
+/// The number of `CREATE_RESOURCE` arguments, including the optional trailing ones.
+const CREATE_RESOURCE_ARGS: usize = 6;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum GeneratorError {
     InvalidType {
@@ -376,6 +379,12 @@ pub fn generate_instruction(
                     .map_err(GeneratorError::IdValidationError)?;
                 fields.push(validated_arg.dom);
             }
+            // The supply cap and non-fungible data schema may be left out
+            while fields.len() < CREATE_RESOURCE_ARGS {
+                fields.push(Value::Option {
+                    value: Box::new(None),
+                });
+            }
 
             Instruction::CallFunction {
                 fn_identifier: FnIdentifier::Native(NativeFnIdentifier::ResourceManager(
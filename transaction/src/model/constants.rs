@@ -1,3 +1,4 @@
 pub const TRANSACTION_VERSION_V1: u8 = 1;
 pub const MAX_EPOCH_DURATION: u64 = 100;
 pub const MAX_NUMBER_OF_INTENT_SIGNATURES: usize = 16;
+pub const MAX_MESSAGE_SIZE: usize = 2048;
@@ -5,6 +5,7 @@ use sbor::*;
 use scrypto::component::ComponentAddress;
 use scrypto::core::{NativeFnIdentifier, Receiver};
 use scrypto::crypto::*;
+use scrypto::math::Decimal;
 use scrypto::resource::NonFungibleAddress;
 
 #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeId)]
@@ -33,10 +34,16 @@ pub trait ExecutableTransaction {
     /// Returns the tip percentage
     fn tip_percentage(&self) -> u32;
 
+    /// Returns the price paid per cost unit, in XRD
+    fn cost_unit_price(&self) -> Decimal;
+
     /// Returns the instructions to execute.
     fn instructions(&self) -> &[Instruction];
 
     fn initial_proofs(&self) -> Vec<NonFungibleAddress>;
 
     fn blobs(&self) -> &[Vec<u8>];
+
+    /// Returns the message attached to the transaction, if any.
+    fn message(&self) -> Option<&[u8]>;
 }
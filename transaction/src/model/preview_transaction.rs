@@ -2,6 +2,7 @@ use sbor::*;
 use scrypto::buffer::scrypto_encode;
 use scrypto::constants::{ECDSA_TOKEN, ED25519_TOKEN};
 use scrypto::crypto::{hash, Hash, PublicKey};
+use scrypto::math::Decimal;
 use scrypto::resource::{NonFungibleAddress, NonFungibleId};
 
 use crate::model::{ExecutableTransaction, Instruction, TransactionIntent};
@@ -71,7 +72,15 @@ impl ExecutableTransaction for ValidatedPreviewTransaction {
         self.preview_intent.intent.header.tip_percentage
     }
 
+    fn cost_unit_price(&self) -> Decimal {
+        self.preview_intent.intent.header.cost_unit_price
+    }
+
     fn blobs(&self) -> &[Vec<u8>] {
         &self.preview_intent.intent.manifest.blobs
     }
+
+    fn message(&self) -> Option<&[u8]> {
+        self.preview_intent.intent.header.message.as_deref()
+    }
 }
@@ -1,10 +1,13 @@
+use sbor::rust::collections::HashSet;
 use sbor::rust::vec::Vec;
 use scrypto::buffer::scrypto_encode;
 use scrypto::core::NetworkDefinition;
 use scrypto::crypto::*;
+use scrypto::math::Decimal;
 use scrypto::resource::NonFungibleAddress;
 
 use crate::builder::TransactionBuilder;
+use crate::errors::SignatureValidationError;
 use crate::model::*;
 
 /// Represents a test transaction, for testing/simulation purpose only.
@@ -14,11 +17,17 @@ pub struct TestTransaction {
 }
 
 impl TestTransaction {
+    /// Creates a test transaction signed by the given public keys.
+    ///
+    /// The signer list is sorted and de-duplicated, so lists that differ only in order or
+    /// repetition produce the same transaction.
     pub fn new(
         manifest: TransactionManifest,
         nonce: u64,
-        signer_public_keys: Vec<PublicKey>,
+        mut signer_public_keys: Vec<PublicKey>,
     ) -> Self {
+        signer_public_keys.sort_by_cached_key(scrypto_encode);
+        signer_public_keys.dedup();
         let transaction = TransactionBuilder::new()
             .header(TransactionHeader {
                 version: TRANSACTION_VERSION_V1,
@@ -30,6 +39,8 @@ impl TestTransaction {
                 notary_as_signatory: false,
                 cost_unit_limit: 10_000_000,
                 tip_percentage: 5,
+                cost_unit_price: Decimal::from("0.0000001"),
+                message: None,
             })
             .manifest(manifest)
             .notary_signature(EcdsaSecp256k1Signature([0u8; 65]).into())
@@ -40,6 +51,24 @@ impl TestTransaction {
             signer_public_keys,
         }
     }
+
+    /// Creates a test transaction signed by the given public keys, rejecting duplicate signers.
+    ///
+    /// Apart from the duplicate check, the signer list is normalized as in [`TestTransaction::new`].
+    pub fn new_strict(
+        manifest: TransactionManifest,
+        nonce: u64,
+        signer_public_keys: Vec<PublicKey>,
+    ) -> Result<Self, SignatureValidationError> {
+        let mut signers = HashSet::new();
+        for public_key in &signer_public_keys {
+            if !signers.insert(public_key) {
+                return Err(SignatureValidationError::DuplicateSigner);
+            }
+        }
+
+        Ok(Self::new(manifest, nonce, signer_public_keys))
+    }
 }
 
 impl ExecutableTransaction for TestTransaction {
@@ -59,6 +88,10 @@ impl ExecutableTransaction for TestTransaction {
         self.transaction.signed_intent.intent.header.tip_percentage
     }
 
+    fn cost_unit_price(&self) -> Decimal {
+        self.transaction.signed_intent.intent.header.cost_unit_price
+    }
+
     fn instructions(&self) -> &[Instruction] {
         &self.transaction.signed_intent.intent.manifest.instructions
     }
@@ -70,4 +103,55 @@ impl ExecutableTransaction for TestTransaction {
     fn blobs(&self) -> &[Vec<u8>] {
         &self.transaction.signed_intent.intent.manifest.blobs
     }
+
+    fn message(&self) -> Option<&[u8]> {
+        self.transaction
+            .signed_intent
+            .intent
+            .header
+            .message
+            .as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::ManifestBuilder;
+    use scrypto::constants::SYS_FAUCET_COMPONENT;
+
+    fn manifest() -> TransactionManifest {
+        ManifestBuilder::new(&NetworkDefinition::simulator())
+            .lock_fee(10.into(), SYS_FAUCET_COMPONENT)
+            .build()
+    }
+
+    fn public_key(seed: u8) -> PublicKey {
+        EcdsaSecp256k1PublicKey([seed; 33]).into()
+    }
+
+    #[test]
+    fn signer_order_and_duplicates_are_normalized() {
+        let first = TestTransaction::new(manifest(), 1, vec![public_key(1), public_key(2)]);
+        let second = TestTransaction::new(
+            manifest(),
+            1,
+            vec![public_key(2), public_key(1), public_key(2)],
+        );
+
+        assert_eq!(first.signer_public_keys, second.signer_public_keys);
+        assert_eq!(first.transaction_hash(), second.transaction_hash());
+        assert_eq!(first.initial_proofs(), second.initial_proofs());
+    }
+
+    #[test]
+    fn new_strict_rejects_duplicate_signers() {
+        assert!(matches!(
+            TestTransaction::new_strict(manifest(), 1, vec![public_key(1), public_key(1)]),
+            Err(SignatureValidationError::DuplicateSigner)
+        ));
+        assert!(
+            TestTransaction::new_strict(manifest(), 1, vec![public_key(1), public_key(2)]).is_ok()
+        );
+    }
 }
@@ -2,6 +2,7 @@ use sbor::*;
 use scrypto::buffer::{scrypto_decode, scrypto_encode};
 use scrypto::core::NetworkDefinition;
 use scrypto::crypto::{hash, Hash, PublicKey, Signature, SignatureWithPublicKey};
+use scrypto::math::Decimal;
 
 use crate::manifest::{compile, CompileError};
 use crate::model::Instruction;
@@ -20,6 +21,14 @@ pub struct TransactionHeader {
     pub notary_as_signatory: bool,
     pub cost_unit_limit: u32,
     pub tip_percentage: u32,
+    /// The price the sender is willing to pay per cost unit, in XRD
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub cost_unit_price: Decimal,
+    /// An optional message for indexers, e.g. a memo or client id; it doesn't affect execution
+    pub message: Option<Vec<u8>>,
 }
 
 #[derive(Debug, Clone, TypeId, Encode, Decode, PartialEq, Eq)]
@@ -152,6 +161,8 @@ mod tests {
                 notary_as_signatory: false,
                 cost_unit_limit: 1_000_000,
                 tip_percentage: 5,
+                cost_unit_price: Decimal::zero(),
+                message: None,
             },
             "CLEAR_AUTH_ZONE;",
             Vec::new(),
@@ -174,18 +185,18 @@ mod tests {
         };
 
         assert_eq!(
-            "671a87cacf3f359ed6f368c50684fe963567a345eea7382ad931dd8a09d30e5a",
+            "16db6802127cdc67aa1ca46da7e1b8c4f1a1965f194004c6dec2dba374685b99",
             transaction.signed_intent.intent.hash().to_string()
         );
         assert_eq!(
-            "95299e1b74664150ae319ccade62cc0ed605548c65115f25272c6e4269182f21",
+            "9caf05817d0cfa6da07a7c98fb2f0b5e97bf438bc6231d883555ce563d396d70",
             transaction.signed_intent.hash().to_string()
         );
         assert_eq!(
-            "bcfc92958a504627cfa04b8b1dc9804c5e3a039e5231759258b3be4c6d6e740a",
+            "9108b264acd9ae72ae977585d2662c0d3e7496a6170410d855d64ec7eb0a5bf5",
             transaction.hash().to_string()
         );
-        assert_eq!("1002000000100200000010020000001009000000070107f20a00000000000000000a64000000000000000a0500000000000000110e0000004563647361536563703235366b3101000000912100000002f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f901000940420f00090500000010020000003011010000000d000000436c656172417574685a6f6e65000000003030000000003011020000000e0000004563647361536563703235366b310100000092410000000132e68b38e908177113142e58aee6453c34615d1e6d8c48530d5748f6367e27925c55a01c7735fdeda44928a7d015a0e48203f4a39834e73412d150dff092abe70e0000004563647361536563703235366b3101000000924100000000144cbd023cc482c4a39dca0e2d3f2a61bc765c9bbd72e75cf10484a7a3ddf1457fc8bebef15f703cb67e9818e40954a6081f0338e34f17730133050149d93468110e0000004563647361536563703235366b3101000000924100000000245d5ac8983efbf1f4aaf9f369a571d8bdfaf07f1173299998d043252183a1ac7ab0428724dd94e195bdf0092c3e34f78814a7300cbf2ab41131f9c4da69b8ab", hex::encode(scrypto_encode(&transaction)));
+        assert_eq!("100200000010020000001002000000100b000000070107f20a00000000000000000a64000000000000000a0500000000000000110e0000004563647361536563703235366b3101000000912100000002f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f901000940420f000905000000a1200000000000000000000000000000000000000000000000000000000000000000000000120110020000003011010000000d000000436c656172417574685a6f6e65000000003030000000003011020000000e0000004563647361536563703235366b31010000009241000000008d74c842f586a1f7b00ad418bdd7fb895ee45582d32b1f26c72b5cebda050fda344d19e9ecd735b0b7cbae2d367fd3b4cb163149dba709efb8c4226e076e73720e0000004563647361536563703235366b3101000000924100000001fb68d257dd9f64a3b1516108538e66d78684d678c0865fb4ff9961a83e18ea1e02ec33682f432ab2511351bde987e2e81843d7e5ae17c566bc3f1190a23260f4110e0000004563647361536563703235366b3101000000924100000000d1217ca1667fc78219fe8092f274b5feea684c179574df41d3a2abbc1dc2cd106df7dfd2cf2437b909ef87121b897ff0c63ebd3cdbf661f2df4611927e6ed6f8", hex::encode(scrypto_encode(&transaction)));
     }
 
     #[test]
@@ -208,6 +219,8 @@ mod tests {
                 notary_as_signatory: false,
                 cost_unit_limit: 1_000_000,
                 tip_percentage: 5,
+                cost_unit_price: Decimal::zero(),
+                message: None,
             },
             "CLEAR_AUTH_ZONE;",
             Vec::new(),
@@ -230,17 +243,17 @@ mod tests {
         };
 
         assert_eq!(
-            "08e1fc53bc3542c9e641bb6335c375bffcbc7bf86c96feecff7b6689568c9d1a",
+            "d8b45ea821fd7016877da23cd1df783af45321ae470f905ba5899e7230b720fb",
             transaction.signed_intent.intent.hash().to_string()
         );
         assert_eq!(
-            "d96f9285e8001ebb38cf676aee8009ec471afd7660f1229e512d30790d6e2b06",
+            "960da3998f6cebb0c596ab7d875aa5d7b499d9ffb2598ac8f7d275079434a8ed",
             transaction.signed_intent.hash().to_string()
         );
         assert_eq!(
-            "5c4bae2e3713a711c513a096c45a06d695d39188c77d0f2d0d1283cfa6a026a7",
+            "76292a1827af5661b5242c9164d36ab76d9f75546fd267cfad080944a9886bc1",
             transaction.hash().to_string()
         );
-        assert_eq!("1002000000100200000010020000001009000000070107f20a00000000000000000a64000000000000000a0500000000000000110c000000456464736145643235353139010000009320000000f381626e41e7027ea431bfe3009e94bdd25a746beec468948d6c3c7c5dc9a54b01000940420f00090500000010020000003011010000000d000000436c656172417574685a6f6e65000000003030000000003011020000000c0000004564647361456432353531390200000093200000004cb5abf6ad79fbf5abbccafcc269d85cd2651ed4b885b5869f241aedf0a5ba299440000000c5a8fc87ec5d839b6b9914aeb320a8f6d758e25de9a8ae737f526a9d79df9b179e991fdf877f54ca38ad6177c34ea7cca04b4ffac627d3a224ef095121b7f0070c0000004564647361456432353531390200000093200000007422b9887598068e32c4448a949adb290d0f4e35b9e01b0ee5f1a1e600fe2674944000000079ffb153e8b19103725e2897dabf6214b5b0c189d285d9dcf4c3785bcc952540966821b07ce5cc4972c47148d4dd26087f6161054a8dd600ba933ea789b3d808110c000000456464736145643235353139010000009440000000b17f1ddea31beeb62266f450a4cdb7d8f2810941bddcf6270cad1b23208160e5c12e2952e9fa5f810d57c1b6a9c15bb9413aeb6f21bfb803c70fc15bef488e02", hex::encode(scrypto_encode(&transaction)));
+        assert_eq!("100200000010020000001002000000100b000000070107f20a00000000000000000a64000000000000000a0500000000000000110c000000456464736145643235353139010000009320000000f381626e41e7027ea431bfe3009e94bdd25a746beec468948d6c3c7c5dc9a54b01000940420f000905000000a1200000000000000000000000000000000000000000000000000000000000000000000000120110020000003011010000000d000000436c656172417574685a6f6e65000000003030000000003011020000000c0000004564647361456432353531390200000093200000004cb5abf6ad79fbf5abbccafcc269d85cd2651ed4b885b5869f241aedf0a5ba2994400000007b858191052ab3fdc35df24ca73a1979efd14a39f88f11f1933ceec41f98dcb1b4d8d3f98bc5ea1ad05380e584df55991b0363649ec0937972a52c3b17c2f00b0c0000004564647361456432353531390200000093200000007422b9887598068e32c4448a949adb290d0f4e35b9e01b0ee5f1a1e600fe267494400000000ce65c294e3e8dcd79f1fb3ce1a628550af1d63ae49e1b5f43250ccd88e6b74efdfe2e32eb6c33876b84641f1ef48a48b083b6569f540f6b467cdf784bffa400110c00000045646473614564323535313901000000944000000021ad74c66f02d685dd3c1afa53d34a7e054ea34970fcdba165fcb3754a4d1150c4132b8b8d1d216c9eaf99aa0df19fffcca51ebd645e83020b754508eb9de00b", hex::encode(scrypto_encode(&transaction)));
     }
 }
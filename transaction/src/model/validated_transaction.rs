@@ -1,6 +1,7 @@
 use sbor::rust::vec::Vec;
 use scrypto::buffer::scrypto_encode;
 use scrypto::crypto::*;
+use scrypto::math::Decimal;
 use scrypto::resource::NonFungibleAddress;
 
 use crate::model::*;
@@ -14,9 +15,14 @@ pub struct Validated<T> {
     pub initial_proofs: Vec<NonFungibleAddress>,
     pub cost_unit_limit: u32,
     pub tip_percentage: u32,
+    pub cost_unit_price: Decimal,
     pub blobs: Vec<Vec<u8>>,
+    pub message: Option<Vec<u8>>,
 }
 
+/// Represents a validated notarized transaction
+pub type ValidatedTransaction = Validated<NotarizedTransaction>;
+
 impl<T> Validated<T> {
     pub fn new(
         transaction: T,
@@ -25,7 +31,9 @@ impl<T> Validated<T> {
         initial_proofs: Vec<NonFungibleAddress>,
         cost_unit_limit: u32,
         tip_percentage: u32,
+        cost_unit_price: Decimal,
         blobs: Vec<Vec<u8>>,
+        message: Option<Vec<u8>>,
     ) -> Self {
         Self {
             transaction,
@@ -34,7 +42,9 @@ impl<T> Validated<T> {
             initial_proofs,
             cost_unit_limit,
             tip_percentage,
+            cost_unit_price,
             blobs,
+            message,
         }
     }
 
@@ -64,6 +74,10 @@ impl<T> ExecutableTransaction for Validated<T> {
         self.tip_percentage
     }
 
+    fn cost_unit_price(&self) -> Decimal {
+        self.cost_unit_price
+    }
+
     fn instructions(&self) -> &[Instruction] {
         &self.instructions
     }
@@ -75,4 +89,8 @@ impl<T> ExecutableTransaction for Validated<T> {
     fn blobs(&self) -> &[Vec<u8>] {
         &self.blobs
     }
+
+    fn message(&self) -> Option<&[u8]> {
+        self.message.as_deref()
+    }
 }
@@ -23,6 +23,21 @@ impl EcdsaSecp256k1PrivateKey {
         EcdsaSecp256k1Signature(buf)
     }
 
+    /// Signs a message hash as is, returning the signature and its recovery id.
+    ///
+    /// The public key can be recovered from the signature with `recover_ecdsa_secp256k1_hash`.
+    pub fn sign_recoverable(&self, message: &Hash) -> (EcdsaSecp256k1Signature, u8) {
+        let m = Message::from_slice(&message.0).expect("Hash is always a valid message");
+        let signature = secp256k1::SECP256K1.sign_ecdsa_recoverable(&m, &self.0);
+        let (recovery_id, signature_data) = signature.serialize_compact();
+        let recovery_id = recovery_id.to_i32() as u8;
+
+        let mut buf = [0u8; 65];
+        buf[0] = recovery_id;
+        buf[1..].copy_from_slice(&signature_data);
+        (EcdsaSecp256k1Signature(buf), recovery_id)
+    }
+
     pub fn to_bytes(&self) -> Vec<u8> {
         self.0.secret_bytes().to_vec()
     }
@@ -46,7 +61,7 @@ impl EcdsaSecp256k1PrivateKey {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::validation::verify_ecdsa_secp256k1;
+    use crate::validation::{recover_ecdsa_secp256k1_hash, verify_ecdsa_secp256k1};
     use sbor::rust::str::FromStr;
     use scrypto::{
         constants::ECDSA_TOKEN,
@@ -68,6 +83,28 @@ mod tests {
         assert!(verify_ecdsa_secp256k1(test_message.as_bytes(), &pk, &sig));
     }
 
+    #[test]
+    fn sign_recoverable_and_recover() {
+        let sk = EcdsaSecp256k1PrivateKey::from_u64(42).unwrap();
+        let message = hash("Test");
+
+        let (sig, recovery_id) = sk.sign_recoverable(&message);
+
+        assert_eq!(
+            recover_ecdsa_secp256k1_hash(&message, &sig, recovery_id),
+            Some(sk.public_key())
+        );
+        assert_ne!(
+            recover_ecdsa_secp256k1_hash(&hash("Other"), &sig, recovery_id),
+            Some(sk.public_key())
+        );
+        assert_ne!(
+            recover_ecdsa_secp256k1_hash(&message, &sig, recovery_id ^ 1),
+            Some(sk.public_key())
+        );
+        assert_eq!(recover_ecdsa_secp256k1_hash(&message, &sig, 4), None);
+    }
+
     #[test]
     fn test_non_fungible_address_codec() {
         let expected = "000000000000000000000000000000000000000000000000000002300721000000031b84c5567b126440995d3ed5aaba0565d71e1834604819ff9c17f5e9d5dd078f";
@@ -46,7 +46,7 @@ impl EddsaEd25519PrivateKey {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::validation::verify_eddsa_ed25519;
+    use crate::validation::{verify_eddsa_ed25519, verify_eddsa_ed25519_batch};
     use sbor::rust::str::FromStr;
 
     #[test]
@@ -63,4 +63,19 @@ mod tests {
         assert_eq!(sk.sign(test_message.as_bytes()), sig);
         assert!(verify_eddsa_ed25519(test_message.as_bytes(), &pk, &sig));
     }
+
+    #[test]
+    fn verify_batch() {
+        let mut items: Vec<(Hash, EddsaEd25519PublicKey, EddsaEd25519Signature)> = (1..=3)
+            .map(|i| {
+                let sk = EddsaEd25519PrivateKey::from_u64(i).unwrap();
+                let message = hash(i.to_le_bytes());
+                (message, sk.public_key(), sk.sign(&message.0))
+            })
+            .collect();
+        assert!(verify_eddsa_ed25519_batch(&items));
+
+        items[1].0 = hash("Tampered");
+        assert!(!verify_eddsa_ed25519_batch(&items));
+    }
 }
@@ -1,3 +1,4 @@
+use sbor::rust::vec::Vec;
 use scrypto::crypto::*;
 
 pub fn recover(message: &[u8], signature: &SignatureWithPublicKey) -> Option<PublicKey> {
@@ -28,6 +29,20 @@ pub fn recover_ecdsa_secp256k1(
     None
 }
 
+/// Recovers the public key which signed a message hash as is, e.g. with `sign_recoverable`.
+pub fn recover_ecdsa_secp256k1_hash(
+    message: &Hash,
+    signature: &EcdsaSecp256k1Signature,
+    recovery_id: u8,
+) -> Option<EcdsaSecp256k1PublicKey> {
+    let id = secp256k1::ecdsa::RecoveryId::from_i32(recovery_id.into()).ok()?;
+    let sig = secp256k1::ecdsa::RecoverableSignature::from_compact(&signature.0[1..], id).ok()?;
+    let msg = secp256k1::Message::from_slice(&message.0).expect("Hash is always a valid message");
+    sig.recover(&msg)
+        .ok()
+        .map(|pk| EcdsaSecp256k1PublicKey(pk.serialize()))
+}
+
 pub fn verify(message: &[u8], public_key: &PublicKey, signature: &Signature) -> bool {
     match (public_key, signature) {
         (PublicKey::EcdsaSecp256k1(pk), Signature::EcdsaSecp256k1(sig)) => {
@@ -74,3 +89,29 @@ pub fn verify_eddsa_ed25519(
 
     false
 }
+
+/// Verifies a batch of Ed25519 signatures at once, which is faster than verifying them one by one.
+///
+/// Returns `true` only if all signatures are valid.
+pub fn verify_eddsa_ed25519_batch<M: AsRef<[u8]>>(
+    items: &[(M, EddsaEd25519PublicKey, EddsaEd25519Signature)],
+) -> bool {
+    let mut messages = Vec::with_capacity(items.len());
+    let mut signatures = Vec::with_capacity(items.len());
+    let mut public_keys = Vec::with_capacity(items.len());
+    for (message, public_key, signature) in items {
+        match (
+            ed25519_dalek::Signature::from_bytes(&signature.0),
+            ed25519_dalek::PublicKey::from_bytes(&public_key.0),
+        ) {
+            (Ok(sig), Ok(pk)) => {
+                messages.push(message.as_ref());
+                signatures.push(sig);
+                public_keys.push(pk);
+            }
+            _ => return false,
+        }
+    }
+
+    ed25519_dalek::verify_batch(&messages, &signatures, &public_keys).is_ok()
+}
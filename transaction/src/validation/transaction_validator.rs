@@ -2,7 +2,8 @@ use sbor::Decode;
 use std::collections::HashSet;
 
 use scrypto::buffer::scrypto_decode;
-use scrypto::crypto::PublicKey;
+use scrypto::crypto::{PublicKey, SignatureWithPublicKey};
+use scrypto::math::Decimal;
 use scrypto::values::*;
 
 use crate::errors::{SignatureValidationError, *};
@@ -34,12 +35,25 @@ pub trait TransactionValidator<T: Decode> {
     ) -> Result<Validated<T>, TransactionValidationError>;
 }
 
+/// Decodes and validates a notarized transaction, without executing it.
+///
+/// Runs the same deserialization, header, signature, id and intent hash checks as
+/// `NotarizedTransactionValidator`.
+pub fn validate_transaction(
+    transaction: &[u8],
+    config: ValidationConfig,
+    intent_hash_manager: &impl IntentHashManager,
+) -> Result<ValidatedTransaction, TransactionValidationError> {
+    NotarizedTransactionValidator::new(config).validate_from_slice(transaction, intent_hash_manager)
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct ValidationConfig {
     pub network_id: u8,
     pub current_epoch: u64,
     pub max_cost_unit_limit: u32,
     pub min_tip_percentage: u32,
+    pub min_cost_unit_price: Decimal,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -66,7 +80,9 @@ impl TransactionValidator<NotarizedTransaction> for NotarizedTransactionValidato
 
         let cost_unit_limit = transaction.signed_intent.intent.header.cost_unit_limit;
         let tip_percentage = transaction.signed_intent.intent.header.tip_percentage;
+        let cost_unit_price = transaction.signed_intent.intent.header.cost_unit_price;
         let blobs = transaction.signed_intent.intent.manifest.blobs.clone();
+        let message = transaction.signed_intent.intent.header.message.clone();
 
         Ok(Validated::new(
             transaction,
@@ -75,7 +91,9 @@ impl TransactionValidator<NotarizedTransaction> for NotarizedTransactionValidato
             AuthModule::signer_keys_to_non_fungibles(&keys),
             cost_unit_limit,
             tip_percentage,
+            cost_unit_price,
             blobs,
+            message,
         ))
     }
 }
@@ -237,10 +255,20 @@ impl NotarizedTransactionValidator {
         if header.cost_unit_limit > self.config.max_cost_unit_limit {
             return Err(HeaderValidationError::InvalidCostUnitLimit);
         }
+        if header.cost_unit_price < self.config.min_cost_unit_price {
+            return Err(HeaderValidationError::CostUnitPriceTooLow);
+        }
         if header.tip_percentage < self.config.min_tip_percentage {
             return Err(HeaderValidationError::InvalidTipBps);
         }
 
+        // message
+        if let Some(message) = &header.message {
+            if message.len() > MAX_MESSAGE_SIZE {
+                return Err(HeaderValidationError::MessageTooLarge);
+            }
+        }
+
         Ok(())
     }
 
@@ -256,18 +284,39 @@ impl NotarizedTransactionValidator {
         // verify intent signature
         let mut signers = HashSet::new();
         let intent_payload = transaction.signed_intent.intent.to_bytes();
+        let mut ed25519_signatures = Vec::new();
         for sig in &transaction.signed_intent.intent_signatures {
             let public_key = recover(&intent_payload, sig)
                 .ok_or(SignatureValidationError::InvalidIntentSignature)?;
 
-            if !verify(&intent_payload, &public_key, &sig.signature()) {
-                return Err(SignatureValidationError::InvalidIntentSignature);
+            match sig {
+                SignatureWithPublicKey::EddsaEd25519 {
+                    public_key,
+                    signature,
+                } => {
+                    ed25519_signatures.push((&intent_payload, *public_key, *signature));
+                }
+                _ => {
+                    if !verify(&intent_payload, &public_key, &sig.signature()) {
+                        return Err(SignatureValidationError::InvalidIntentSignature);
+                    }
+                }
             }
 
             if !signers.insert(public_key) {
                 return Err(SignatureValidationError::DuplicateSigner);
             }
         }
+        let ed25519_signatures_valid = match ed25519_signatures.as_slice() {
+            [] => true,
+            [(message, public_key, signature)] => {
+                verify_eddsa_ed25519(message, public_key, signature)
+            }
+            batch => verify_eddsa_ed25519_batch(batch),
+        };
+        if !ed25519_signatures_valid {
+            return Err(SignatureValidationError::InvalidIntentSignature);
+        }
 
         if transaction.signed_intent.intent.header.notary_as_signatory {
             signers.insert(transaction.signed_intent.intent.header.notary_public_key);
@@ -295,15 +344,17 @@ impl NotarizedTransactionValidator {
         id_validator
             .move_resources(&value)
             .map_err(CallDataValidationError::IdValidationError)?;
-        if let Some(vault_id) = value.vault_ids.iter().nth(0) {
-            return Err(CallDataValidationError::VaultNotAllowed(vault_id.clone()));
-        }
-        if let Some(kv_store_id) = value.kv_store_ids.iter().nth(0) {
-            return Err(CallDataValidationError::KeyValueStoreNotAllowed(
-                kv_store_id.clone(),
-            ));
-        }
-        Ok(())
+        value.validate_no_stored_nodes().map_err(|e| match e {
+            ScryptoValueValidationError::VaultNotAllowed(vault_id) => {
+                CallDataValidationError::VaultNotAllowed(vault_id)
+            }
+            ScryptoValueValidationError::KeyValueStoreNotAllowed(kv_store_id) => {
+                CallDataValidationError::KeyValueStoreNotAllowed(kv_store_id)
+            }
+            ScryptoValueValidationError::ComponentNotAllowed(component_address) => {
+                CallDataValidationError::ComponentNotAllowed(component_address)
+            }
+        })
     }
 }
 
@@ -314,6 +365,7 @@ mod tests {
     use super::*;
     use crate::{
         builder::ManifestBuilder, builder::TransactionBuilder, signing::EcdsaSecp256k1PrivateKey,
+        signing::EddsaEd25519PrivateKey, signing::Signer,
     };
 
     macro_rules! assert_invalid_tx {
@@ -324,6 +376,7 @@ mod tests {
                 current_epoch: 1,
                 max_cost_unit_limit: 10_000_000,
                 min_tip_percentage: 0,
+                min_cost_unit_price: Decimal::zero(),
             };
             let validator = NotarizedTransactionValidator::new(config);
             assert_eq!(
@@ -387,6 +440,171 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_invalid_message() {
+        let mut transaction = create_transaction(1, 0, 100, 5, vec![1], 2);
+        transaction.signed_intent.intent.header.message = Some(vec![0u8; MAX_MESSAGE_SIZE + 1]);
+
+        let validator = NotarizedTransactionValidator::new(ValidationConfig {
+            network_id: NetworkDefinition::simulator().id,
+            current_epoch: 1,
+            max_cost_unit_limit: 10_000_000,
+            min_tip_percentage: 0,
+            min_cost_unit_price: Decimal::zero(),
+        });
+
+        assert_eq!(
+            Err(TransactionValidationError::HeaderValidationError(
+                HeaderValidationError::MessageTooLarge
+            )),
+            validator.validate(transaction, &TestIntentHashManager::new())
+        );
+    }
+
+    #[test]
+    fn test_cost_unit_price() {
+        let mut intent = create_transaction(1, 0, 100, 5, vec![1], 2)
+            .signed_intent
+            .intent;
+        let validator = NotarizedTransactionValidator::new(ValidationConfig {
+            network_id: NetworkDefinition::simulator().id,
+            current_epoch: 1,
+            max_cost_unit_limit: 10_000_000,
+            min_tip_percentage: 0,
+            min_cost_unit_price: "0.0000001".parse().unwrap(),
+        });
+
+        intent.header.cost_unit_price = "0.00000009".parse().unwrap();
+        assert_eq!(
+            Err(HeaderValidationError::CostUnitPriceTooLow),
+            validator.validate_header(&intent)
+        );
+
+        intent.header.cost_unit_price = "0.0000001".parse().unwrap();
+        assert_eq!(Ok(()), validator.validate_header(&intent));
+    }
+
+    #[test]
+    fn test_tampered_message() {
+        let sk_notary = EcdsaSecp256k1PrivateKey::from_u64(2).unwrap();
+        let mut transaction = TransactionBuilder::new()
+            .header(TransactionHeader {
+                version: 1,
+                network_id: NetworkDefinition::simulator().id,
+                start_epoch_inclusive: 0,
+                end_epoch_exclusive: 100,
+                nonce: 5,
+                notary_public_key: sk_notary.public_key().into(),
+                notary_as_signatory: false,
+                cost_unit_limit: 1_000_000,
+                tip_percentage: 5,
+                cost_unit_price: Decimal::zero(),
+                message: Some(b"invoice #1".to_vec()),
+            })
+            .manifest(
+                ManifestBuilder::new(&NetworkDefinition::simulator())
+                    .clear_auth_zone()
+                    .build(),
+            )
+            .notarize(&sk_notary)
+            .build();
+        transaction.signed_intent.intent.header.message = Some(b"invoice #2".to_vec());
+
+        let validator = NotarizedTransactionValidator::new(ValidationConfig {
+            network_id: NetworkDefinition::simulator().id,
+            current_epoch: 1,
+            max_cost_unit_limit: 10_000_000,
+            min_tip_percentage: 0,
+            min_cost_unit_price: Decimal::zero(),
+        });
+
+        assert_eq!(
+            Err(TransactionValidationError::SignatureValidationError(
+                SignatureValidationError::InvalidNotarySignature
+            )),
+            validator.validate(transaction, &TestIntentHashManager::new())
+        );
+    }
+
+    #[test]
+    fn test_multiple_ed25519_signers() {
+        let sk_notary = EcdsaSecp256k1PrivateKey::from_u64(2).unwrap();
+        let signers: Vec<EddsaEd25519PrivateKey> = (1..=3)
+            .map(|i| EddsaEd25519PrivateKey::from_u64(i).unwrap())
+            .collect();
+        let mut builder = TransactionBuilder::new()
+            .header(TransactionHeader {
+                version: 1,
+                network_id: NetworkDefinition::simulator().id,
+                start_epoch_inclusive: 0,
+                end_epoch_exclusive: 100,
+                nonce: 5,
+                notary_public_key: sk_notary.public_key().into(),
+                notary_as_signatory: false,
+                cost_unit_limit: 1_000_000,
+                tip_percentage: 5,
+                cost_unit_price: Decimal::zero(),
+                message: None,
+            })
+            .manifest(
+                ManifestBuilder::new(&NetworkDefinition::simulator())
+                    .clear_auth_zone()
+                    .build(),
+            );
+        for signer in &signers {
+            builder = builder.sign(signer);
+        }
+        let transaction = builder.notarize(&sk_notary).build();
+        let validator = NotarizedTransactionValidator::new(ValidationConfig {
+            network_id: NetworkDefinition::simulator().id,
+            current_epoch: 1,
+            max_cost_unit_limit: 10_000_000,
+            min_tip_percentage: 0,
+            min_cost_unit_price: Decimal::zero(),
+        });
+        assert_eq!(
+            validator
+                .validate_signatures(&transaction)
+                .map(|keys| keys.len()),
+            Ok(3)
+        );
+
+        let mut tampered = transaction;
+        tampered.signed_intent.intent_signatures[1] = Signer::sign(&signers[1], b"other intent");
+        assert_eq!(
+            Err(SignatureValidationError::InvalidIntentSignature),
+            validator.validate_signatures(&tampered)
+        );
+    }
+
+    #[test]
+    fn test_validate_transaction_from_bytes() {
+        let config = ValidationConfig {
+            network_id: NetworkDefinition::simulator().id,
+            current_epoch: 1,
+            max_cost_unit_limit: 10_000_000,
+            min_tip_percentage: 0,
+            min_cost_unit_price: Decimal::zero(),
+        };
+
+        let transaction = create_transaction(1, 0, 100, 5, vec![1, 2], 2);
+        let result = validate_transaction(
+            &transaction.to_bytes(),
+            config,
+            &TestIntentHashManager::new(),
+        );
+        assert_eq!(result.map(|v| v.transaction), Ok(transaction.clone()));
+
+        let mut tampered = transaction;
+        tampered.signed_intent.intent.header.nonce = 6;
+        assert_eq!(
+            validate_transaction(&tampered.to_bytes(), config, &TestIntentHashManager::new()),
+            Err(TransactionValidationError::SignatureValidationError(
+                SignatureValidationError::InvalidNotarySignature
+            ))
+        );
+    }
+
     #[test]
     fn test_valid_preview() {
         let mut intent_hash_manager: TestIntentHashManager = TestIntentHashManager::new();
@@ -399,6 +617,7 @@ mod tests {
             current_epoch: 1,
             max_cost_unit_limit: 10_000_000,
             min_tip_percentage: 0,
+            min_cost_unit_price: Decimal::zero(),
         });
 
         let result = validator.validate_preview_intent(
@@ -436,6 +655,8 @@ mod tests {
                 notary_as_signatory: false,
                 cost_unit_limit: 1_000_000,
                 tip_percentage: 5,
+                cost_unit_price: Decimal::zero(),
+                message: None,
             })
             .manifest(
                 ManifestBuilder::new(&NetworkDefinition::simulator())